@@ -21,6 +21,18 @@ export const dataCreditsResolvers = combineResolvers(
     mint: "hntMint",
     owner: "owner",
   }),
+  ataResolver({
+    instruction: "mintDataCreditsV1",
+    account: "recipientTokenAccount",
+    mint: "dcMint",
+    owner: "recipient",
+  }),
+  ataResolver({
+    instruction: "mintDataCreditsV1",
+    account: "burner",
+    mint: "hntMint",
+    owner: "owner",
+  }),
   ataResolver({
     instruction: "delegateDataCreditsV0",
     account: "fromAccount",
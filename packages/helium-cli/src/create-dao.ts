@@ -157,7 +157,25 @@ async function run() {
       type: "number",
       describe: "Authority index for squads. Defaults to 1",
       default: 1,
-    }
+    },
+    entityMetadataUrl: {
+      type: "string",
+      describe: "Base url for entity (hotspot) metadata. Defaults to the program's built in url",
+      default: "",
+    },
+    epochLength: {
+      type: "number",
+      describe: "Length of an epoch in seconds. Defaults to one day",
+    },
+    epochOffset: {
+      type: "number",
+      describe: "Seconds past midnight UTC at which epochs start. Defaults to 0",
+    },
+    noClockwork: {
+      type: "boolean",
+      describe: "Skip the clockwork thread, leaving hst issuance to crankers",
+      default: false,
+    },
   });
 
   const argv = await yarg.argv;
@@ -365,6 +383,9 @@ async function run() {
         registrar: registrar,
         authority,
         netEmissionsCap: toBN(34.24, 8),
+        entityMetadataUrl: argv.entityMetadataUrl,
+        epochLength: argv.epochLength ? new anchor.BN(argv.epochLength) : null,
+        epochOffset: argv.epochOffset ? new anchor.BN(argv.epochOffset) : null,
         // TODO: Emissions and net emissions schedule for hnt
         hstEmissionSchedule: [
           {
@@ -382,7 +403,8 @@ async function run() {
       .accounts({
         dcMint: dcKeypair.publicKey,
         hntMint: hntKeypair.publicKey,
-        thread,
+        thread: argv.noClockwork ? null : thread,
+        clockwork: argv.noClockwork ? null : undefined,
         // TODO: Create actual HST pool
         hstPool: await getAssociatedTokenAddress(
           hntKeypair.publicKey,
@@ -269,6 +269,15 @@ async function run() {
           .accounts({ maker, updateAuthority: makerAcc.updateAuthority })
           .instruction()
       );
+      // Update authority changes only take effect once the new authority accepts
+      if (!makerAcc.updateAuthority.equals(updateAuthority)) {
+        innerCreateInstrs.push(
+          await hemProgram.methods
+            .acceptMakerUpdateAuthorityV0()
+            .accounts({ maker, newUpdateAuthority: updateAuthority })
+            .instruction()
+        );
+      }
 
       if (makerAcc.merkleTree.equals(SystemProgram.programId)) {
         const setTree = await hemProgram.methods
@@ -64,6 +64,11 @@ const yarg = yargs(hideBin(process.argv)).options({
     type: "boolean",
     default: false,
   },
+  noClockwork: {
+    type: "boolean",
+    describe: "Skip the clockwork threads, leaving the end of epoch steps to crankers",
+    default: false,
+  },
   url: {
     alias: "u",
     default: "http://127.0.0.1:8899",
@@ -486,6 +491,8 @@ async function run() {
           threshold: thresholdPercent(20),
         },
         onboardingDcFee: toBN(4000000, 0), // $40 in dc
+        // Use the default delegator rewards percent
+        delegatorRewardsPercentSchedule: [],
       })
       .accounts({
         dao,
@@ -497,6 +504,7 @@ async function run() {
         dntMintAuthority: daoAcc.authority,
         subDaoFreezeAuthority: daoAcc.authority,
         authority: daoAcc.authority,
+        clockwork: argv.noClockwork ? null : undefined,
       })
       .preInstructions([
         ComputeBudgetProgram.setComputeUnitLimit({ units: 500000 }),
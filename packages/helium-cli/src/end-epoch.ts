@@ -2,7 +2,7 @@ import {
   currentEpoch,
   daoEpochInfoKey,
   daoKey,
-  EPOCH_LENGTH,
+  epochConfig,
   init as initDao
 } from "@helium/helium-sub-daos-sdk";
 import * as anchor from "@coral-xyz/anchor";
@@ -50,14 +50,17 @@ async function run() {
       bytes: b58.encode(dao.toBuffer()),
     }
   }]);
+  const config = epochConfig(
+    await heliumSubDaosProgram.account.daoV0.fetch(dao)
+  );
   let targetTs = argv.from ? new BN(argv.from) : subdaos[0].account.vehntLastCalculatedTs;
 
   while (targetTs.toNumber() < new Date().valueOf() / 1000) {
-    const epoch = currentEpoch(targetTs);
+    const epoch = currentEpoch(targetTs, config);
     console.log(epoch.toNumber(), targetTs.toNumber())
     const daoEpochInfo =
       await heliumSubDaosProgram.account.daoEpochInfoV0.fetchNullable(
-        daoEpochInfoKey(dao, targetTs)[0]
+        daoEpochInfoKey(dao, targetTs, undefined, config)[0]
       );
     if (!daoEpochInfo?.doneCalculatingScores) {
       for (const subDao of subdaos) {
@@ -119,7 +122,7 @@ async function run() {
       console.log(`Failed to issue hst pool: ${e.message}`);
     }
     
-    targetTs = targetTs.add(new BN(EPOCH_LENGTH));
+    targetTs = targetTs.add(new BN(config.epochLength));
   }
 }
 
@@ -5,7 +5,7 @@ import {
 } from "@solana/web3.js";
 import os from "os";
 import yargs from "yargs/yargs";
-import { loadKeypair, parseEmissionsSchedule, sendInstructionsOrCreateProposal } from "./utils";
+import { loadKeypair, parseEmissionsSchedule, parseUtilityScoreConfig, sendInstructionsOrCreateProposal } from "./utils";
 import { mintWindowedBreakerKey, accountWindowedBreakerKey, init as initCb } from "@helium/circuit-breaker-sdk"
 
 
@@ -44,6 +44,18 @@ const yarg = yargs(hideBin(process.argv)).options({
     type: "string",
     default: null,
   },
+  crankBountyLamports: {
+    required: false,
+    describe: "Lamports paid from the crank vault to whoever runs a per-epoch crank step",
+    type: "number",
+    default: null,
+  },
+  newUtilityScoreConfigPath: {
+    required: false,
+    describe: "Path to file that contains the new utility score config",
+    type: "string",
+    default: null,
+  },
   govProgramId: {
     type: "string",
     describe: "Pubkey of the GOV program",
@@ -57,6 +69,12 @@ const yarg = yargs(hideBin(process.argv)).options({
   executeProposal: {
     type: "boolean",
   },
+  entityMetadataUrl: {
+    required: false,
+    describe: "Base url for entity (hotspot) metadata",
+    type: "string",
+    default: null,
+  },
 });
 
 
@@ -76,6 +94,8 @@ async function run() {
   const instructions = [];
 
   const hntMint = new PublicKey(argv.hntMint);
+  const dao = daoKey(hntMint)[0];
+  const daoAcc = await program.account.daoV0.fetch(dao);
   if (argv.newAuthority) {
     const hntCircuitBreaker = mintWindowedBreakerKey(hntMint)[0]
     const hntCbAcc = await cbProgram.account.mintWindowedCircuitBreakerV0.fetch(hntCircuitBreaker);
@@ -91,8 +111,12 @@ async function run() {
     authority: new PublicKey(argv.newAuthority),
     emissionSchedule: argv.newEmissionsSchedulePath ? await parseEmissionsSchedule(argv.newEmissionsSchedulePath) : null,
     hstEmissionSchedule: argv.newHstEmissionsSchedulePath ? await parseEmissionsSchedule(argv.newHstEmissionsSchedulePath) : null,
+    entityMetadataUrl: argv.entityMetadataUrl,
+    crankBountyLamports: argv.crankBountyLamports !== null ? new anchor.BN(argv.crankBountyLamports) : null,
+    utilityScoreConfig: argv.newUtilityScoreConfigPath ? await parseUtilityScoreConfig(argv.newUtilityScoreConfigPath) : null,
   }).accounts({
-    dao: daoKey(hntMint)[0],
+    dao,
+    payer: daoAcc.authority,
   }).instruction());
 
   await sendInstructionsOrCreateProposal({
@@ -95,9 +95,10 @@ async function run() {
   const instructions = [
     await hemProgram.methods.updateRewardableEntityConfigV0({
       settings,
-      newAuthority: rewardableConfigAcc.authority,
+      newAuthority: null,
     }).accounts({
       rewardableEntityConfig: rewardableConfigKey,
+      payer: rewardableConfigAcc.authority,
     })
     .instruction(),
   ];
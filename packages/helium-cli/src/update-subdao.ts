@@ -52,6 +52,18 @@ const yarg = yargs(hideBin(process.argv)).options({
     default: null,
     type: "string"
   },
+  newActiveDeviceAuthority: {
+    required: false,
+    describe: "New authority that attests active device counts per epoch",
+    type: "string",
+    default: null,
+  },
+  newDelegatorRewardsSchedulePath: {
+    required: false,
+    describe: "Path to file that contains the new delegator rewards percent schedule",
+    type: "string",
+    default: null,
+  },
   govProgramId: {
     type: "string",
     describe: "Pubkey of the GOV program",
@@ -106,6 +118,7 @@ async function run() {
         .accounts({
           rewardableEntityConfig: config,
           authority: configAcc.authority,
+          payer: configAcc.authority,
         })
         .instruction()
     );
@@ -167,13 +180,16 @@ async function run() {
   
   instructions.push(await program.methods.updateSubDaoV0({
     authority: argv.newAuthority ? new PublicKey(argv.newAuthority) : null,
-    emissionSchedule: argv.newEmissionsSchedulePath ? parseEmissionsSchedule(argv.newEmissionsSchedulePath) : null,
+    emissionSchedule: argv.newEmissionsSchedulePath ? await parseEmissionsSchedule(argv.newEmissionsSchedulePath) : null,
     dcBurnAuthority: null,
     onboardingDcFee: null,
     activeDeviceAggregator: argv.newActiveDeviceAggregator ? new PublicKey(argv.newActiveDeviceAggregator) : null,
+    activeDeviceAuthority: argv.newActiveDeviceAuthority ? new PublicKey(argv.newActiveDeviceAuthority) : null,
+    delegatorRewardsPercentSchedule: argv.newDelegatorRewardsSchedulePath ? await parseEmissionsSchedule(argv.newDelegatorRewardsSchedulePath) : null,
   }).accounts({
     subDao,
     authority: subDaoAcc.authority,
+    payer: subDaoAcc.authority,
   }).instruction());
 
   await sendInstructionsOrCreateProposal({
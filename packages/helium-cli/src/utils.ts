@@ -638,4 +638,27 @@ export async function parseEmissionsSchedule(filepath: string) {
     }
   });
   return schedule;
+}
+
+export async function parseUtilityScoreConfig(filepath: string) {
+  const json = JSON.parse(fs.readFileSync(filepath).toString());
+  const factor = (name: string) => {
+    const x = json[name];
+    if (!x || !["divisor", "exponentNumerator", "exponentDenominator", "floor"].every((k) => k in x)) {
+      throw new Error(`json format incorrect, missing ${name}`);
+    }
+    return {
+      divisor: new anchor.BN(x.divisor),
+      exponentNumerator: x.exponentNumerator,
+      exponentDenominator: x.exponentDenominator,
+      floor: new anchor.BN(x.floor),
+    };
+  };
+  return {
+    v0: {
+      vehnt: factor("vehnt"),
+      dcBurned: factor("dcBurned"),
+      devices: factor("devices"),
+    },
+  };
 }
\ No newline at end of file
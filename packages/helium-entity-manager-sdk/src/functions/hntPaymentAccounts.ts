@@ -0,0 +1,77 @@
+import { HeliumEntityManager } from "@helium/idls/lib/types/helium_entity_manager";
+import { AnchorProvider, Program } from "@coral-xyz/anchor";
+import { mintWindowedBreakerKey } from "@helium/circuit-breaker-sdk";
+import {
+  dataCreditsKey,
+  init as initDataCredits,
+} from "@helium/data-credits-sdk";
+import { init as initHeliumSubDaos } from "@helium/helium-sub-daos-sdk";
+import {
+  createAssociatedTokenAccountIdempotentInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+
+/**
+ * Accounts for the `hntPayment` group of the `WithHntV0` instructions, which burn HNT
+ * from `hntOwner` for the DC a fee needs.
+ */
+export async function hntPaymentAccounts({
+  program,
+  rewardableEntityConfig,
+  hntOwner,
+}: {
+  program: Program<HeliumEntityManager>;
+  rewardableEntityConfig: PublicKey;
+  hntOwner: PublicKey;
+}) {
+  const provider = program.provider as AnchorProvider;
+  const hsdProgram = await initHeliumSubDaos(provider);
+  const dcProgram = await initDataCredits(provider);
+
+  const { subDao } = await program.account.rewardableEntityConfigV0.fetch(
+    rewardableEntityConfig
+  );
+  const { dao } = await hsdProgram.account.subDaoV0.fetch(subDao);
+  const { hntMint, dcMint } = await hsdProgram.account.daoV0.fetch(dao);
+  const dataCredits = dataCreditsKey(dcMint)[0];
+  const { hntPriceOracle } = await dcProgram.account.dataCreditsV0.fetch(
+    dataCredits
+  );
+
+  return {
+    dao,
+    subDao,
+    dcMint,
+    hntPayment: {
+      hntOwner,
+      hntBurner: getAssociatedTokenAddressSync(hntMint, hntOwner, true),
+      hntMint,
+      dcMint,
+      dataCredits,
+      hntPriceOracle,
+      circuitBreaker: mintWindowedBreakerKey(dcMint)[0],
+    },
+  };
+}
+
+/**
+ * The DC account fees are burned from has to exist before the instruction runs, create it
+ * for owners that have only ever held HNT.
+ */
+export function createDcBurnerIx({
+  payer,
+  owner,
+  dcMint,
+}: {
+  payer: PublicKey;
+  owner: PublicKey;
+  dcMint: PublicKey;
+}): TransactionInstruction {
+  return createAssociatedTokenAccountIdempotentInstruction(
+    payer,
+    getAssociatedTokenAddressSync(dcMint, owner, true),
+    owner,
+    dcMint
+  );
+}
@@ -0,0 +1,122 @@
+import { HeliumEntityManager } from "@helium/idls/lib/types/helium_entity_manager";
+import {
+  BorshAccountsCoder,
+  IdlAccounts,
+  Program,
+} from "@coral-xyz/anchor";
+import { PublicKey, TransactionInstruction } from "@solana/web3.js";
+
+type IotInfo = IdlAccounts<HeliumEntityManager>["iotHotspotInfoV1"];
+type MobileInfo = IdlAccounts<HeliumEntityManager>["mobileHotspotInfoV1"];
+
+// V0 infos are still around until they're migrated, `version` says which one was read
+export type Versioned<T> = T & { version: 0 | 1 };
+
+function isAccount(data: Buffer, name: string): boolean {
+  return BorshAccountsCoder.accountDiscriminator(name).equals(
+    data.subarray(0, 8)
+  );
+}
+
+/**
+ * Reads an iot info of either version, filling in the fields V0 doesn't have.
+ */
+export async function getIotInfo(
+  program: Program<HeliumEntityManager>,
+  info: PublicKey
+): Promise<Versioned<IotInfo> | null> {
+  const account = await program.provider.connection.getAccountInfo(info);
+  if (!account) {
+    return null;
+  }
+
+  if (isAccount(account.data, "IotHotspotInfoV0")) {
+    const v0 = program.coder.accounts.decode(
+      "IotHotspotInfoV0",
+      account.data
+    );
+    return {
+      ...v0,
+      region: null,
+      displayName: null,
+      lastAssertedTs: null,
+      owner: null,
+      transferred: false,
+      version: 0,
+    };
+  }
+
+  return {
+    ...program.coder.accounts.decode<IotInfo>("IotHotspotInfoV1", account.data),
+    version: 1,
+  };
+}
+
+/**
+ * Reads a mobile info of either version, filling in the fields V0 doesn't have.
+ */
+export async function getMobileInfo(
+  program: Program<HeliumEntityManager>,
+  info: PublicKey
+): Promise<Versioned<MobileInfo> | null> {
+  const account = await program.provider.connection.getAccountInfo(info);
+  if (!account) {
+    return null;
+  }
+
+  if (isAccount(account.data, "MobileHotspotInfoV0")) {
+    const v0 = program.coder.accounts.decode(
+      "MobileHotspotInfoV0",
+      account.data
+    );
+    return {
+      ...v0,
+      displayName: null,
+      // Matches what migrateMobileInfoV0 sets
+      deviceType: v0.isFullHotspot ? { cbrs: {} } : { wifiDataOnly: {} },
+      lastAssertedTs: null,
+      owner: null,
+      transferred: false,
+      version: 0,
+    };
+  }
+
+  return {
+    ...program.coder.accounts.decode<MobileInfo>(
+      "MobileHotspotInfoV1",
+      account.data
+    ),
+    version: 1,
+  };
+}
+
+/**
+ * Instructions that migrate V0 infos to V1 ahead of time. Instructions that write the info
+ * migrate it themselves, so this is only needed to read V1 fields from a V0 info.
+ */
+export async function migrateInfoIxs({
+  program,
+  iotInfo,
+  mobileInfo,
+}: {
+  program: Program<HeliumEntityManager>;
+  iotInfo?: PublicKey;
+  mobileInfo?: PublicKey;
+}): Promise<TransactionInstruction[]> {
+  const ixs: TransactionInstruction[] = [];
+  if (iotInfo && (await getIotInfo(program, iotInfo))?.version === 0) {
+    ixs.push(
+      await program.methods.migrateIotInfoV0().accounts({ iotInfo }).instruction()
+    );
+  }
+  if (mobileInfo && (await getMobileInfo(program, mobileInfo))?.version === 0) {
+    ixs.push(
+      await program.methods
+        .migrateMobileInfoV0()
+        .accounts({ mobileInfo })
+        .instruction()
+    );
+  }
+
+  return ixs;
+}
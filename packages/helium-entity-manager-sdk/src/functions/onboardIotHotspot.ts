@@ -1,9 +1,10 @@
 import { HeliumEntityManager } from "@helium/idls/lib/types/helium_entity_manager";
-import { Program } from "@coral-xyz/anchor";
+import { AnchorProvider, Program } from "@coral-xyz/anchor";
 import BN from "bn.js";
 import { PublicKey } from "@solana/web3.js";
 import { iotInfoKey, keyToAssetKey } from "../pdas";
 import { proofArgsAndAccounts, ProofArgsAndAccountsArgs } from "./proofArgsAndAccounts";
+import { createDcBurnerIx, hntPaymentAccounts } from "./hntPaymentAccounts";
 
 
 export async function onboardIotHotspot({
@@ -12,22 +13,29 @@ export async function onboardIotHotspot({
   assetId,
   maker,
   dao,
-  dcFeePayer,
   payer,
   location,
   elevation,
   gain,
+  region,
+  maxHnt,
+  hntOwner,
   ...rest
 }: {
   program: Program<HeliumEntityManager>;
   rewardableEntityConfig: PublicKey;
   payer?: PublicKey;
-  dcFeePayer?: PublicKey;
   maker: PublicKey;
   location?: BN;
   elevation?: number;
   gain?: number;
+  // Anchor enum, ie { us915: {} }
+  region?: any;
   dao: PublicKey;
+  // When set, the onboarding fee is paid by burning at most this much HNT from `hntOwner`
+  // instead of drawing on the maker's escrow balance
+  maxHnt?: BN;
+  hntOwner?: PublicKey;
 } & Omit<ProofArgsAndAccountsArgs, "connection">) {
   const {
     asset: {
@@ -52,25 +60,48 @@ export async function onboardIotHotspot({
   const keyToAsset = (
     await keyToAssetKey(dao, json_uri.split("/").slice(-1)[0])
   )[0];
-  return program.methods
-    .onboardIotHotspotV0({
-      ...args,
-      location: typeof location == "undefined" ? null : location,
-      elevation: typeof elevation == "undefined" ? null : elevation,
-      gain: typeof gain == "undefined" ? null : gain,
-    })
-    .accounts({
-      // hotspot: assetId,
-      ...accounts,
-      payer,
-      dcFeePayer,
+  const onboardArgs = {
+    ...args,
+    location: typeof location == "undefined" ? null : location,
+    elevation: typeof elevation == "undefined" ? null : elevation,
+    gain: typeof gain == "undefined" ? null : gain,
+    region: typeof region == "undefined" ? null : region,
+  };
+  const onboardAccounts = {
+    // hotspot: assetId,
+    ...accounts,
+    payer,
+    rewardableEntityConfig,
+    hotspotOwner: owner,
+    iotInfo: info,
+    maker,
+    dao,
+    issuingAuthority: makerAcc?.issuingAuthority,
+    keyToAsset,
+  };
+
+  if (maxHnt) {
+    const wallet = (program.provider as AnchorProvider).wallet.publicKey;
+    const { subDao, dcMint, hntPayment } = await hntPaymentAccounts({
+      program,
       rewardableEntityConfig,
-      hotspotOwner: owner,
-      iotInfo: info,
-      maker,
-      dao,
-      issuingAuthority: makerAcc?.issuingAuthority,
-      keyToAsset,
-    })
+      hntOwner: hntOwner || wallet,
+    });
+
+    return program.methods
+      .onboardIotHotspotWithHntV0({ onboard: onboardArgs, maxHnt })
+      .preInstructions([
+        createDcBurnerIx({ payer: payer || wallet, owner: maker, dcMint }),
+      ])
+      .accounts({
+        onboard: { ...onboardAccounts, subDao, dcMint },
+        hntPayment,
+      } as any)
+      .remainingAccounts(remainingAccounts);
+  }
+
+  return program.methods
+    .onboardIotHotspotV0(onboardArgs)
+    .accounts(onboardAccounts)
     .remainingAccounts(remainingAccounts);
 }
@@ -1,8 +1,9 @@
 import { HeliumEntityManager } from "@helium/idls/lib/types/helium_entity_manager";
-import { Program } from "@coral-xyz/anchor";
+import { AnchorProvider, Program } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { keyToAssetKey, mobileInfoKey } from "../pdas";
 import { proofArgsAndAccounts, ProofArgsAndAccountsArgs } from "./proofArgsAndAccounts";
+import { createDcBurnerIx, hntPaymentAccounts } from "./hntPaymentAccounts";
 import BN from "bn.js";
 
 export async function onboardMobileHotspot({
@@ -11,19 +12,25 @@ export async function onboardMobileHotspot({
   assetId,
   maker,
   location,
+  deviceType = { cbrs: {} },
   dao,
   payer,
-  dcFeePayer,
+  maxHnt,
+  hntOwner,
   ...rest
 }: {
   program: Program<HeliumEntityManager>;
   payer?: PublicKey;
-  dcFeePayer?: PublicKey;
   assetId: PublicKey;
   location?: BN;
+  deviceType?: any;
   rewardableEntityConfig: PublicKey;
   maker: PublicKey;
   dao: PublicKey;
+  // When set, the onboarding fee is paid by burning at most this much HNT from `hntOwner`
+  // instead of drawing on the maker's escrow balance
+  maxHnt?: BN;
+  hntOwner?: PublicKey;
 } & Omit<ProofArgsAndAccountsArgs, "connection">) {
   const {
     asset: {
@@ -45,25 +52,48 @@ export async function onboardMobileHotspot({
   );
   const makerAcc = await program.account.makerV0.fetchNullable(maker);
 
-  return program.methods
-    .onboardMobileHotspotV0({
-      ...args,
-      location: typeof location == "undefined" ? null : location,
-    })
-    .accounts({
-      // hotspot: assetId,
-      ...accounts,
-      dcFeePayer,
-      payer,
+  const onboardArgs = {
+    ...args,
+    location: typeof location == "undefined" ? null : location,
+    deviceType,
+  };
+  const onboardAccounts = {
+    // hotspot: assetId,
+    ...accounts,
+    payer,
+    rewardableEntityConfig,
+    hotspotOwner: owner,
+    mobileInfo: info,
+    maker,
+    dao,
+    issuingAuthority: makerAcc?.issuingAuthority,
+    keyToAsset: (
+      await keyToAssetKey(dao, json_uri.split("/").slice(-1)[0])
+    )[0],
+  };
+
+  if (maxHnt) {
+    const wallet = (program.provider as AnchorProvider).wallet.publicKey;
+    const { subDao, dcMint, hntPayment } = await hntPaymentAccounts({
+      program,
       rewardableEntityConfig,
-      hotspotOwner: owner,
-      mobileInfo: info,
-      maker,
-      dao,
-      issuingAuthority: makerAcc?.issuingAuthority,
-      keyToAsset: (
-        await keyToAssetKey(dao, json_uri.split("/").slice(-1)[0])
-      )[0],
-    })
+      hntOwner: hntOwner || wallet,
+    });
+
+    return program.methods
+      .onboardMobileHotspotWithHntV0({ onboard: onboardArgs, maxHnt })
+      .preInstructions([
+        createDcBurnerIx({ payer: payer || wallet, owner: maker, dcMint }),
+      ])
+      .accounts({
+        onboard: { ...onboardAccounts, subDao, dcMint },
+        hntPayment,
+      } as any)
+      .remainingAccounts(remainingAccounts);
+  }
+
+  return program.methods
+    .onboardMobileHotspotV0(onboardArgs)
+    .accounts(onboardAccounts)
     .remainingAccounts(remainingAccounts);
 }
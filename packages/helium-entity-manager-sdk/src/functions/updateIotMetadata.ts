@@ -1,7 +1,9 @@
 import { HeliumEntityManager } from "@helium/idls/lib/types/helium_entity_manager";
-import { BN, Program } from "@coral-xyz/anchor";
+import { AnchorProvider, BN, Program } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
-import { iotInfoKey } from "../pdas";
+import { init as initHeliumSubDaos } from "@helium/helium-sub-daos-sdk";
+import { iotInfoKey, keyToAssetKey } from "../pdas";
+import { createDcBurnerIx, hntPaymentAccounts } from "./hntPaymentAccounts";
 import { proofArgsAndAccounts, ProofArgsAndAccountsArgs } from "./proofArgsAndAccounts";
 
 export async function updateIotMetadata({
@@ -11,16 +13,27 @@ export async function updateIotMetadata({
   location,
   elevation,
   gain,
+  region = null,
+  displayName = null,
   payer,
   dcFeePayer,
+  maxHnt,
+  hntOwner,
   ...rest
 }: {
   program: Program<HeliumEntityManager>;
   payer?: PublicKey;
   dcFeePayer?: PublicKey;
+  // When set, the location fee is paid by burning at most this much HNT from `hntOwner`
+  maxHnt?: BN;
+  hntOwner?: PublicKey;
   location: BN | null;
   elevation: number | null;
   gain: number | null;
+  // Anchor enum, ie { us915: {} }
+  region?: any;
+  // Empty string clears the display name
+  displayName?: string | null;
   assetId: PublicKey;
   rewardableEntityConfig: PublicKey;
 } & Omit<ProofArgsAndAccountsArgs, "connection">) {
@@ -38,23 +51,57 @@ export async function updateIotMetadata({
     ...rest,
   });
 
-  const [info] = await iotInfoKey(rewardableEntityConfig, json_uri.split("/").slice(-1)[0]);
+  const entityKey = json_uri.split("/").slice(-1)[0];
+  const [info] = await iotInfoKey(rewardableEntityConfig, entityKey);
+  const { subDao } = await program.account.rewardableEntityConfigV0.fetch(
+    rewardableEntityConfig
+  );
+  const hsdProgram = await initHeliumSubDaos(program.provider as AnchorProvider);
+  const { dao } = await hsdProgram.account.subDaoV0.fetch(subDao);
+  const [keyToAsset] = await keyToAssetKey(dao, entityKey);
 
-  return program.methods
-    .updateIotInfoV0({
-      location,
-      elevation,
-      gain,
-      ...args
-    })
-    .accounts({
-      // hotspot: assetId,
-      ...accounts,
-      payer,
-      dcFeePayer,
+  const updateArgs = {
+    location,
+    elevation,
+    gain,
+    region,
+    displayName,
+    ...args
+  };
+  const updateAccounts = {
+    // hotspot: assetId,
+    ...accounts,
+    payer,
+    dcFeePayer,
+    rewardableEntityConfig,
+    hotspotOwner: owner,
+    iotInfo: info,
+    keyToAsset,
+  };
+
+  if (maxHnt) {
+    const wallet = (program.provider as AnchorProvider).wallet.publicKey;
+    const feePayer = dcFeePayer || wallet;
+    const { dcMint, hntPayment } = await hntPaymentAccounts({
+      program,
       rewardableEntityConfig,
-      hotspotOwner: owner,
-      iotInfo: info,
-    })
+      hntOwner: hntOwner || feePayer,
+    });
+
+    return program.methods
+      .updateIotInfoWithHntV0({ update: updateArgs, maxHnt })
+      .preInstructions([
+        createDcBurnerIx({ payer: payer || wallet, owner: feePayer, dcMint }),
+      ])
+      .accounts({
+        update: { ...updateAccounts, dcFeePayer: feePayer, dao, subDao, dcMint },
+        hntPayment,
+      } as any)
+      .remainingAccounts(remainingAccounts);
+  }
+
+  return program.methods
+    .updateIotInfoV0(updateArgs)
+    .accounts(updateAccounts)
     .remainingAccounts(remainingAccounts);
 }
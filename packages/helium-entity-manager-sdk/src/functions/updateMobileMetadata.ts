@@ -1,7 +1,9 @@
 import { HeliumEntityManager } from "@helium/idls/lib/types/helium_entity_manager";
-import { BN, Program } from "@coral-xyz/anchor";
+import { AnchorProvider, BN, Program } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
-import { mobileInfoKey } from "../pdas";
+import { init as initHeliumSubDaos } from "@helium/helium-sub-daos-sdk";
+import { mobileInfoKey, keyToAssetKey } from "../pdas";
+import { createDcBurnerIx, hntPaymentAccounts } from "./hntPaymentAccounts";
 import { proofArgsAndAccounts, ProofArgsAndAccountsArgs } from "./proofArgsAndAccounts";
 
 export async function updateMobileMetadata({
@@ -9,14 +11,22 @@ export async function updateMobileMetadata({
   rewardableEntityConfig,
   assetId,
   location,
+  displayName = null,
   dcFeePayer,
   payer,
+  maxHnt,
+  hntOwner,
   ...rest
 }: {
   program: Program<HeliumEntityManager>;
   payer?: PublicKey;
   dcFeePayer?: PublicKey;
+  // When set, the location fee is paid by burning at most this much HNT from `hntOwner`
+  maxHnt?: BN;
+  hntOwner?: PublicKey;
   location: BN | null;
+  // Empty string clears the display name
+  displayName?: string | null;
   assetId: PublicKey;
   rewardableEntityConfig: PublicKey;
 } & Omit<ProofArgsAndAccountsArgs, "connection">) {
@@ -33,20 +43,53 @@ export async function updateMobileMetadata({
     assetId,
     ...rest,
   });
-  const [info] = await mobileInfoKey(rewardableEntityConfig, json_uri.split("/").slice(-1)[0]);
+  const entityKey = json_uri.split("/").slice(-1)[0];
+  const [info] = await mobileInfoKey(rewardableEntityConfig, entityKey);
+  const { subDao } = await program.account.rewardableEntityConfigV0.fetch(
+    rewardableEntityConfig
+  );
+  const hsdProgram = await initHeliumSubDaos(program.provider as AnchorProvider);
+  const { dao } = await hsdProgram.account.subDaoV0.fetch(subDao);
+  const [keyToAsset] = await keyToAssetKey(dao, entityKey);
 
-  return program.methods
-    .updateMobileInfoV0({
-      location,
-      ...args
-    })
-    .accounts({
-      ...accounts,
-      dcFeePayer,
-      payer,
+  const updateArgs = {
+    location,
+    displayName,
+    ...args
+  };
+  const updateAccounts = {
+    ...accounts,
+    dcFeePayer,
+    payer,
+    rewardableEntityConfig,
+    hotspotOwner: owner,
+    mobileInfo: info,
+    keyToAsset,
+  };
+
+  if (maxHnt) {
+    const wallet = (program.provider as AnchorProvider).wallet.publicKey;
+    const feePayer = dcFeePayer || wallet;
+    const { dcMint, hntPayment } = await hntPaymentAccounts({
+      program,
       rewardableEntityConfig,
-      hotspotOwner: owner,
-      mobileInfo: info,
-    })
+      hntOwner: hntOwner || feePayer,
+    });
+
+    return program.methods
+      .updateMobileInfoWithHntV0({ update: updateArgs, maxHnt })
+      .preInstructions([
+        createDcBurnerIx({ payer: payer || wallet, owner: feePayer, dcMint }),
+      ])
+      .accounts({
+        update: { ...updateAccounts, dcFeePayer: feePayer, dao, subDao, dcMint },
+        hntPayment,
+      } as any)
+      .remainingAccounts(remainingAccounts);
+  }
+
+  return program.methods
+    .updateMobileInfoV0(updateArgs)
+    .accounts(updateAccounts)
     .remainingAccounts(remainingAccounts);
 }
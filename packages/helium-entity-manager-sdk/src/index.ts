@@ -1,6 +1,15 @@
 import { Program } from "@coral-xyz/anchor";
 
 export * from "./constants";
+export {
+  createDcBurnerIx,
+  hntPaymentAccounts,
+} from "./functions/hntPaymentAccounts";
+export {
+  getIotInfo,
+  getMobileInfo,
+  migrateInfoIxs,
+} from "./functions/hotspotInfo";
 export { onboardIotHotspot } from "./functions/onboardIotHotspot";
 export { onboardMobileHotspot } from "./functions/onboardMobileHotspot";
 export { proofArgsAndAccounts } from "./functions/proofArgsAndAccounts";
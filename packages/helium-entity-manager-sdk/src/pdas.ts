@@ -5,6 +5,26 @@ import { sha256 } from "js-sha256";
 // @ts-ignore
 import bs58 from "bs58";
 
+// Mirrors `entity_key_hash` on chain. PDAs hash the decoded helium address, so a key issued as
+// b58 bytes or as utf8 text derives the same accounts. `encoding` is how a Buffer key is stored
+export const encodeEntityKey = (
+  entityKey: Buffer | string,
+  encoding: BufferEncoding | "b58" = "b58"
+): Buffer => {
+  if (typeof entityKey !== "string") {
+    if (encoding === "b58") {
+      return entityKey;
+    }
+    entityKey = entityKey.toString(encoding);
+  }
+  try {
+    return Buffer.from(bs58.decode(entityKey));
+  } catch (e: any) {
+    // Not a helium address, hashed as is like on chain
+    return Buffer.from(entityKey, encoding === "b58" ? "utf-8" : encoding);
+  }
+};
+
 export const entityCreatorKey = (
   dao: PublicKey,
   programId: PublicKey = PROGRAM_ID
@@ -54,15 +74,27 @@ export const makerApprovalKey = (
     programId
   );
 
+export const makerRevocationKey = (
+  rewardableEntityConfig: PublicKey,
+  maker: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+) =>
+  PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("maker_revocation", "utf-8"),
+      rewardableEntityConfig.toBuffer(),
+      maker.toBuffer(),
+    ],
+    programId
+  );
+
 export const keyToAssetKey = (
   dao: PublicKey,
   entityKey: Buffer | string,
-  programId: PublicKey = PROGRAM_ID
+  programId: PublicKey = PROGRAM_ID,
+  encoding: BufferEncoding | "b58" = "b58"
 ) => {
-  if (typeof entityKey === "string") {
-    entityKey = Buffer.from(bs58.decode(entityKey));
-  }
-  const hash = sha256(entityKey);
+  const hash = sha256(encodeEntityKey(entityKey, encoding));
 
   return PublicKey.findProgramAddressSync(
     [Buffer.from("key_to_asset", "utf-8"), dao.toBuffer(), Buffer.from(hash, "hex")],
@@ -73,12 +105,10 @@ export const keyToAssetKey = (
 export const iotInfoKey = (
   rewardableEntityConfig: PublicKey,
   entityKey: Buffer | string,
-  programId: PublicKey = PROGRAM_ID
+  programId: PublicKey = PROGRAM_ID,
+  encoding: BufferEncoding | "b58" = "b58"
 ) => {
-  if (typeof entityKey === "string") {
-    entityKey = Buffer.from(bs58.decode(entityKey));
-  }
-  const hash = sha256(entityKey);
+  const hash = sha256(encodeEntityKey(entityKey, encoding));
 
   return PublicKey.findProgramAddressSync(
     [
@@ -93,12 +123,10 @@ export const iotInfoKey = (
 export const mobileInfoKey = async (
   rewardableEntityConfig: PublicKey,
   entityKey: Buffer | string,
-  programId: PublicKey = PROGRAM_ID
+  programId: PublicKey = PROGRAM_ID,
+  encoding: BufferEncoding | "b58" = "b58"
 ) => {
-  if (typeof entityKey === "string") {
-    entityKey = Buffer.from(bs58.decode(entityKey));
-  }
-  const hash = sha256(entityKey);
+  const hash = sha256(encodeEntityKey(entityKey, encoding));
 
   return PublicKey.findProgramAddressSync(
     [
@@ -16,6 +16,10 @@ import { PROGRAM_ID } from "./constants";
 import { Program } from "@coral-xyz/anchor";
 import { init } from "./init";
 
+// How a `KeySerializationV0` entity key is stored, for the pda helpers
+const keyEncoding = (keySerialization: any): BufferEncoding | "b58" =>
+  keySerialization?.utf8 ? "utf-8" : "b58";
+
 export const heliumEntityManagerResolvers = combineResolvers(
   heliumCommonResolver,
   ataResolver({
@@ -33,7 +37,9 @@ export const heliumEntityManagerResolvers = combineResolvers(
       return (
         await keyToAssetKey(
           accounts.dao as PublicKey,
-          args[args.length - 1].entityKey
+          args[args.length - 1].entityKey,
+          PROGRAM_ID,
+          keyEncoding(args[args.length - 1].keySerialization)
         )
       )[0];
     }
@@ -53,7 +59,9 @@ export const heliumEntityManagerResolvers = combineResolvers(
       return (
         await iotInfoKey(
           accounts.rewardableEntityConfig as PublicKey,
-          keyToAssetAcc.entityKey
+          keyToAssetAcc.entityKey,
+          PROGRAM_ID,
+          keyEncoding(keyToAssetAcc.keySerialization)
         )
       )[0];
     } else if (path[path.length - 1] === "recipient") {
@@ -76,7 +84,9 @@ export const heliumEntityManagerResolvers = combineResolvers(
       return (
         await mobileInfoKey(
           accounts.rewardableEntityConfig as PublicKey,
-          keyToAssetAcc.entityKey
+          keyToAssetAcc.entityKey,
+          PROGRAM_ID,
+          keyEncoding(keyToAssetAcc.keySerialization)
         )
       )[0];
     } else if (path[path.length - 1] === "recipient") {
@@ -102,10 +112,38 @@ export const heliumEntityManagerResolvers = combineResolvers(
     mint: "hotspot",
     owner: "hotspotOwner",
   }),
+  ataResolver({
+    instruction: "onboardIotHotspotV0",
+    account: "dcBurner",
+    mint: "dcMint",
+    owner: "maker",
+  }),
+  ataResolver({
+    instruction: "onboardMobileHotspotV0",
+    account: "dcBurner",
+    mint: "dcMint",
+    owner: "maker",
+  }),
   ataResolver({
     account: "dcBurner",
     mint: "dcMint",
     owner: "dcFeePayer",
   }),
+  ataResolver({
+    account: "makerEscrow",
+    mint: "dcMint",
+    owner: "maker",
+  }),
+  ataResolver({
+    account: "makerBond",
+    mint: "hntMint",
+    owner: "maker",
+  }),
+  ataResolver({
+    instruction: "topUpMakerEscrowV0",
+    account: "burner",
+    mint: "hntMint",
+    owner: "owner",
+  }),
   subDaoEpochInfoResolver
 );
@@ -5,8 +5,45 @@ export const PROGRAM_ID = new PublicKey(
   "hdaoVTCqhfHHo75XdAMxBKdUqvq1i5bF23sisBqVgGR"
 );
 
+// Default epoch length. Daos may configure their own, see `epochConfig`
 export const EPOCH_LENGTH = 60 * 60 * 24;
 
-export function currentEpoch(unixTime: BN): BN {
-  return new BN(Math.floor(unixTime.toNumber() / EPOCH_LENGTH));
+export type EpochConfig = {
+  epochLength: number;
+  epochOffset: number;
+};
+
+export const DEFAULT_EPOCH_CONFIG: EpochConfig = {
+  epochLength: EPOCH_LENGTH,
+  epochOffset: 0,
+};
+
+/**
+ * Epoch config of a dao account. Daos created before epochs were configurable store a zero
+ * length and use the default.
+ */
+export function epochConfig(dao: {
+  epochLength: BN;
+  epochOffset: BN;
+}): EpochConfig {
+  return {
+    epochLength: dao.epochLength.toNumber() || EPOCH_LENGTH,
+    epochOffset: dao.epochOffset.toNumber(),
+  };
+}
+
+export function currentEpoch(
+  unixTime: BN,
+  config: EpochConfig = DEFAULT_EPOCH_CONFIG
+): BN {
+  return new BN(
+    Math.floor((unixTime.toNumber() - config.epochOffset) / config.epochLength)
+  );
+}
+
+export function epochStartTs(
+  epoch: BN | number,
+  config: EpochConfig = DEFAULT_EPOCH_CONFIG
+): number {
+  return new BN(epoch).toNumber() * config.epochLength + config.epochOffset;
 }
\ No newline at end of file
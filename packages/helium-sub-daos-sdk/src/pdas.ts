@@ -1,14 +1,20 @@
 import { PublicKey } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
-import { currentEpoch, PROGRAM_ID } from "./constants";
+import {
+  currentEpoch,
+  DEFAULT_EPOCH_CONFIG,
+  EpochConfig,
+  PROGRAM_ID,
+} from "./constants";
 
 export function subDaoEpochInfoKey(
   subDao: PublicKey,
   unixTime: number | BN,
-  programId: PublicKey = PROGRAM_ID
+  programId: PublicKey = PROGRAM_ID,
+  epochConfig: EpochConfig = DEFAULT_EPOCH_CONFIG
 ): [PublicKey, number] {
   let bU64 = Buffer.alloc(8);
-  const epoch = currentEpoch(new BN(unixTime)).toNumber();
+  const epoch = currentEpoch(new BN(unixTime), epochConfig).toNumber();
   bU64.writeBigUInt64LE(BigInt(epoch));
   return PublicKey.findProgramAddressSync(
     [Buffer.from("sub_dao_epoch_info", "utf-8"), subDao.toBuffer(), bU64],
@@ -19,10 +25,11 @@ export function subDaoEpochInfoKey(
 export function daoEpochInfoKey(
   dao: PublicKey,
   unixTime: number | BN,
-  programId: PublicKey = PROGRAM_ID
+  programId: PublicKey = PROGRAM_ID,
+  epochConfig: EpochConfig = DEFAULT_EPOCH_CONFIG
 ): [PublicKey, number] {
   let bU64 = Buffer.alloc(8);
-  const epoch = currentEpoch(new BN(unixTime)).toNumber();
+  const epoch = currentEpoch(new BN(unixTime), epochConfig).toNumber();
   bU64.writeBigUInt64LE(BigInt(epoch));
   return PublicKey.findProgramAddressSync(
     [Buffer.from("dao_epoch_info", "utf-8"), dao.toBuffer(), bU64],
@@ -60,6 +67,16 @@ export function delegatedPositionKey(
   );
 }
 
+export function crankVaultKey(
+  dao: PublicKey,
+  programId: PublicKey = PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("crank_vault", "utf-8"), dao.toBuffer()],
+    programId
+  );
+}
+
 const CLOCKWORK_PID = new PublicKey("CLoCKyJ6DXBJqqu2VWx9RLbgnwwR6BMHHuyasVmfMzBh");
 export function threadKey(
   authority: PublicKey,
@@ -6,18 +6,73 @@ import {
 } from "@helium/spl-utils";
 import { treasuryManagementResolvers } from "@helium/treasury-management-sdk";
 import { init, PROGRAM_ID as VSR_PROGRAM_ID, vsrResolvers } from "@helium/voter-stake-registry-sdk";
-import { AnchorProvider, Provider } from "@coral-xyz/anchor";
+import { IDL } from "@helium/idls/lib/types/helium_sub_daos";
+import { AnchorProvider, BorshAccountsCoder, Idl, Provider } from "@coral-xyz/anchor";
 import { PublicKey, SYSVAR_CLOCK_PUBKEY } from "@solana/web3.js";
-import { EPOCH_LENGTH, PROGRAM_ID } from "./constants";
-import { daoEpochInfoKey, subDaoEpochInfoKey } from "./pdas";
+import {
+  DEFAULT_EPOCH_CONFIG,
+  EpochConfig,
+  epochConfig,
+  epochStartTs,
+  PROGRAM_ID,
+} from "./constants";
+import { daoEpochInfoKey, subDaoEpochInfoKey, threadKey } from "./pdas";
 
 const THREAD_PID = new PublicKey(
   "CLoCKyJ6DXBJqqu2VWx9RLbgnwwR6BMHHuyasVmfMzBh"
 );
 
+const coder = new BorshAccountsCoder(IDL as Idl);
+
+async function daoEpochConfig(
+  provider: Provider,
+  dao: PublicKey
+): Promise<EpochConfig> {
+  const daoInfo = await provider.connection.getAccountInfo(dao);
+  if (!daoInfo) {
+    return DEFAULT_EPOCH_CONFIG;
+  }
+
+  return epochConfig(coder.decode("DaoV0", daoInfo.data));
+}
+
+async function subDaoEpochConfig(
+  provider: Provider,
+  subDao: PublicKey
+): Promise<EpochConfig> {
+  const subDaoInfo = await provider.connection.getAccountInfo(subDao);
+  if (!subDaoInfo) {
+    return DEFAULT_EPOCH_CONFIG;
+  }
+
+  return daoEpochConfig(provider, coder.decode("SubDaoV0", subDaoInfo.data).dao);
+}
+
+/**
+ * Instructions that touch two sub daos (ie redelegation) prefix their epoch info accounts
+ * with "source" or "target". Returns the matching sub dao account name, if any.
+ */
+function subDaoAccountFor(name: string, epochInfoAccount: string): string | undefined {
+  if (name === epochInfoAccount) {
+    return "subDao";
+  }
+  for (const prefix of ["source", "target"]) {
+    if (
+      name ===
+      prefix + epochInfoAccount[0].toUpperCase() + epochInfoAccount.slice(1)
+    ) {
+      return `${prefix}SubDao`;
+    }
+  }
+}
+
 export const subDaoEpochInfoResolver = resolveIndividual(
   async ({ provider, path, accounts }) => {
-    if (path[path.length - 1] === "subDaoEpochInfo" && accounts.registrar) {
+    const subDaoAccount = subDaoAccountFor(
+      path[path.length - 1],
+      "subDaoEpochInfo"
+    );
+    if (subDaoAccount && accounts.registrar) {
       const vsr = await init(provider as AnchorProvider, VSR_PROGRAM_ID);
       let registrar;
       try {
@@ -32,10 +87,15 @@ export const subDaoEpochInfoResolver = resolveIndividual(
       const unixTime = Number(clock!.data.readBigInt64LE(8 * 4)) + (registrar?.timeOffset.toNumber() || 0);
       const subDao = get(accounts, [
         ...path.slice(0, path.length - 1),
-        "subDao",
+        subDaoAccount,
       ]) as PublicKey;
       if (subDao) {
-        const [key] = await subDaoEpochInfoKey(subDao, unixTime, PROGRAM_ID);
+        const [key] = await subDaoEpochInfoKey(
+          subDao,
+          unixTime,
+          PROGRAM_ID,
+          await subDaoEpochConfig(provider, subDao)
+        );
 
         return key;
       }
@@ -45,7 +105,11 @@ export const subDaoEpochInfoResolver = resolveIndividual(
 
 export const closingTimeEpochInfoResolver = resolveIndividual(
   async ({ provider, path, accounts }) => {
-    if (path[path.length - 1] === "closingTimeSubDaoEpochInfo") {
+    const subDaoAccount = subDaoAccountFor(
+      path[path.length - 1],
+      "closingTimeSubDaoEpochInfo"
+    );
+    if (subDaoAccount) {
       const program = await init(
         provider as AnchorProvider,
         VSR_PROGRAM_ID,
@@ -53,7 +117,7 @@ export const closingTimeEpochInfoResolver = resolveIndividual(
 
       const subDao = get(accounts, [
         ...path.slice(0, path.length - 1),
-        "subDao",
+        subDaoAccount,
       ]) as PublicKey;
       const position = get(accounts, [
         ...path.slice(0, path.length - 1),
@@ -66,6 +130,8 @@ export const closingTimeEpochInfoResolver = resolveIndividual(
         const [key] = await subDaoEpochInfoKey(
           subDao,
           positionAcc.lockup.endTs,
+          PROGRAM_ID,
+          await subDaoEpochConfig(provider, subDao)
         );
 
         return key;
@@ -83,12 +149,16 @@ async function getSolanaUnixTimestamp(provider: Provider): Promise<bigint> {
 
 export const genesisEndEpochInfoResolver = resolveIndividual(
   async ({ provider, path, accounts }) => {
-    if (path[path.length - 1] === "genesisEndSubDaoEpochInfo") {
+    const subDaoAccount = subDaoAccountFor(
+      path[path.length - 1],
+      "genesisEndSubDaoEpochInfo"
+    );
+    if (subDaoAccount) {
       const program = await init(provider as AnchorProvider, VSR_PROGRAM_ID);
 
       const subDao = get(accounts, [
         ...path.slice(0, path.length - 1),
-        "subDao",
+        subDaoAccount,
       ]) as PublicKey;
       const position = get(accounts, [
         ...path.slice(0, path.length - 1),
@@ -108,7 +178,9 @@ export const genesisEndEpochInfoResolver = resolveIndividual(
             : positionAcc.genesisEnd;
         const [key] = await subDaoEpochInfoKey(
           subDao,
-          ts
+          ts,
+          PROGRAM_ID,
+          await subDaoEpochConfig(provider, subDao)
         );
 
         return key;
@@ -153,18 +225,65 @@ export const heliumSubDaosResolvers = combineResolvers(
     mint: "dntMint",
     owner: "positionAuthority",
   }),
+  ataResolver({
+    instruction: "batchClaimRewardsV0",
+    account: "delegatorAta",
+    mint: "dntMint",
+    owner: "positionAuthority",
+  }),
+  ataResolver({
+    instruction: "redelegateV0",
+    account: "delegatorAta",
+    mint: "dntMint",
+    owner: "positionAuthority",
+  }),
   ataResolver({
     account: "positionTokenAccount",
     mint: "mint",
     owner: "positionAuthority",
   }),
-  resolveIndividual(async ({ args, path, accounts }) => {
+  resolveIndividual(async ({ args, path, accounts, provider, idlIx }) => {
     if (path[path.length - 1] == "clockwork") {
       return THREAD_PID;
+    } else if (
+      idlIx.name === "initializeDaoV0" &&
+      path[path.length - 1] == "thread" &&
+      accounts.dao
+    ) {
+      return threadKey(accounts.dao as PublicKey, "issue_hst")[0];
+    } else if (
+      idlIx.name === "initializeSubDaoV0" &&
+      accounts.subDao &&
+      (path[path.length - 1] == "calculateThread" ||
+        path[path.length - 1] == "issueThread")
+    ) {
+      return threadKey(
+        accounts.subDao as PublicKey,
+        path[path.length - 1] == "calculateThread" ? "calculate" : "issue"
+      )[0];
+    } else if (
+      idlIx.name === "calculateUtilityScoreV0" &&
+      path[path.length - 1] == "activeDeviceAggregator" &&
+      accounts.subDao
+    ) {
+      // Sub daos with an active device authority don't read the aggregator
+      const subDaoInfo = await provider.connection.getAccountInfo(
+        accounts.subDao as PublicKey
+      );
+      if (subDaoInfo) {
+        const subDao = coder.decode("SubDaoV0", subDaoInfo.data);
+        if (subDao.activeDeviceAuthority.equals(PublicKey.default)) {
+          return subDao.activeDeviceAggregator;
+        }
+      }
     } else if (path[path.length - 1] == "prevDaoEpochInfo" && accounts.dao) {
+      const dao = accounts.dao as PublicKey;
+      const config = await daoEpochConfig(provider, dao);
       return daoEpochInfoKey(
-        accounts.dao as PublicKey,
-        (args[0].epoch.toNumber() - 1) * EPOCH_LENGTH
+        dao,
+        epochStartTs(args[0].epoch.toNumber() - 1, config),
+        PROGRAM_ID,
+        config
       )[0];
     }
   }),
@@ -78,6 +78,9 @@ IotMetadata.init({
   },
   gain: {
     type: INTEGER,
+  },
+  displayName: {
+    type: STRING,
   }
 }, { sequelize, underscored: true, modelName: 'iot_metadata'});
 
@@ -89,5 +92,11 @@ MobileMetadata.init({
   },
   location: {
     type: STRING,
+  },
+  displayName: {
+    type: STRING,
+  },
+  deviceType: {
+    type: STRING,
   }
 }, { sequelize, underscored: true, modelName: "mobile_metadata"})
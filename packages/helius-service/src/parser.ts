@@ -1,6 +1,9 @@
 import { Program } from '@project-serum/anchor';
 import { HeliumEntityManager } from '@helium/idls/lib/types/helium_entity_manager';
+import { getIotInfo, getMobileInfo } from '@helium/helium-entity-manager-sdk';
 import { PublicKey } from '@solana/web3.js';
+// @ts-ignore
+import bs58 from 'bs58';
 import { Entity, IotMetadata, MobileMetadata } from './model';
 
 type Parser = {
@@ -27,10 +30,11 @@ async function getAssetIdFromInfo(program: Program<HeliumEntityManager>, tx: any
   let infoKey = tx.message.accountKeys[ix.accounts[infoIdx]];
   let infoName = idlIx.accounts[infoIdx].name;
   let info;
+  // Infos may not have been migrated to V1 yet
   if (infoName === "iotInfo" || infoName === "info") {
-    info = await program.account.iotHotspotInfoV0.fetch(infoKey);
+    info = await getIotInfo(program as any, infoKey);
   } else if (infoName === "mobileInfo") {
-    info = await program.account.mobileHotspotInfoV0.fetch(infoKey);
+    info = await getMobileInfo(program as any, infoKey);
   }
   return info.asset;
 }
@@ -38,7 +42,12 @@ async function getAssetIdFromInfo(program: Program<HeliumEntityManager>, tx: any
 async function getKeysFromKeyToAsset(program: Program<HeliumEntityManager>, tx: any, ix: any, ixName: string): Promise<[PublicKey, string]> {
   let keyToAssetKey = findAccountKey(program, tx, ix, ixName, "keyToAsset")!;
   let keyToAsset = await program.account.keyToAssetV0.fetch(keyToAssetKey);
-  return [keyToAsset.asset, keyToAsset.entityKey.toString()];
+  const entityKey = Buffer.from(keyToAsset.entityKey);
+  // Utf8 keys are stored as the address text, b58 keys as the decoded address bytes
+  const hotspotKey = keyToAsset.keySerialization.utf8
+    ? entityKey.toString('utf-8')
+    : bs58.encode(entityKey);
+  return [keyToAsset.asset, hotspotKey];
 }
 
 export const instructionParser: Record<string, Parser>  = {
@@ -60,6 +69,7 @@ export const instructionParser: Record<string, Parser>  = {
       await MobileMetadata.upsert({
         hotspotKey,
         location: args.location ? args.location.toString() : null,
+        deviceType: Object.keys(args.deviceType)[0],
       });
     }
   },
@@ -89,6 +99,7 @@ export const instructionParser: Record<string, Parser>  = {
         await MobileMetadata.upsert({
           hotspotKey,
           location: args.location ? args.location.toString() : null,
+          deviceType: "cbrs",
         })
       }
     }
@@ -116,6 +127,8 @@ export const instructionParser: Record<string, Parser>  = {
         ...(args.location && {location: args.location.toString()}),
         ...(args.elevation && {elevation: args.elevation}),
         ...(args.gain && {gain: args.gain}),
+        // Empty string clears the display name
+        ...(args.displayName != null && {displayName: args.displayName || null}),
       }, {
         where: {hotspotKey: record.getDataValue("hotspotKey")}
       });
@@ -132,6 +145,7 @@ export const instructionParser: Record<string, Parser>  = {
       
       await MobileMetadata.update({
         ...(args.location && {location: args.location.toString()}),
+        ...(args.displayName != null && {displayName: args.displayName || null}),
       }, {
         where: {hotspotKey: record.getDataValue("hotspotKey")}
       });
@@ -4,3 +4,8 @@ process.env.ANCHOR_WALLET =
   process.env.ANCHOR_WALLET || os.homedir() + "/.config/solana/id.json";
 
 export const SOLANA_URL = process.env.SOLANA_URL || "http://127.0.0.1:8899";
+// Rewardable entity configs to read owner chosen display names from
+export const IOT_REWARDABLE_ENTITY_CONFIG =
+  process.env.IOT_REWARDABLE_ENTITY_CONFIG;
+export const MOBILE_REWARDABLE_ENTITY_CONFIG =
+  process.env.MOBILE_REWARDABLE_ENTITY_CONFIG;
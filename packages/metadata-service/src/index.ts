@@ -2,6 +2,47 @@ import cors from "@fastify/cors";
 // @ts-ignore
 import animalHash from "angry-purple-tiger";
 import Fastify, { FastifyInstance } from "fastify";
+import {
+  getIotInfo,
+  getMobileInfo,
+  init as initHem,
+  iotInfoKey,
+  mobileInfoKey,
+} from "@helium/helium-entity-manager-sdk";
+import { PublicKey } from "@solana/web3.js";
+import {
+  IOT_REWARDABLE_ENTITY_CONFIG,
+  MOBILE_REWARDABLE_ENTITY_CONFIG,
+} from "./env";
+import { provider } from "./solana";
+
+const hemProgramPromise = initHem(provider);
+
+async function getDisplayName(eccCompact: string): Promise<string | null> {
+  const program = await hemProgramPromise;
+  if (IOT_REWARDABLE_ENTITY_CONFIG) {
+    const [info] = iotInfoKey(
+      new PublicKey(IOT_REWARDABLE_ENTITY_CONFIG),
+      eccCompact
+    );
+    const iotInfo = await getIotInfo(program, info);
+    if (iotInfo?.displayName) {
+      return iotInfo.displayName;
+    }
+  }
+  if (MOBILE_REWARDABLE_ENTITY_CONFIG) {
+    const [info] = await mobileInfoKey(
+      new PublicKey(MOBILE_REWARDABLE_ENTITY_CONFIG),
+      eccCompact
+    );
+    const mobileInfo = await getMobileInfo(program, info);
+    if (mobileInfo?.displayName) {
+      return mobileInfo.displayName;
+    }
+  }
+
+  return null;
+}
 
 const server: FastifyInstance = Fastify({
   logger: true
@@ -19,8 +60,10 @@ server.get<{ Params: { eccCompact: string } }>("/:eccCompact", async (request, r
   // const [storage] = await hotspotStorageKey(bufferCompact);
   // const assetId = new PublicKey((await provider.connection.getAccountInfo(storage)).data.subarray(8, 8 + 32));
   const digest = animalHash(eccCompact);
+  const displayName = await getDisplayName(eccCompact);
 
   return {
+    // The animal name stays canonical, display names are only an attribute
     name: digest,
     description: "A hotspot NFT on Helium",
     image:
@@ -28,6 +71,9 @@ server.get<{ Params: { eccCompact: string } }>("/:eccCompact", async (request, r
     attributes: [
       { trait_type: "ecc_compact", value: eccCompact },
       { trait_type: "rewardable", value: true },
+      ...(displayName
+        ? [{ trait_type: "display_name", value: displayName }]
+        : []),
     ],
   };
 });
@@ -7,7 +7,7 @@ import { CustomAccountResolver } from "@coral-xyz/anchor/dist/cjs/program/accoun
 import { AllInstructions } from "@coral-xyz/anchor/dist/cjs/program/namespace/types";
 import { PublicKey } from "@solana/web3.js";
 import camelCase from "camelcase";
-import { Accounts, get, set } from "./utils";
+import { Accounts, get, isOmitted, set } from "./utils";
 
 type IndividualResolver = (args: {
   programId: PublicKey;
@@ -62,7 +62,7 @@ async function resolveIndividualImpl({
     } else {
       let resolved = 0;
       let value = get(accounts, newPath);
-      if (!value) {
+      if (!value && !isOmitted(accounts, newPath)) {
         value = await resolver({
           programId,
           provider,
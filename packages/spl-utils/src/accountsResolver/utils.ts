@@ -25,6 +25,18 @@ export function set(
   return accounts;
 }
 
+/**
+ * Optional accounts explicitly passed as null are left out of the instruction
+ */
+export function isOmitted(accounts: Accounts, path: string[]): boolean {
+  return (
+    path.reduce(
+      (acc: any, subPath) => acc && acc[subPath],
+      accounts as any
+    ) === null
+  );
+}
+
 export function get(accounts: Accounts, path: string[]): PublicKey | undefined {
   // Only return if pubkey
   const ret: PublicKey | Accounts | undefined = path.reduce((acc, subPath) => {
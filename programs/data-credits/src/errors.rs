@@ -17,4 +17,13 @@ pub enum DataCreditsErrors {
 
   #[msg("Arithmetic error")]
   ArithmeticError,
+
+  #[msg("Exactly one of hnt_amount or dc_amount must be provided")]
+  InvalidArgs,
+
+  #[msg("The burner doesn't hold enough HNT")]
+  InsufficientBalance,
+
+  #[msg("Pyth price is not positive")]
+  InvalidPythPrice,
 }
@@ -1,5 +1,3 @@
-use std::ops::Div;
-
 use crate::errors::*;
 use crate::DataCreditsV0;
 use anchor_lang::prelude::*;
@@ -11,15 +9,66 @@ use circuit_breaker::{
   cpi::{accounts::MintV0, mint_v0},
   CircuitBreaker, MintArgsV0, MintWindowedCircuitBreakerV0,
 };
-use pyth_sdk_solana::load_price_feed_from_account_info;
+use pyth_sdk_solana::{load_price_feed_from_account_info, Price};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct MintDataCreditsArgsV0 {
-  hnt_amount: u64,
+  pub hnt_amount: u64,
 }
 
 pub const TESTING: bool = std::option_env!("TESTING").is_some();
 
+/// Loads the HNT price from the pyth oracle, failing if it is stale or not positive.
+pub fn load_hnt_price(hnt_price_oracle: &AccountInfo) -> Result<Price> {
+  let hnt_price_oracle = load_price_feed_from_account_info(hnt_price_oracle).map_err(|e| {
+    msg!("Pyth error {}", e);
+    error!(DataCreditsErrors::PythError)
+  })?;
+
+  let current_time = Clock::get()?.unix_timestamp;
+  let hnt_price = hnt_price_oracle
+    .get_ema_price_no_older_than(current_time, if TESTING { 6000000 } else { 10 * 60 })
+    .ok_or_else(|| error!(DataCreditsErrors::PythPriceNotFound))?;
+  require_gt!(hnt_price.price, 0, DataCreditsErrors::InvalidPythPrice);
+
+  Ok(hnt_price)
+}
+
+// price * hnt_amount / 10^(8 + expo - 5)
+fn price_and_normalize(hnt_price: &Price) -> Result<(u128, u128)> {
+  let price_expo = -hnt_price.expo;
+  let right_shift = 8 + price_expo - 5;
+  let normalize = u32::try_from(right_shift)
+    .ok()
+    .and_then(|shift| 10_u128.checked_pow(shift))
+    .ok_or_else(|| error!(DataCreditsErrors::ArithmeticError))?;
+  let price =
+    u128::try_from(hnt_price.price).map_err(|_| error!(DataCreditsErrors::InvalidPythPrice))?;
+
+  Ok((price, normalize))
+}
+
+/// The DC minted for burning `hnt_amount` HNT at `hnt_price`, rounded down.
+pub fn dc_for_hnt(hnt_price: &Price, hnt_amount: u64) -> Result<u64> {
+  let (price, normalize) = price_and_normalize(hnt_price)?;
+  u128::from(hnt_amount)
+    .checked_mul(price)
+    .and_then(|n| n.checked_div(normalize))
+    .and_then(|n| u64::try_from(n).ok())
+    .ok_or_else(|| error!(DataCreditsErrors::ArithmeticError))
+}
+
+/// The HNT to burn for `dc_amount` DC at `hnt_price`, rounded up so it always covers the DC.
+pub fn hnt_for_dc(hnt_price: &Price, dc_amount: u64) -> Result<u64> {
+  let (price, normalize) = price_and_normalize(hnt_price)?;
+  u128::from(dc_amount)
+    .checked_mul(normalize)
+    .and_then(|n| n.checked_add(price.checked_sub(1)?))
+    .and_then(|n| n.checked_div(price))
+    .and_then(|n| u64::try_from(n).ok())
+    .ok_or_else(|| error!(DataCreditsErrors::ArithmeticError))
+}
+
 #[derive(Accounts)]
 #[instruction(args: MintDataCreditsArgsV0)]
 pub struct MintDataCreditsV0<'info> {
@@ -132,31 +181,8 @@ pub fn handler(ctx: Context<MintDataCreditsV0>, args: MintDataCreditsArgsV0) ->
     token::thaw_account(ctx.accounts.thaw_ctx().with_signer(signer_seeds))?;
   }
 
-  let hnt_price_oracle = load_price_feed_from_account_info(&ctx.accounts.hnt_price_oracle)
-    .map_err(|e| {
-      msg!("Pyth error {}", e);
-      error!(DataCreditsErrors::PythError)
-    })?;
-
-  let current_time = Clock::get()?.unix_timestamp;
-  let hnt_price = hnt_price_oracle
-    .get_ema_price_no_older_than(current_time, if TESTING { 6000000 } else { 10 * 60 })
-    .ok_or_else(|| error!(DataCreditsErrors::PythPriceNotFound))?;
-
-  // price * hnt_amount / 10^(8 + expo - 5)
-  let price_expo = -hnt_price.expo;
-  let right_shift = 8 + price_expo - 5;
-  let normalize = 10_u64
-    .checked_pow(u32::try_from(right_shift).unwrap())
-    .ok_or_else(|| error!(DataCreditsErrors::ArithmeticError))?;
-
-  let dc_amount = u64::try_from(
-    u128::from(args.hnt_amount)
-      .checked_mul(u128::try_from(hnt_price.price).unwrap())
-      .ok_or_else(|| error!(DataCreditsErrors::ArithmeticError))?
-      .div(u128::from(normalize)),
-  )
-  .map_err(|_| error!(DataCreditsErrors::ArithmeticError))?;
+  let hnt_price = load_hnt_price(&ctx.accounts.hnt_price_oracle)?;
+  let dc_amount = dc_for_hnt(&hnt_price, args.hnt_amount)?;
 
   msg!(
     "HNT Price is {} * 10^{}, issuing {} data credits",
@@ -166,7 +192,6 @@ pub fn handler(ctx: Context<MintDataCreditsV0>, args: MintDataCreditsArgsV0) ->
   );
 
   // mint the new tokens to recipient
-  // TODO needs to mint at an oracle provided rate to hnt
   mint_v0(
     ctx.accounts.mint_ctx().with_signer(signer_seeds),
     MintArgsV0 { amount: dc_amount },
@@ -0,0 +1,162 @@
+use super::mint_data_credits_v0::{dc_for_hnt, hnt_for_dc, load_hnt_price};
+use crate::errors::*;
+use crate::DataCreditsV0;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+  associated_token::AssociatedToken,
+  token::{self, Burn, FreezeAccount, Mint, ThawAccount, Token, TokenAccount},
+};
+use circuit_breaker::{
+  cpi::{accounts::MintV0, mint_v0},
+  CircuitBreaker, MintArgsV0, MintWindowedCircuitBreakerV0,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct MintDataCreditsArgsV1 {
+  // Exactly one of these is set. With `dc_amount`, the HNT needed at the oracle price is burned.
+  pub hnt_amount: Option<u64>,
+  pub dc_amount: Option<u64>,
+}
+
+/// Same as `mint_data_credits_v0`, but can also mint an exact amount of DC.
+#[derive(Accounts)]
+pub struct MintDataCreditsV1<'info> {
+  #[account(
+    seeds = [
+      "dc".as_bytes(),
+      dc_mint.key().as_ref(),
+    ],
+    bump = data_credits.data_credits_bump,
+    has_one = hnt_mint,
+    has_one = dc_mint,
+    has_one = hnt_price_oracle
+  )]
+  pub data_credits: Box<Account<'info, DataCreditsV0>>,
+  /// CHECK: Checked via load call in handler
+  pub hnt_price_oracle: AccountInfo<'info>,
+
+  // hnt tokens from this account are burned
+  #[account(
+    mut,
+    constraint = burner.mint == hnt_mint.key(),
+    has_one = owner,
+  )]
+  pub burner: Box<Account<'info, TokenAccount>>,
+  #[account(
+    init_if_needed,
+    payer = owner,
+    associated_token::mint = dc_mint,
+    associated_token::authority = recipient,
+  )]
+  pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+  /// CHECK: DC credits sent here
+  pub recipient: AccountInfo<'info>,
+
+  #[account(mut)]
+  pub owner: Signer<'info>,
+
+  #[account(mut)]
+  pub hnt_mint: Box<Account<'info, Mint>>,
+  #[account(mut)]
+  pub dc_mint: Box<Account<'info, Mint>>,
+  /// CHECK: Verified by cpi
+  #[account(
+    mut,
+    seeds = ["mint_windowed_breaker".as_bytes(), dc_mint.key().as_ref()],
+    seeds::program = circuit_breaker_program.key(),
+    bump = circuit_breaker.bump_seed
+  )]
+  pub circuit_breaker: Box<Account<'info, MintWindowedCircuitBreakerV0>>,
+  pub circuit_breaker_program: Program<'info, CircuitBreaker>,
+  pub token_program: Program<'info, Token>,
+  pub system_program: Program<'info, System>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> MintDataCreditsV1<'info> {
+  fn burn_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+    let cpi_accounts = Burn {
+      mint: self.hnt_mint.to_account_info(),
+      from: self.burner.to_account_info(),
+      authority: self.owner.to_account_info(),
+    };
+
+    CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+  }
+
+  fn thaw_ctx(&self) -> CpiContext<'_, '_, '_, 'info, ThawAccount<'info>> {
+    let cpi_accounts = ThawAccount {
+      account: self.recipient_token_account.to_account_info(),
+      mint: self.dc_mint.to_account_info(),
+      authority: self.data_credits.to_account_info(),
+    };
+    CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+  }
+
+  fn mint_ctx(&self) -> CpiContext<'_, '_, '_, 'info, MintV0<'info>> {
+    let cpi_accounts = MintV0 {
+      mint: self.dc_mint.to_account_info(),
+      to: self.recipient_token_account.to_account_info(),
+      mint_authority: self.data_credits.to_account_info(),
+      token_program: self.token_program.to_account_info(),
+      circuit_breaker: self.circuit_breaker.to_account_info(),
+    };
+    CpiContext::new(self.circuit_breaker_program.to_account_info(), cpi_accounts)
+  }
+
+  fn freeze_ctx(&self) -> CpiContext<'_, '_, '_, 'info, FreezeAccount<'info>> {
+    let cpi_accounts = FreezeAccount {
+      account: self.recipient_token_account.to_account_info(),
+      mint: self.dc_mint.to_account_info(),
+      authority: self.data_credits.to_account_info(),
+    };
+    CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+  }
+}
+
+pub fn handler(ctx: Context<MintDataCreditsV1>, args: MintDataCreditsArgsV1) -> Result<()> {
+  let signer_seeds: &[&[&[u8]]] = &[&[
+    b"dc",
+    ctx.accounts.dc_mint.to_account_info().key.as_ref(),
+    &[ctx.accounts.data_credits.data_credits_bump],
+  ]];
+
+  let hnt_price = load_hnt_price(&ctx.accounts.hnt_price_oracle)?;
+  let (hnt_amount, dc_amount) = match (args.hnt_amount, args.dc_amount) {
+    (Some(hnt_amount), None) => (hnt_amount, dc_for_hnt(&hnt_price, hnt_amount)?),
+    (None, Some(dc_amount)) => (hnt_for_dc(&hnt_price, dc_amount)?, dc_amount),
+    _ => return Err(error!(DataCreditsErrors::InvalidArgs)),
+  };
+
+  require_gte!(
+    ctx.accounts.burner.amount,
+    hnt_amount,
+    DataCreditsErrors::InsufficientBalance
+  );
+
+  msg!(
+    "HNT Price is {} * 10^{}, burning {} hnt and issuing {} data credits",
+    hnt_price.price,
+    hnt_price.expo,
+    hnt_amount,
+    dc_amount
+  );
+
+  // burn the hnt tokens
+  token::burn(ctx.accounts.burn_ctx(), hnt_amount)?;
+
+  // unfreeze the recipient_token_account if necessary
+  if ctx.accounts.recipient_token_account.is_frozen() {
+    token::thaw_account(ctx.accounts.thaw_ctx().with_signer(signer_seeds))?;
+  }
+
+  // mint the new tokens to recipient
+  mint_v0(
+    ctx.accounts.mint_ctx().with_signer(signer_seeds),
+    MintArgsV0 { amount: dc_amount },
+  )?;
+
+  token::freeze_account(ctx.accounts.freeze_ctx().with_signer(signer_seeds))?;
+
+  Ok(())
+}
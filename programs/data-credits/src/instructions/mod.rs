@@ -3,6 +3,7 @@ pub mod delegate_data_credits_v0;
 pub mod genesis_issue_delegated_data_credits_v0;
 pub mod initialize_data_credits_v0;
 pub mod mint_data_credits_v0;
+pub mod mint_data_credits_v1;
 pub mod update_data_credits_v0;
 
 pub use burn::*;
@@ -10,4 +11,5 @@ pub use delegate_data_credits_v0::*;
 pub use genesis_issue_delegated_data_credits_v0::*;
 pub use initialize_data_credits_v0::*;
 pub use mint_data_credits_v0::*;
+pub use mint_data_credits_v1::*;
 pub use update_data_credits_v0::*;
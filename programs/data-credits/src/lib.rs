@@ -29,6 +29,13 @@ pub mod data_credits {
     instructions::mint_data_credits_v0::handler(ctx, args)
   }
 
+  pub fn mint_data_credits_v1(
+    ctx: Context<MintDataCreditsV1>,
+    args: MintDataCreditsArgsV1,
+  ) -> Result<()> {
+    instructions::mint_data_credits_v1::handler(ctx, args)
+  }
+
   pub fn genesis_issue_delegated_data_credits_v0(
     ctx: Context<GenesisIssueDelegatedDataCreditsV0>,
     args: GenesisIssueDelegatedDataCreditsArgsV0,
@@ -1 +1,6 @@
 pub static HOTSPOT_METADATA_URL: &str = "https://entities.nft.test-helium.com";
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 32;
+// Bounded by compute, larger batches also need a lookup table to fit in a transaction
+pub const MAX_ISSUE_ENTITIES_BATCH_SIZE: usize = 8;
+// Keeps the maker account small enough to resize in one instruction
+pub const MAX_MAKER_TREES: usize = 16;
@@ -16,4 +16,55 @@ pub enum ErrorCode {
 
   #[msg("The realloc increase was too large")]
   InvalidDataIncrease,
+
+  #[msg("Elevation or gain is outside the range allowed by the rewardable entity config")]
+  InvalidIotSettings,
+
+  #[msg("The maker's DC escrow doesn't have enough DC for this operation")]
+  InsufficientMakerEscrowBalance,
+
+  #[msg("Entity key is not a valid helium public key for its key serialization")]
+  InvalidHeliumKey,
+
+  #[msg("The rewardable entity config has no fees for this mobile device type")]
+  InvalidDeviceType,
+
+  #[msg("Paying the fee would burn more HNT than max_hnt allows")]
+  HntSlippageExceeded,
+
+  #[msg("The maker hasn't bonded enough HNT to be approved")]
+  InsufficientMakerBond,
+
+  #[msg("The maker bond is locked until all approvals are revoked and the cooldown has passed")]
+  MakerBondLocked,
+
+  #[msg("Invalid number of entities or accounts in the batch")]
+  InvalidBatchSize,
+
+  #[msg("The maker tree doesn't have room for the batch")]
+  TreeFull,
+
+  #[msg("Entity key was already issued")]
+  EntityKeyAlreadyIssued,
+
+  #[msg("The hotspot hasn't changed owners since it was last reset")]
+  OwnerUnchanged,
+
+  #[msg("The maker already has the maximum number of merkle trees")]
+  TooManyMakerTrees,
+
+  #[msg("The rewardable entity config isn't a mobile config")]
+  InvalidMobileSettings,
+
+  #[msg("The maker has no approval for this config, and no revocation still in its cooldown")]
+  MakerNotSlashable,
+
+  #[msg("Can't slash more than the bond held for this config")]
+  SlashExceedsMakerBond,
+
+  #[msg("The maker approval is already counted")]
+  MakerApprovalAlreadyTracked,
+
+  #[msg("The maker may have approvals from before bonding that aren't counted yet")]
+  UntrackedMakerApprovals,
 }
@@ -0,0 +1,19 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use shared_utils::accept_authority;
+
+#[derive(Accounts)]
+pub struct AcceptMakerUpdateAuthorityV0<'info> {
+  pub new_update_authority: Signer<'info>,
+  #[account(mut)]
+  pub maker: Box<Account<'info, MakerV0>>,
+}
+
+pub fn handler(ctx: Context<AcceptMakerUpdateAuthorityV0>) -> Result<()> {
+  let maker: &mut MakerV0 = &mut ctx.accounts.maker;
+  accept_authority(
+    &mut maker.update_authority,
+    &mut maker.pending_update_authority,
+    ctx.accounts.new_update_authority.key,
+  )
+}
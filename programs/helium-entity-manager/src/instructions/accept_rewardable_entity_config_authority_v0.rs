@@ -0,0 +1,19 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use shared_utils::accept_authority;
+
+#[derive(Accounts)]
+pub struct AcceptRewardableEntityConfigAuthorityV0<'info> {
+  pub new_authority: Signer<'info>,
+  #[account(mut)]
+  pub rewardable_entity_config: Box<Account<'info, RewardableEntityConfigV0>>,
+}
+
+pub fn handler(ctx: Context<AcceptRewardableEntityConfigAuthorityV0>) -> Result<()> {
+  let config: &mut RewardableEntityConfigV0 = &mut ctx.accounts.rewardable_entity_config;
+  accept_authority(
+    &mut config.authority,
+    &mut config.pending_authority,
+    ctx.accounts.new_authority.key,
+  )
+}
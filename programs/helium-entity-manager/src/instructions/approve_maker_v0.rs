@@ -1,5 +1,17 @@
+use crate::error::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_spl::{
+  associated_token::AssociatedToken,
+  token::{Mint, Token, TokenAccount},
+};
+use helium_sub_daos::DaoV0;
+
+#[event]
+pub struct MakerApprovedV0 {
+  pub rewardable_entity_config: Pubkey,
+  pub maker: Pubkey,
+}
 
 #[derive(Accounts)]
 pub struct ApproveMakerV0<'info> {
@@ -12,7 +24,25 @@ pub struct ApproveMakerV0<'info> {
   pub rewardable_entity_config: Box<Account<'info, RewardableEntityConfigV0>>,
   pub authority: Signer<'info>,
 
+  #[account(
+    mut,
+    has_one = dao,
+  )]
   pub maker: Box<Account<'info, MakerV0>>,
+  #[account(
+    has_one = hnt_mint,
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  pub hnt_mint: Box<Account<'info, Mint>>,
+  /// HNT the maker has bonded, see `bond_maker_v0`. Each approval holds its own part of it.
+  #[account(
+    init_if_needed,
+    payer = payer,
+    associated_token::mint = hnt_mint,
+    associated_token::authority = maker,
+    constraint = maker_bond.amount >= maker.locked_bond.saturating_add(rewardable_entity_config.maker_bond) @ ErrorCode::InsufficientMakerBond,
+  )]
+  pub maker_bond: Box<Account<'info, TokenAccount>>,
   #[account(
     init,
     payer = payer,
@@ -21,6 +51,8 @@ pub struct ApproveMakerV0<'info> {
     bump,
   )]
   pub maker_approval: Box<Account<'info, MakerApprovalV0>>,
+  pub token_program: Program<'info, Token>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
   pub system_program: Program<'info, System>,
 }
 
@@ -29,6 +61,19 @@ pub fn handler(ctx: Context<ApproveMakerV0>) -> Result<()> {
     rewardable_entity_config: ctx.accounts.rewardable_entity_config.key(),
     maker: ctx.accounts.maker.key(),
     bump_seed: ctx.bumps["maker_approval"],
+    bond_amount: ctx.accounts.rewardable_entity_config.maker_bond,
+    tracked: true,
+  });
+  let maker = &mut ctx.accounts.maker;
+  maker.num_approvals = maker.num_approvals.checked_add(1).unwrap();
+  maker.locked_bond = maker
+    .locked_bond
+    .checked_add(ctx.accounts.rewardable_entity_config.maker_bond)
+    .unwrap();
+
+  emit!(MakerApprovedV0 {
+    rewardable_entity_config: ctx.accounts.rewardable_entity_config.key(),
+    maker: ctx.accounts.maker.key(),
   });
 
   Ok(())
@@ -0,0 +1,64 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+  associated_token::AssociatedToken,
+  token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use helium_sub_daos::DaoV0;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct BondMakerArgsV0 {
+  pub amount: u64,
+}
+
+/// Stakes HNT behind a maker. The bond is held in the maker's HNT associated token
+/// account and must cover the rewardable entity config's `maker_bond` to be approved.
+#[derive(Accounts)]
+#[instruction(args: BondMakerArgsV0)]
+pub struct BondMakerV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub owner: Signer<'info>,
+  #[account(
+    mut,
+    constraint = source.mint == hnt_mint.key(),
+    has_one = owner,
+  )]
+  pub source: Box<Account<'info, TokenAccount>>,
+  #[account(
+    has_one = dao,
+  )]
+  pub maker: Box<Account<'info, MakerV0>>,
+  #[account(
+    has_one = hnt_mint,
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  pub hnt_mint: Box<Account<'info, Mint>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    associated_token::mint = hnt_mint,
+    associated_token::authority = maker,
+  )]
+  pub maker_bond: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<BondMakerV0>, args: BondMakerArgsV0) -> Result<()> {
+  token::transfer(
+    CpiContext::new(
+      ctx.accounts.token_program.to_account_info(),
+      Transfer {
+        from: ctx.accounts.source.to_account_info(),
+        to: ctx.accounts.maker_bond.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+      },
+    ),
+    args.amount,
+  )?;
+
+  Ok(())
+}
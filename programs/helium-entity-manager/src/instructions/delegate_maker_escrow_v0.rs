@@ -0,0 +1,118 @@
+use crate::error::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+  associated_token::AssociatedToken,
+  token::{Mint, Token, TokenAccount},
+};
+use data_credits::{
+  cpi::{accounts::DelegateDataCreditsV0, delegate_data_credits_v0},
+  program::DataCredits,
+  DataCreditsV0, DelegateDataCreditsArgsV0,
+};
+use helium_sub_daos::{DaoV0, SubDaoV0};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct DelegateMakerEscrowArgsV0 {
+  pub amount: u64,
+  pub router_key: String,
+}
+
+/// DC can't be transferred between wallets, so nothing is returned to the maker. Instead, the
+/// maker's update authority can delegate unused escrow to a router on the given sub dao, where it
+/// is burned for data transfer like any other delegated DC.
+#[derive(Accounts)]
+#[instruction(args: DelegateMakerEscrowArgsV0)]
+pub struct DelegateMakerEscrowV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub update_authority: Signer<'info>,
+  #[account(
+    has_one = update_authority,
+    has_one = dao,
+  )]
+  pub maker: Box<Account<'info, MakerV0>>,
+  #[account(
+    mut,
+    associated_token::mint = dc_mint,
+    associated_token::authority = maker,
+  )]
+  pub maker_escrow: Box<Account<'info, TokenAccount>>,
+  /// CHECK: Initialized by cpi
+  #[account(mut)]
+  pub delegated_data_credits: UncheckedAccount<'info>,
+  /// CHECK: Initialized by cpi
+  #[account(mut)]
+  pub escrow_account: UncheckedAccount<'info>,
+
+  #[account(
+    has_one = dc_mint,
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  #[account(
+    has_one = dao,
+  )]
+  pub sub_dao: Box<Account<'info, SubDaoV0>>,
+  pub dc_mint: Box<Account<'info, Mint>>,
+  #[account(
+    seeds = [
+      "dc".as_bytes(),
+      dc_mint.key().as_ref(),
+    ],
+    seeds::program = data_credits_program.key(),
+    bump = data_credits.data_credits_bump,
+    has_one = dc_mint,
+  )]
+  pub data_credits: Box<Account<'info, DataCreditsV0>>,
+
+  pub data_credits_program: Program<'info, DataCredits>,
+  pub token_program: Program<'info, Token>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> DelegateMakerEscrowV0<'info> {
+  fn delegate_ctx(&self) -> CpiContext<'_, '_, '_, 'info, DelegateDataCreditsV0<'info>> {
+    let cpi_accounts = DelegateDataCreditsV0 {
+      delegated_data_credits: self.delegated_data_credits.to_account_info(),
+      data_credits: self.data_credits.to_account_info(),
+      dc_mint: self.dc_mint.to_account_info(),
+      dao: self.dao.to_account_info(),
+      sub_dao: self.sub_dao.to_account_info(),
+      owner: self.maker.to_account_info(),
+      from_account: self.maker_escrow.to_account_info(),
+      escrow_account: self.escrow_account.to_account_info(),
+      payer: self.payer.to_account_info(),
+      associated_token_program: self.associated_token_program.to_account_info(),
+      token_program: self.token_program.to_account_info(),
+      system_program: self.system_program.to_account_info(),
+    };
+
+    CpiContext::new(self.data_credits_program.to_account_info(), cpi_accounts)
+  }
+}
+
+pub fn handler(ctx: Context<DelegateMakerEscrowV0>, args: DelegateMakerEscrowArgsV0) -> Result<()> {
+  require_gte!(
+    ctx.accounts.maker_escrow.amount,
+    args.amount,
+    ErrorCode::InsufficientMakerEscrowBalance
+  );
+
+  let maker_seeds: &[&[&[u8]]] = &[&[
+    b"maker",
+    ctx.accounts.maker.dao.as_ref(),
+    ctx.accounts.maker.name.as_bytes(),
+    &[ctx.accounts.maker.bump_seed],
+  ]];
+
+  delegate_data_credits_v0(
+    ctx.accounts.delegate_ctx().with_signer(maker_seeds),
+    DelegateDataCreditsArgsV0 {
+      amount: args.amount,
+      router_key: args.router_key,
+    },
+  )?;
+
+  Ok(())
+}
@@ -5,8 +5,6 @@ use crate::error::ErrorCode;
 use crate::{id, state::*};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::hash::hash;
-use anchor_lang::solana_program::program::{invoke, invoke_signed};
-use anchor_lang::solana_program::system_instruction::{self, create_account};
 use anchor_spl::token::Mint;
 use angry_purple_tiger::AnimalName;
 use helium_sub_daos::DaoV0;
@@ -18,6 +16,7 @@ use mpl_bubblegum::{
   program::Bubblegum,
   state::TreeConfig,
 };
+use shared_utils::init_pda_account;
 use spl_account_compression::{program::SplAccountCompression, Noop};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -30,6 +29,16 @@ pub struct GenesisIssueHotspotArgsV0 {
   pub num_location_asserts: u16,
 }
 
+#[event]
+pub struct HotspotGenesisIssuedV0 {
+  pub dao: Pubkey,
+  pub entity_key: Vec<u8>,
+  pub asset_id: Pubkey,
+  pub location: Option<u64>,
+  // Whether a mobile info was also created
+  pub is_mobile: bool,
+}
+
 #[derive(Accounts)]
 #[instruction(args: GenesisIssueHotspotArgsV0)]
 pub struct GenesisIssueHotspotV0<'info> {
@@ -76,7 +85,7 @@ pub struct GenesisIssueHotspotV0<'info> {
   #[account(
     init,
     payer = lazy_signer,
-    space = IOT_HOTSPOT_INFO_SIZE,
+    space = 60 + std::mem::size_of::<IotHotspotInfoV1>(),
     seeds = [
       "iot_info".as_bytes(),
       rewardable_entity_config.key().as_ref(),
@@ -84,7 +93,7 @@ pub struct GenesisIssueHotspotV0<'info> {
     ],
     bump
   )]
-  pub info: Box<Account<'info, IotHotspotInfoV0>>,
+  pub info: Box<Account<'info, IotHotspotInfoV1>>,
   /// CHECK: Handled by cpi
   #[account(mut)]
   pub tree_authority: Account<'info, TreeConfig>,
@@ -155,11 +164,17 @@ pub fn handler<'info>(
     &[ctx.accounts.maker.bump_seed],
   ]];
 
+  let metadata_url = if ctx.accounts.dao.entity_metadata_url.is_empty() {
+    HOTSPOT_METADATA_URL
+  } else {
+    ctx.accounts.dao.entity_metadata_url.as_str()
+  };
+
   let name = animal_name.to_string();
   let metadata = MetadataArgs {
     name: name[..min(name.len(), 32)].to_owned(),
     symbol: String::from("HOTSPOT"),
-    uri: format!("{}/{}", HOTSPOT_METADATA_URL, key_str),
+    uri: format!("{}/{}", metadata_url, key_str),
     collection: Some(Collection {
       key: ctx.accounts.collection.key(),
       verified: false, // Verified in cpi
@@ -198,9 +213,11 @@ pub fn handler<'info>(
     entity_key: args.entity_key.clone(),
     dao: ctx.accounts.dao.key(),
     bump_seed: ctx.bumps["key_to_asset"],
+    // Genesis keys are always decoded ecc compact addresses from the legacy chain
+    key_serialization: KeySerializationV0::B58,
   });
 
-  ctx.accounts.info.set_inner(IotHotspotInfoV0 {
+  ctx.accounts.info.set_inner(IotHotspotInfoV1 {
     asset: asset_id,
     location: args.location,
     bump_seed: ctx.bumps["info"],
@@ -208,6 +225,11 @@ pub fn handler<'info>(
     gain: args.gain,
     is_full_hotspot: args.is_full_hotspot,
     num_location_asserts: args.num_location_asserts,
+    region: None,
+    display_name: None,
+    last_asserted_ts: None,
+    owner: Some(ctx.accounts.recipient.key()),
+    transferred: false,
   });
 
   // The remaining account should be the mobile info if this
@@ -224,75 +246,40 @@ pub fn handler<'info>(
     let (address, bump_seed) = Pubkey::find_program_address(seeds, &id());
     require_eq!(address, account_info.key());
 
-    let serialized_data = MobileHotspotInfoV0 {
-      asset: asset_id,
-      location: args.location,
-      num_location_asserts: args.num_location_asserts,
-      is_full_hotspot: args.is_full_hotspot,
-      bump_seed,
-    }
-    .try_to_vec()?;
-    let account_size = MOBILE_HOTSPOT_INFO_SIZE;
-
-    let mut signers_seeds = seeds.to_vec();
-    let bump = &[bump_seed];
-    signers_seeds.push(bump);
-
-    let rent = Rent::get()?;
-    let total_lamports = rent.minimum_balance(account_size);
-    let payer_info = ctx.accounts.lazy_signer.to_account_info();
-    let system_info = ctx.accounts.system_program.to_account_info();
-
-    // If the account has some lamports already it can't be created using create_account instruction
-    // Anybody can send lamports to a PDA and by doing so create the account and perform DoS attack by blocking create_account
-    if account_info.lamports() > 0 {
-      let top_up_lamports = total_lamports.saturating_sub(account_info.lamports());
-
-      if top_up_lamports > 0 {
-        invoke(
-          &system_instruction::transfer(payer_info.key, account_info.key, top_up_lamports),
-          &[
-            payer_info.clone(),
-            account_info.clone(),
-            system_info.clone(),
-          ],
-        )?;
-      }
-
-      invoke_signed(
-        &system_instruction::allocate(account_info.key, account_size as u64),
-        &[account_info.clone(), system_info.clone()],
-        &[&signers_seeds[..]],
-      )?;
-
-      invoke_signed(
-        &system_instruction::assign(account_info.key, &id()),
-        &[account_info.clone(), system_info.clone()],
-        &[&signers_seeds[..]],
-      )?;
-    } else {
-      // If the PDA doesn't exist use create_account to use lower compute budget
-      let create_account_instruction = create_account(
-        payer_info.key,
-        account_info.key,
-        total_lamports,
-        account_size as u64,
-        &id(),
-      );
-
-      invoke_signed(
-        &create_account_instruction,
-        &[
-          payer_info.clone(),
-          account_info.clone(),
-          system_info.clone(),
-        ],
-        &[&signers_seeds[..]],
-      )?;
-    }
-
-    account_info.data.borrow_mut()[..serialized_data.len()].copy_from_slice(&serialized_data);
+    init_pda_account(
+      &ctx.accounts.lazy_signer.to_account_info(),
+      &ctx.accounts.system_program.to_account_info(),
+      account_info,
+      &[
+        b"mobile_info",
+        mobile_rewardable_entity_config.key.as_ref(),
+        &hash,
+        &[bump_seed],
+      ],
+      60 + std::mem::size_of::<MobileHotspotInfoV1>(),
+      &MobileHotspotInfoV1 {
+        asset: asset_id,
+        location: args.location,
+        num_location_asserts: args.num_location_asserts,
+        is_full_hotspot: args.is_full_hotspot,
+        bump_seed,
+        display_name: None,
+        // Genesis mobile hotspots are all cbrs radios
+        device_type: MobileDeviceTypeV0::Cbrs,
+        last_asserted_ts: None,
+        owner: Some(ctx.accounts.recipient.key()),
+        transferred: false,
+      },
+    )?;
   }
 
+  emit!(HotspotGenesisIssuedV0 {
+    dao: ctx.accounts.dao.key(),
+    entity_key: args.entity_key,
+    asset_id,
+    location: args.location,
+    is_mobile: !ctx.remaining_accounts.is_empty(),
+  });
+
   Ok(())
 }
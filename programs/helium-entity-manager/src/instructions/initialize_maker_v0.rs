@@ -20,6 +20,15 @@ pub struct InitializeMakerArgsV0 {
   pub metadata_url: String,
 }
 
+#[event]
+pub struct MakerInitializedV0 {
+  pub maker: Pubkey,
+  pub dao: Pubkey,
+  pub name: String,
+  pub issuing_authority: Pubkey,
+  pub update_authority: Pubkey,
+}
+
 #[derive(Accounts)]
 #[instruction(args: InitializeMakerArgsV0)]
 pub struct InitializeMakerV0<'info> {
@@ -151,6 +160,14 @@ pub fn handler(ctx: Context<InitializeMakerV0>, args: InitializeMakerArgsV0) ->
     Some(0),
   )?;
 
+  emit!(MakerInitializedV0 {
+    maker: ctx.accounts.maker.key(),
+    dao: ctx.accounts.dao.key(),
+    name: args.name.clone(),
+    issuing_authority: args.issuing_authority,
+    update_authority: args.update_authority,
+  });
+
   ctx.accounts.maker.set_inner(MakerV0 {
     name: args.name,
     issuing_authority: args.issuing_authority,
@@ -161,6 +178,12 @@ pub fn handler(ctx: Context<InitializeMakerV0>, args: InitializeMakerArgsV0) ->
     bump_seed: ctx.bumps["maker"],
     collection_bump_seed: ctx.bumps["collection"],
     dao: ctx.accounts.dao.key(),
+    merkle_trees: vec![],
+    num_approvals: 0,
+    locked_bond: 0,
+    bond_unlock_ts: 0,
+    pending_update_authority: None,
+    approvals_tracked: true,
   });
 
   Ok(())
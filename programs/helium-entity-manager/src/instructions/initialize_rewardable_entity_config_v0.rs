@@ -2,6 +2,7 @@ use crate::error::ErrorCode;
 use crate::state::*;
 use anchor_lang::prelude::*;
 use helium_sub_daos::SubDaoV0;
+use shared_utils::resize_to_fit;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct InitializeRewardableEntityConfigArgsV0 {
@@ -47,7 +48,17 @@ pub fn handler(
       authority: ctx.accounts.authority.key(),
       bump_seed: ctx.bumps["rewardable_entity_config"],
       settings: args.settings,
+      maker_bond: 0,
+      maker_bond_cooldown_seconds: 0,
+      transfer_reassert_dc_fee: None,
+      pending_authority: None,
     });
 
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.rewardable_entity_config,
+  )?;
+
   Ok(())
 }
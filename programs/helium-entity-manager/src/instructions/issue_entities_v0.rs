@@ -0,0 +1,226 @@
+use crate::constants::MAX_ISSUE_ENTITIES_BATCH_SIZE;
+use crate::entity_key_hash;
+use crate::error::ErrorCode;
+use crate::id;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use helium_sub_daos::DaoV0;
+use mpl_bubblegum::state::TreeConfig;
+use mpl_bubblegum::utils::get_asset_id;
+use mpl_bubblegum::{
+  cpi::{accounts::MintToCollectionV1, mint_to_collection_v1},
+  program::Bubblegum,
+};
+use shared_utils::init_pda_account;
+use spl_account_compression::{program::SplAccountCompression, Noop};
+
+use super::issue_entity_v0::{hotspot_metadata, EntityIssuedV0, IssueEntityArgsV0};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct IssueEntitiesArgsV0 {
+  pub entities: Vec<IssueEntityArgsV0>,
+}
+
+/// Issues several entities into the maker's current tree in one transaction. For each
+/// entity the remaining accounts hold its key to asset PDA followed by its recipient.
+/// Issuance is all or nothing, so a key that was already issued fails the whole batch.
+#[derive(Accounts)]
+#[instruction(args: IssueEntitiesArgsV0)]
+pub struct IssueEntitiesV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub issuing_authority: Signer<'info>,
+  pub collection: Box<Account<'info, Mint>>,
+  /// CHECK: Handled by cpi
+  #[account(
+    mut,
+    seeds = ["metadata".as_bytes(), token_metadata_program.key().as_ref(), collection.key().as_ref()],
+    seeds::program = token_metadata_program.key(),
+    bump,
+  )]
+  pub collection_metadata: UncheckedAccount<'info>,
+  /// CHECK: Handled By cpi account
+  #[account(
+    seeds = ["metadata".as_bytes(), token_metadata_program.key().as_ref(), collection.key().as_ref(), "edition".as_bytes()],
+    seeds::program = token_metadata_program.key(),
+    bump,
+  )]
+  pub collection_master_edition: UncheckedAccount<'info>,
+  #[account(
+    mut,
+    has_one = issuing_authority,
+    has_one = collection,
+    has_one = merkle_tree,
+  )]
+  pub maker: Box<Account<'info, MakerV0>>,
+  /// CHECK: Signs as a verified creator to make searching easier
+  #[account(
+    seeds = [b"entity_creator", dao.key().as_ref()],
+    bump,
+  )]
+  pub entity_creator: UncheckedAccount<'info>,
+  pub dao: Box<Account<'info, DaoV0>>,
+  #[account(
+      mut,
+      seeds = [merkle_tree.key().as_ref()],
+      seeds::program = bubblegum_program.key(),
+      bump,
+  )]
+  pub tree_authority: Box<Account<'info, TreeConfig>>,
+  /// CHECK: Used in cpi
+  #[account(mut)]
+  pub merkle_tree: AccountInfo<'info>,
+  #[account(
+    seeds = ["collection_cpi".as_bytes()],
+    seeds::program = bubblegum_program.key(),
+    bump,
+  )]
+  /// CHECK: Used in cpi
+  pub bubblegum_signer: UncheckedAccount<'info>,
+
+  /// CHECK: Verified by constraint
+  #[account(address = mpl_token_metadata::ID)]
+  pub token_metadata_program: AccountInfo<'info>,
+  pub log_wrapper: Program<'info, Noop>,
+  pub bubblegum_program: Program<'info, Bubblegum>,
+  pub compression_program: Program<'info, SplAccountCompression>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> IssueEntitiesV0<'info> {
+  fn mint_to_collection_ctx(
+    &self,
+    recipient: AccountInfo<'info>,
+  ) -> CpiContext<'_, '_, '_, 'info, MintToCollectionV1<'info>> {
+    let cpi_accounts = MintToCollectionV1 {
+      tree_authority: self.tree_authority.to_account_info(),
+      leaf_delegate: recipient.clone(),
+      leaf_owner: recipient,
+      merkle_tree: self.merkle_tree.to_account_info(),
+      payer: self.payer.to_account_info(),
+      tree_delegate: self.maker.to_account_info(),
+      log_wrapper: self.log_wrapper.to_account_info(),
+      compression_program: self.compression_program.to_account_info(),
+      system_program: self.system_program.to_account_info(),
+      collection_authority: self.maker.to_account_info(),
+      collection_authority_record_pda: self.bubblegum_program.to_account_info(),
+      collection_mint: self.collection.to_account_info(),
+      collection_metadata: self.collection_metadata.to_account_info(),
+      edition_account: self.collection_master_edition.to_account_info(),
+      bubblegum_signer: self.bubblegum_signer.to_account_info(),
+      token_metadata_program: self.token_metadata_program.to_account_info(),
+    };
+    CpiContext::new(self.bubblegum_program.to_account_info(), cpi_accounts)
+  }
+}
+
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, IssueEntitiesV0<'info>>,
+  args: IssueEntitiesArgsV0,
+) -> Result<()> {
+  let count = args.entities.len();
+  require!(
+    count > 0 && count <= MAX_ISSUE_ENTITIES_BATCH_SIZE,
+    ErrorCode::InvalidBatchSize
+  );
+  require_eq!(
+    ctx.remaining_accounts.len(),
+    count * 2,
+    ErrorCode::InvalidBatchSize
+  );
+  // The batch can't roll over to the next tree part way through, so it has to fit in this one
+  require_gte!(
+    ctx
+      .accounts
+      .tree_authority
+      .total_mint_capacity
+      .saturating_sub(ctx.accounts.tree_authority.num_minted),
+    count as u64,
+    ErrorCode::TreeFull
+  );
+
+  let maker_seeds: &[&[&[u8]]] = &[&[
+    b"maker",
+    ctx.accounts.maker.dao.as_ref(),
+    ctx.accounts.maker.name.as_bytes(),
+    &[ctx.accounts.maker.bump_seed],
+  ]];
+  let entity_creator_seeds: &[&[&[u8]]] = &[&[
+    b"entity_creator",
+    ctx.accounts.dao.to_account_info().key.as_ref(),
+    &[ctx.bumps["entity_creator"]],
+  ]];
+  let mut creator = ctx.accounts.entity_creator.to_account_info();
+  creator.is_signer = true;
+  let dao = ctx.accounts.dao.key();
+
+  for (i, entity) in args.entities.into_iter().enumerate() {
+    let key_to_asset = &ctx.remaining_accounts[i * 2];
+    let recipient = &ctx.remaining_accounts[i * 2 + 1];
+
+    let hash = entity_key_hash(&entity.entity_key, entity.key_serialization);
+    let (address, bump_seed) =
+      Pubkey::find_program_address(&[b"key_to_asset", dao.as_ref(), &hash], &id());
+    require_keys_eq!(address, key_to_asset.key());
+    // Also catches a key repeated within the batch, the first one created the account
+    require!(
+      key_to_asset.data_is_empty(),
+      ErrorCode::EntityKeyAlreadyIssued
+    );
+
+    let asset_id = get_asset_id(
+      &ctx.accounts.merkle_tree.key(),
+      ctx.accounts.tree_authority.num_minted,
+    );
+    let metadata = hotspot_metadata(
+      &ctx.accounts.dao,
+      ctx.accounts.collection.key(),
+      ctx.accounts.entity_creator.key(),
+      &entity.entity_key,
+      entity.key_serialization,
+    )?;
+
+    mint_to_collection_v1(
+      ctx
+        .accounts
+        .mint_to_collection_ctx(recipient.clone())
+        .with_remaining_accounts(vec![creator.clone()])
+        .with_signer(&[maker_seeds[0], entity_creator_seeds[0]]),
+      metadata,
+    )?;
+    ctx.accounts.tree_authority.reload()?;
+
+    init_pda_account(
+      &ctx.accounts.payer.to_account_info(),
+      &ctx.accounts.system_program.to_account_info(),
+      key_to_asset,
+      &[b"key_to_asset", dao.as_ref(), &hash, &[bump_seed]],
+      8 + std::mem::size_of::<KeyToAssetV0>() + (8 * entity.entity_key.len()),
+      &KeyToAssetV0 {
+        asset: asset_id,
+        dao,
+        entity_key: entity.entity_key.clone(),
+        bump_seed,
+        key_serialization: entity.key_serialization,
+      },
+    )?;
+
+    emit!(EntityIssuedV0 {
+      dao,
+      maker: ctx.accounts.maker.key(),
+      entity_key: entity.entity_key,
+      key_serialization: entity.key_serialization,
+      asset_id,
+    });
+  }
+
+  // Roll over to the next queued tree so the following issuance doesn't fail on a full tree
+  if ctx.accounts.tree_authority.num_minted >= ctx.accounts.tree_authority.total_mint_capacity {
+    if let Some(next_tree) = ctx.accounts.maker.next_merkle_tree() {
+      ctx.accounts.maker.merkle_tree = next_tree;
+    }
+  }
+
+  Ok(())
+}
@@ -2,8 +2,8 @@ use std::cmp::min;
 
 use crate::state::*;
 use crate::{constants::HOTSPOT_METADATA_URL, error::ErrorCode};
+use crate::{entity_key_hash, entity_key_to_b58, validate_helium_key};
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
 use anchor_spl::token::Mint;
 use angry_purple_tiger::AnimalName;
 use helium_sub_daos::DaoV0;
@@ -21,6 +21,16 @@ use spl_account_compression::{program::SplAccountCompression, Noop};
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct IssueEntityArgsV0 {
   pub entity_key: Vec<u8>,
+  pub key_serialization: KeySerializationV0,
+}
+
+#[event]
+pub struct EntityIssuedV0 {
+  pub dao: Pubkey,
+  pub maker: Pubkey,
+  pub entity_key: Vec<u8>,
+  pub key_serialization: KeySerializationV0,
+  pub asset_id: Pubkey,
 }
 
 #[derive(Accounts)]
@@ -66,7 +76,7 @@ pub struct IssueEntityV0<'info> {
     seeds = [
       "key_to_asset".as_bytes(),
       dao.key().as_ref(),
-      &hash(&args.entity_key[..]).to_bytes()
+      &entity_key_hash(&args.entity_key, args.key_serialization)
     ],
     bump
   )]
@@ -124,30 +134,33 @@ impl<'info> IssueEntityV0<'info> {
   }
 }
 
-pub fn handler(ctx: Context<IssueEntityV0>, args: IssueEntityArgsV0) -> Result<()> {
-  let key_str = bs58::encode(args.entity_key.clone()).into_string();
+/// Validates the entity key and builds the cNFT metadata it's issued with
+pub fn hotspot_metadata(
+  dao: &DaoV0,
+  collection: Pubkey,
+  entity_creator: Pubkey,
+  entity_key: &[u8],
+  key_serialization: KeySerializationV0,
+) -> Result<MetadataArgs> {
+  validate_helium_key(entity_key, key_serialization)?;
+  let key_str = entity_key_to_b58(entity_key, key_serialization)?;
   let animal_name: AnimalName = key_str
     .parse()
     .map_err(|_| error!(ErrorCode::InvalidEccCompact))?;
 
-  let maker_seeds: &[&[&[u8]]] = &[&[
-    b"maker",
-    ctx.accounts.maker.dao.as_ref(),
-    ctx.accounts.maker.name.as_bytes(),
-    &[ctx.accounts.maker.bump_seed],
-  ]];
-  let asset_id = get_asset_id(
-    &ctx.accounts.merkle_tree.key(),
-    ctx.accounts.tree_authority.num_minted,
-  );
+  let metadata_url = if dao.entity_metadata_url.is_empty() {
+    HOTSPOT_METADATA_URL
+  } else {
+    dao.entity_metadata_url.as_str()
+  };
 
   let name = animal_name.to_string();
-  let metadata = MetadataArgs {
+  Ok(MetadataArgs {
     name: name[..min(name.len(), 32)].to_owned(),
     symbol: String::from("HOTSPOT"),
-    uri: format!("{}/{}", HOTSPOT_METADATA_URL, key_str),
+    uri: format!("{}/{}", metadata_url, key_str),
     collection: Some(Collection {
-      key: ctx.accounts.collection.key(),
+      key: collection,
       verified: false, // Verified in cpi
     }),
     primary_sale_happened: true,
@@ -157,12 +170,34 @@ pub fn handler(ctx: Context<IssueEntityV0>, args: IssueEntityArgsV0) -> Result<(
     uses: None,
     token_program_version: TokenProgramVersion::Original,
     creators: vec![Creator {
-      address: ctx.accounts.entity_creator.key(),
+      address: entity_creator,
       verified: true,
       share: 100,
     }],
     seller_fee_basis_points: 0,
-  };
+  })
+}
+
+pub fn handler(ctx: Context<IssueEntityV0>, args: IssueEntityArgsV0) -> Result<()> {
+  let maker_seeds: &[&[&[u8]]] = &[&[
+    b"maker",
+    ctx.accounts.maker.dao.as_ref(),
+    ctx.accounts.maker.name.as_bytes(),
+    &[ctx.accounts.maker.bump_seed],
+  ]];
+  let asset_id = get_asset_id(
+    &ctx.accounts.merkle_tree.key(),
+    ctx.accounts.tree_authority.num_minted,
+  );
+
+  let metadata = hotspot_metadata(
+    &ctx.accounts.dao,
+    ctx.accounts.collection.key(),
+    ctx.accounts.entity_creator.key(),
+    &args.entity_key,
+    args.key_serialization,
+  )?;
+
   let entity_creator_seeds: &[&[&[u8]]] = &[&[
     b"entity_creator",
     ctx.accounts.dao.to_account_info().key.as_ref(),
@@ -182,9 +217,26 @@ pub fn handler(ctx: Context<IssueEntityV0>, args: IssueEntityArgsV0) -> Result<(
   ctx.accounts.key_to_asset.set_inner(KeyToAssetV0 {
     asset: asset_id,
     dao: ctx.accounts.dao.key(),
-    entity_key: args.entity_key,
+    entity_key: args.entity_key.clone(),
     bump_seed: ctx.bumps["key_to_asset"],
+    key_serialization: args.key_serialization,
   });
 
+  emit!(EntityIssuedV0 {
+    dao: ctx.accounts.dao.key(),
+    maker: ctx.accounts.maker.key(),
+    entity_key: args.entity_key,
+    key_serialization: args.key_serialization,
+    asset_id,
+  });
+
+  // Roll over to the next queued tree so the following issuance doesn't fail on a full tree
+  ctx.accounts.tree_authority.reload()?;
+  if ctx.accounts.tree_authority.num_minted >= ctx.accounts.tree_authority.total_mint_capacity {
+    if let Some(next_tree) = ctx.accounts.maker.next_merkle_tree() {
+      ctx.accounts.maker.merkle_tree = next_tree;
+    }
+  }
+
   Ok(())
 }
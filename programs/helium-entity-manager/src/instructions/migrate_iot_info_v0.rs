@@ -0,0 +1,24 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use shared_utils::resize_to_fit;
+
+/// Permissionlessly upgrades an `IotHotspotInfoV0` to an `IotHotspotInfoV1` in place.
+/// The address doesn't change, so nothing pointing at the info needs to be updated.
+/// Instructions that write the info migrate it too, this only does it ahead of time.
+#[derive(Accounts)]
+pub struct MigrateIotInfoV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(mut)]
+  pub iot_info: Box<Account<'info, VersionedIotHotspotInfo>>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateIotInfoV0>) -> Result<()> {
+  // Written back as V1 on exit
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.iot_info,
+  )
+}
@@ -0,0 +1,23 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use shared_utils::resize_to_fit;
+
+/// Permissionlessly upgrades a `MobileHotspotInfoV0` to a `MobileHotspotInfoV1` in place.
+/// Instructions that write the info migrate it too, this only does it ahead of time.
+#[derive(Accounts)]
+pub struct MigrateMobileInfoV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(mut)]
+  pub mobile_info: Box<Account<'info, VersionedMobileHotspotInfo>>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateMobileInfoV0>) -> Result<()> {
+  // Written back as V1 on exit
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.mobile_info,
+  )
+}
@@ -1,27 +1,67 @@
+pub mod accept_maker_update_authority_v0;
+pub mod accept_rewardable_entity_config_authority_v0;
+pub mod add_maker_tree_v0;
 pub mod approve_maker_v0;
+pub mod bond_maker_v0;
+pub mod delegate_maker_escrow_v0;
 pub mod genesis_issue_hotspot_v0;
 pub mod initialize_maker_v0;
 pub mod initialize_rewardable_entity_config_v0;
+pub mod issue_entities_v0;
 pub mod issue_entity_v0;
+pub mod migrate_iot_info_v0;
+pub mod migrate_mobile_info_v0;
 pub mod onboard_iot_hotspot_v0;
+pub mod onboard_iot_hotspot_with_hnt_v0;
 pub mod onboard_mobile_hotspot_v0;
+pub mod onboard_mobile_hotspot_with_hnt_v0;
+pub mod reset_iot_info_v0;
+pub mod reset_mobile_info_v0;
+pub mod revoke_maker_v0;
+pub mod set_maker_approvals_tracked_v0;
 pub mod set_maker_tree_v0;
+pub mod slash_maker_bond_v0;
+pub mod top_up_maker_escrow_v0;
+pub mod track_maker_approval_v0;
 pub mod update_iot_info_v0;
+pub mod update_iot_info_with_hnt_v0;
 pub mod update_maker_tree_v0;
 pub mod update_maker_v0;
 pub mod update_mobile_info_v0;
+pub mod update_mobile_info_with_hnt_v0;
 pub mod update_rewardable_entity_config_v0;
+pub mod withdraw_maker_bond_v0;
 
+pub use accept_maker_update_authority_v0::*;
+pub use accept_rewardable_entity_config_authority_v0::*;
+pub use add_maker_tree_v0::*;
 pub use approve_maker_v0::*;
+pub use bond_maker_v0::*;
+pub use delegate_maker_escrow_v0::*;
 pub use genesis_issue_hotspot_v0::*;
 pub use initialize_maker_v0::*;
 pub use initialize_rewardable_entity_config_v0::*;
+pub use issue_entities_v0::*;
 pub use issue_entity_v0::*;
+pub use migrate_iot_info_v0::*;
+pub use migrate_mobile_info_v0::*;
 pub use onboard_iot_hotspot_v0::*;
+pub use onboard_iot_hotspot_with_hnt_v0::*;
 pub use onboard_mobile_hotspot_v0::*;
+pub use onboard_mobile_hotspot_with_hnt_v0::*;
+pub use reset_iot_info_v0::*;
+pub use reset_mobile_info_v0::*;
+pub use revoke_maker_v0::*;
+pub use set_maker_approvals_tracked_v0::*;
 pub use set_maker_tree_v0::*;
+pub use slash_maker_bond_v0::*;
+pub use top_up_maker_escrow_v0::*;
+pub use track_maker_approval_v0::*;
 pub use update_iot_info_v0::*;
+pub use update_iot_info_with_hnt_v0::*;
 pub use update_maker_tree_v0::*;
 pub use update_maker_v0::*;
 pub use update_mobile_info_v0::*;
+pub use update_mobile_info_with_hnt_v0::*;
 pub use update_rewardable_entity_config_v0::*;
+pub use withdraw_maker_bond_v0::*;
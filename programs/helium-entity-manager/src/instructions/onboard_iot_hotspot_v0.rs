@@ -1,5 +1,7 @@
+use crate::entity_key_hash;
+use crate::error::ErrorCode;
 use crate::state::*;
-use anchor_lang::{prelude::*, solana_program::hash::hash};
+use anchor_lang::prelude::*;
 use anchor_spl::{
   associated_token::AssociatedToken,
   token::{Mint, Token, TokenAccount},
@@ -26,36 +28,46 @@ pub struct OnboardIotHotspotArgsV0 {
   pub location: Option<u64>,
   pub elevation: Option<i32>,
   pub gain: Option<i32>,
+  pub region: Option<LoraRegion>,
+}
+
+#[event]
+pub struct IotHotspotOnboardedV0 {
+  pub rewardable_entity_config: Pubkey,
+  pub maker: Pubkey,
+  pub entity_key: Vec<u8>,
+  pub asset_id: Pubkey,
+  pub location: Option<u64>,
+  // Onboarding and location fees burned from the maker's escrow
+  pub dc_fee: u64,
 }
 
 #[derive(Accounts)]
-#[instruction(args: OnboardIotHotspotArgsV0)]
 pub struct OnboardIotHotspotV0<'info> {
   #[account(mut)]
   pub payer: Signer<'info>,
-  #[account(mut)]
-  pub dc_fee_payer: Signer<'info>,
   pub issuing_authority: Signer<'info>,
   #[account(
     init,
     payer = payer,
-    space = IOT_HOTSPOT_INFO_SIZE,
+    space = 60 + std::mem::size_of::<IotHotspotInfoV1>(),
     seeds = [
       b"iot_info", 
       rewardable_entity_config.key().as_ref(),
-      &hash(&key_to_asset.entity_key[..]).to_bytes()
+      &entity_key_hash(&key_to_asset.entity_key, key_to_asset.key_serialization)
     ],
     bump,
   )]
-  pub iot_info: Box<Account<'info, IotHotspotInfoV0>>,
+  pub iot_info: Box<Account<'info, IotHotspotInfoV1>>,
   #[account(mut)]
   pub hotspot_owner: Signer<'info>,
   /// CHECK: The merkle tree
   pub merkle_tree: UncheckedAccount<'info>,
+  /// The maker's prepaid DC escrow, onboarding fees are burned from here
   #[account(
     mut,
     associated_token::mint = dc_mint,
-    associated_token::authority = dc_fee_payer,
+    associated_token::authority = maker,
   )]
   pub dc_burner: Box<Account<'info, TokenAccount>>,
 
@@ -71,8 +83,9 @@ pub struct OnboardIotHotspotV0<'info> {
   )]
   pub maker_approval: Box<Account<'info, MakerApprovalV0>>,
   #[account(
-    has_one = merkle_tree,
-    has_one = issuing_authority
+    mut,
+    has_one = issuing_authority,
+    constraint = maker.is_registered_tree(&merkle_tree.key()),
   )]
   pub maker: Box<Account<'info, MakerV0>>,
   #[account(
@@ -81,7 +94,6 @@ pub struct OnboardIotHotspotV0<'info> {
   pub dao: Box<Account<'info, DaoV0>>,
   #[account(
     has_one = dao,
-    constraint = get_asset_id(&merkle_tree.key(), args.index.into()) == key_to_asset.asset,
   )]
   pub key_to_asset: Box<Account<'info, KeyToAssetV0>>,
   #[account(
@@ -115,7 +127,7 @@ impl<'info> OnboardIotHotspotV0<'info> {
       burn_accounts: BurnCommonV0 {
         data_credits: self.dc.to_account_info(),
         burner: self.dc_burner.to_account_info(),
-        owner: self.dc_fee_payer.to_account_info(),
+        owner: self.maker.to_account_info(),
         dc_mint: self.dc_mint.to_account_info(),
         token_program: self.token_program.to_account_info(),
         associated_token_program: self.associated_token_program.to_account_info(),
@@ -132,6 +144,17 @@ pub fn handler<'info>(
   args: OnboardIotHotspotArgsV0,
 ) -> Result<()> {
   let asset_id = get_asset_id(&ctx.accounts.merkle_tree.key(), args.index.into());
+  // Checked here instead of in account constraints, which would read the args from the start
+  // of the instruction data and break the `_with_hnt` variants
+  require_keys_eq!(asset_id, ctx.accounts.key_to_asset.asset);
+  require!(
+    ctx.accounts.rewardable_entity_config.settings.is_valid_iot(
+      args.region,
+      args.elevation,
+      args.gain
+    ),
+    ErrorCode::InvalidIotSettings
+  );
   verify_compressed_nft(VerifyCompressedNftArgs {
     hash: args.hash,
     root: args.root,
@@ -143,9 +166,14 @@ pub fn handler<'info>(
     proof_accounts: ctx.remaining_accounts.to_vec(),
   })?;
 
-  let mut dc_fee = ctx.accounts.sub_dao.onboarding_dc_fee;
+  let (mut dc_fee, location_staking_fee) = ctx
+    .accounts
+    .rewardable_entity_config
+    .settings
+    .iot_onboarding_fees(ctx.accounts.sub_dao.onboarding_dc_fee);
 
-  ctx.accounts.iot_info.set_inner(IotHotspotInfoV0 {
+  let now = Clock::get()?.unix_timestamp;
+  ctx.accounts.iot_info.set_inner(IotHotspotInfoV1 {
     asset: asset_id,
     bump_seed: ctx.bumps["iot_info"],
     location: args.location,
@@ -153,14 +181,15 @@ pub fn handler<'info>(
     gain: args.gain,
     is_full_hotspot: true,
     num_location_asserts: 0,
+    region: args.region,
+    display_name: None,
+    last_asserted_ts: args.location.map(|_| now),
+    owner: Some(ctx.accounts.hotspot_owner.key()),
+    transferred: false,
   });
 
-  if let ConfigSettingsV0::IotConfig {
-    full_location_staking_fee,
-    ..
-  } = ctx.accounts.rewardable_entity_config.settings
-  {
-    dc_fee = full_location_staking_fee.checked_add(dc_fee).unwrap();
+  if let Some(location_staking_fee) = location_staking_fee {
+    dc_fee = location_staking_fee.checked_add(dc_fee).unwrap();
 
     ctx.accounts.iot_info.num_location_asserts = ctx
       .accounts
@@ -170,11 +199,33 @@ pub fn handler<'info>(
       .unwrap();
   }
 
-  // burn the dc tokens
+  require_gte!(
+    ctx.accounts.dc_burner.amount,
+    dc_fee,
+    ErrorCode::InsufficientMakerEscrowBalance
+  );
+
+  let maker_seeds: &[&[&[u8]]] = &[&[
+    b"maker",
+    ctx.accounts.maker.dao.as_ref(),
+    ctx.accounts.maker.name.as_bytes(),
+    &[ctx.accounts.maker.bump_seed],
+  ]];
+
+  // burn the dc tokens from the maker's escrow
   burn_without_tracking_v0(
-    ctx.accounts.burn_ctx(),
+    ctx.accounts.burn_ctx().with_signer(maker_seeds),
     BurnWithoutTrackingArgsV0 { amount: dc_fee },
   )?;
 
+  emit!(IotHotspotOnboardedV0 {
+    rewardable_entity_config: ctx.accounts.rewardable_entity_config.key(),
+    maker: ctx.accounts.maker.key(),
+    entity_key: ctx.accounts.key_to_asset.entity_key.clone(),
+    asset_id,
+    location: args.location,
+    dc_fee,
+  });
+
   Ok(())
 }
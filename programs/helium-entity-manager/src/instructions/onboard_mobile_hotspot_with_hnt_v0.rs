@@ -0,0 +1,56 @@
+use crate::{
+  onboard_mobile_hotspot_v0, utils::HntPaymentV0, OnboardMobileHotspotArgsV0,
+  OnboardMobileHotspotV0,
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OnboardMobileHotspotWithHntArgsV0 {
+  pub onboard: OnboardMobileHotspotArgsV0,
+  pub max_hnt: u64,
+}
+
+/// Onboards a mobile hotspot, first burning HNT for exactly the DC the onboarding costs
+/// so the maker's escrow doesn't need to be topped up beforehand.
+#[derive(Accounts)]
+pub struct OnboardMobileHotspotWithHntV0<'info> {
+  pub onboard: OnboardMobileHotspotV0<'info>,
+  pub hnt_payment: HntPaymentV0<'info>,
+}
+
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, OnboardMobileHotspotWithHntV0<'info>>,
+  args: OnboardMobileHotspotWithHntArgsV0,
+) -> Result<()> {
+  require_keys_eq!(
+    ctx.accounts.hnt_payment.dc_mint.key(),
+    ctx.accounts.onboard.dc_mint.key()
+  );
+
+  let onboard = &ctx.accounts.onboard;
+  let (onboarding_fee, location_staking_fee) = onboard
+    .rewardable_entity_config
+    .settings
+    .mobile_onboarding_fees(onboard.sub_dao.onboarding_dc_fee, args.onboard.device_type)?;
+  let dc_fee = onboarding_fee
+    .checked_add(location_staking_fee.unwrap_or(0))
+    .unwrap();
+
+  let recipient = onboard.maker.to_account_info();
+  let recipient_token_account = onboard.dc_burner.to_account_info();
+  ctx
+    .accounts
+    .hnt_payment
+    .mint_dc(recipient, recipient_token_account, dc_fee, args.max_hnt)?;
+  ctx.accounts.onboard.dc_burner.reload()?;
+
+  onboard_mobile_hotspot_v0::handler(
+    Context::new(
+      ctx.program_id,
+      &mut ctx.accounts.onboard,
+      ctx.remaining_accounts,
+      ctx.bumps,
+    ),
+    args.onboard,
+  )
+}
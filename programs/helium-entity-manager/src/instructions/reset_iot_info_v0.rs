@@ -0,0 +1,86 @@
+use crate::error::ErrorCode;
+use crate::state::*;
+use crate::verify_hotspot_owner;
+use anchor_lang::prelude::*;
+use mpl_bubblegum::utils::get_asset_id;
+use shared_utils::resize_to_fit;
+use spl_account_compression::program::SplAccountCompression;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ResetIotInfoArgsV0 {
+  pub data_hash: [u8; 32],
+  pub creator_hash: [u8; 32],
+  pub root: [u8; 32],
+  pub index: u32,
+}
+
+#[event]
+pub struct IotInfoResetV0 {
+  pub info: Pubkey,
+  pub asset_id: Pubkey,
+  pub previous_owner: Option<Pubkey>,
+  pub owner: Pubkey,
+}
+
+/// Called by a hotspot's new owner after a transfer. Clears the location asserted by the
+/// previous owner, and the next assert is charged the config's `transfer_reassert_dc_fee`.
+#[derive(Accounts)]
+#[instruction(args: ResetIotInfoArgsV0)]
+pub struct ResetIotInfoV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub hotspot_owner: Signer<'info>,
+  #[account(
+    mut,
+    constraint = iot_info.asset == get_asset_id(&merkle_tree.key(), args.index.into()),
+    constraint = iot_info.owner != Some(hotspot_owner.key()) @ ErrorCode::OwnerUnchanged,
+  )]
+  pub iot_info: Box<Account<'info, VersionedIotHotspotInfo>>,
+  /// CHECK: The merkle tree
+  pub merkle_tree: UncheckedAccount<'info>,
+  pub compression_program: Program<'info, SplAccountCompression>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, ResetIotInfoV0<'info>>,
+  args: ResetIotInfoArgsV0,
+) -> Result<()> {
+  let owner = ctx.accounts.hotspot_owner.key();
+  let asset_id = verify_hotspot_owner(
+    ctx.accounts.merkle_tree.to_account_info(),
+    ctx.accounts.compression_program.to_account_info(),
+    owner,
+    args.data_hash,
+    args.creator_hash,
+    args.root,
+    args.index,
+    ctx.remaining_accounts.to_vec(),
+  )?;
+
+  let info = &mut ctx.accounts.iot_info;
+  let previous_owner = info.owner;
+  info.owner = Some(owner);
+  // Infos from before owners were recorded can't tell whether the hotspot changed hands, so
+  // they only record the owner. Otherwise anyone could claim the re-assert discount.
+  if previous_owner.is_some() {
+    info.location = None;
+    info.transferred = true;
+  }
+
+  emit!(IotInfoResetV0 {
+    info: info.key(),
+    asset_id,
+    previous_owner,
+    owner,
+  });
+
+  // Also migrates V0 infos, which are written back as V1
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.iot_info,
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,87 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MakerRevokedV0 {
+  pub rewardable_entity_config: Pubkey,
+  pub maker: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct RevokeMakerV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(mut)]
+  /// CHECK: Just receiving funds
+  pub refund: UncheckedAccount<'info>,
+
+  #[account(
+    has_one = authority
+  )]
+  pub rewardable_entity_config: Box<Account<'info, RewardableEntityConfigV0>>,
+  pub authority: Signer<'info>,
+
+  #[account(mut)]
+  pub maker: Box<Account<'info, MakerV0>>,
+  #[account(
+    mut,
+    close = refund,
+    seeds = ["maker_approval".as_bytes(), rewardable_entity_config.key().as_ref(), maker.key().as_ref()],
+    bump = maker_approval.bump_seed,
+    has_one = maker,
+    has_one = rewardable_entity_config,
+  )]
+  pub maker_approval: Box<Account<'info, MakerApprovalV0>>,
+  /// Keeps the approval's bond slashable through the cooldown, see `slash_maker_bond_v0`
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = 60 + std::mem::size_of::<MakerRevocationV0>(),
+    seeds = ["maker_revocation".as_bytes(), rewardable_entity_config.key().as_ref(), maker.key().as_ref()],
+    bump,
+  )]
+  pub maker_revocation: Box<Account<'info, MakerRevocationV0>>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RevokeMakerV0>) -> Result<()> {
+  // Onboarding requires the approval account, so closing it blocks new hotspots
+  // while leaving already onboarded ones untouched.
+  let approval = &ctx.accounts.maker_approval;
+  let maker = &mut ctx.accounts.maker;
+  // Approvals from before bonding were never counted, see `track_maker_approval_v0`
+  if approval.tracked {
+    maker.num_approvals = maker.num_approvals.checked_sub(1).unwrap();
+    maker.locked_bond = maker.locked_bond.checked_sub(approval.bond_amount).unwrap();
+  }
+  // The bond stays slashable through the cooldown of the most recent revocation
+  let now = Clock::get()?.unix_timestamp;
+  let unlock_ts = now
+    + ctx
+      .accounts
+      .rewardable_entity_config
+      .maker_bond_cooldown_seconds;
+  maker.bond_unlock_ts = maker.bond_unlock_ts.max(unlock_ts);
+
+  let revocation = &mut ctx.accounts.maker_revocation;
+  // Bond from an earlier revocation that is still cooling down stays slashable too
+  let cooling_down = if now < revocation.unlock_ts {
+    revocation.bond_amount
+  } else {
+    0
+  };
+  revocation.set_inner(MakerRevocationV0 {
+    rewardable_entity_config: ctx.accounts.rewardable_entity_config.key(),
+    maker: ctx.accounts.maker.key(),
+    bond_amount: cooling_down.checked_add(approval.bond_amount).unwrap(),
+    unlock_ts,
+    bump_seed: ctx.bumps["maker_revocation"],
+  });
+
+  emit!(MakerRevokedV0 {
+    rewardable_entity_config: ctx.accounts.rewardable_entity_config.key(),
+    maker: ctx.accounts.maker.key(),
+  });
+
+  Ok(())
+}
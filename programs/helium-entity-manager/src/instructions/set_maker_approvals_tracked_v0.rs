@@ -0,0 +1,35 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use helium_sub_daos::DaoV0;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetMakerApprovalsTrackedArgsV0 {
+  pub approvals_tracked: bool,
+}
+
+/// Approvals made before makers had to bond aren't counted in `MakerV0.num_approvals`, and
+/// can't be enumerated on chain. Once every such approval has been tracked with
+/// `track_maker_approval_v0` or revoked, the dao authority confirms it here so that the
+/// maker's bond can be withdrawn.
+#[derive(Accounts)]
+pub struct SetMakerApprovalsTrackedV0<'info> {
+  pub authority: Signer<'info>,
+  #[account(
+    has_one = authority,
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  #[account(
+    mut,
+    has_one = dao,
+  )]
+  pub maker: Box<Account<'info, MakerV0>>,
+}
+
+pub fn handler(
+  ctx: Context<SetMakerApprovalsTrackedV0>,
+  args: SetMakerApprovalsTrackedArgsV0,
+) -> Result<()> {
+  ctx.accounts.maker.approvals_tracked = args.approvals_tracked;
+
+  Ok(())
+}
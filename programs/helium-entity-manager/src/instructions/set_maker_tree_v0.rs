@@ -4,6 +4,7 @@ use mpl_bubblegum::{
   cpi::{accounts::CreateTree, create_tree},
   program::Bubblegum,
 };
+use shared_utils::resize_to_fit;
 use spl_account_compression::{program::SplAccountCompression, Noop};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -68,6 +69,16 @@ pub fn handler(ctx: Context<SetMakerTreeV0>, args: SetMakerTreeArgsV0) -> Result
     args.max_buffer_size,
     None,
   )?;
-  ctx.accounts.maker.merkle_tree = ctx.accounts.merkle_tree.key();
+  ctx
+    .accounts
+    .maker
+    .queue_tree(ctx.accounts.merkle_tree.key())?;
+
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.maker,
+  )?;
+
   Ok(())
 }
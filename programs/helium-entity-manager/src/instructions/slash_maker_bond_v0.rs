@@ -0,0 +1,123 @@
+use crate::error::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use helium_sub_daos::{DaoV0, SubDaoV0};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SlashMakerBondArgsV0 {
+  pub amount: u64,
+}
+
+#[event]
+pub struct MakerBondSlashedV0 {
+  pub rewardable_entity_config: Pubkey,
+  pub maker: Pubkey,
+  pub amount: u64,
+}
+
+/// Lets the rewardable entity config authority move part of the bond a maker holds for the
+/// config to the DAO treasury, for instance when the maker ships fraudulent devices. This
+/// needs either the maker's approval or a revocation that is still in its cooldown.
+#[derive(Accounts)]
+#[instruction(args: SlashMakerBondArgsV0)]
+pub struct SlashMakerBondV0<'info> {
+  #[account(
+    has_one = authority,
+    has_one = sub_dao,
+  )]
+  pub rewardable_entity_config: Box<Account<'info, RewardableEntityConfigV0>>,
+  pub authority: Signer<'info>,
+  #[account(
+    has_one = dao,
+  )]
+  pub sub_dao: Box<Account<'info, SubDaoV0>>,
+  #[account(
+    has_one = hnt_mint,
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  pub hnt_mint: Box<Account<'info, Mint>>,
+  /// HNT account of the DAO authority
+  #[account(
+    mut,
+    token::mint = hnt_mint,
+    constraint = dao_treasury.owner == dao.authority,
+  )]
+  pub dao_treasury: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    has_one = dao,
+  )]
+  pub maker: Box<Account<'info, MakerV0>>,
+  #[account(
+    mut,
+    seeds = ["maker_approval".as_bytes(), rewardable_entity_config.key().as_ref(), maker.key().as_ref()],
+    bump = maker_approval.bump_seed,
+  )]
+  pub maker_approval: Option<Box<Account<'info, MakerApprovalV0>>>,
+  #[account(
+    mut,
+    seeds = ["maker_revocation".as_bytes(), rewardable_entity_config.key().as_ref(), maker.key().as_ref()],
+    bump = maker_revocation.bump_seed,
+  )]
+  pub maker_revocation: Option<Box<Account<'info, MakerRevocationV0>>>,
+  #[account(
+    mut,
+    associated_token::mint = hnt_mint,
+    associated_token::authority = maker,
+  )]
+  pub maker_bond: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<SlashMakerBondV0>, args: SlashMakerBondArgsV0) -> Result<()> {
+  let now = Clock::get()?.unix_timestamp;
+  let accounts = &mut ctx.accounts;
+  match (&mut accounts.maker_approval, &mut accounts.maker_revocation) {
+    (Some(approval), _) => {
+      approval.bond_amount = approval
+        .bond_amount
+        .checked_sub(args.amount)
+        .ok_or_else(|| error!(ErrorCode::SlashExceedsMakerBond))?;
+      if approval.tracked {
+        accounts.maker.locked_bond = accounts.maker.locked_bond.checked_sub(args.amount).unwrap();
+      }
+    }
+    (None, Some(revocation)) if now < revocation.unlock_ts => {
+      revocation.bond_amount = revocation
+        .bond_amount
+        .checked_sub(args.amount)
+        .ok_or_else(|| error!(ErrorCode::SlashExceedsMakerBond))?;
+    }
+    _ => return Err(error!(ErrorCode::MakerNotSlashable)),
+  }
+
+  let maker_seeds: &[&[&[u8]]] = &[&[
+    b"maker",
+    ctx.accounts.maker.dao.as_ref(),
+    ctx.accounts.maker.name.as_bytes(),
+    &[ctx.accounts.maker.bump_seed],
+  ]];
+
+  token::transfer(
+    CpiContext::new_with_signer(
+      ctx.accounts.token_program.to_account_info(),
+      Transfer {
+        from: ctx.accounts.maker_bond.to_account_info(),
+        to: ctx.accounts.dao_treasury.to_account_info(),
+        authority: ctx.accounts.maker.to_account_info(),
+      },
+      maker_seeds,
+    ),
+    args.amount,
+  )?;
+
+  emit!(MakerBondSlashedV0 {
+    rewardable_entity_config: ctx.accounts.rewardable_entity_config.key(),
+    maker: ctx.accounts.maker.key(),
+    amount: args.amount,
+  });
+
+  Ok(())
+}
@@ -0,0 +1,113 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+  associated_token::AssociatedToken,
+  token::{Mint, Token, TokenAccount},
+};
+use circuit_breaker::{program::CircuitBreaker, MintWindowedCircuitBreakerV0};
+use data_credits::{
+  cpi::{accounts::MintDataCreditsV0, mint_data_credits_v0},
+  program::DataCredits,
+  DataCreditsV0, MintDataCreditsArgsV0,
+};
+use helium_sub_daos::DaoV0;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TopUpMakerEscrowArgsV0 {
+  pub hnt_amount: u64,
+}
+
+/// Burns HNT from the owner into DC held by the maker's escrow. The escrow is the
+/// maker's DC associated token account, which onboarding fees are drawn from. DC can't
+/// be withdrawn once escrowed, so top ups should be sized to expected onboarding. Any
+/// surplus can only be delegated to a router with `delegate_maker_escrow_v0`.
+#[derive(Accounts)]
+#[instruction(args: TopUpMakerEscrowArgsV0)]
+pub struct TopUpMakerEscrowV0<'info> {
+  #[account(mut)]
+  pub owner: Signer<'info>,
+  #[account(
+    mut,
+    constraint = burner.mint == hnt_mint.key(),
+    has_one = owner,
+  )]
+  pub burner: Box<Account<'info, TokenAccount>>,
+  #[account(
+    has_one = dao,
+  )]
+  pub maker: Box<Account<'info, MakerV0>>,
+  #[account(
+    has_one = dc_mint,
+    has_one = hnt_mint,
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  /// CHECK: Initialized by cpi
+  #[account(
+    mut,
+    address = anchor_spl::associated_token::get_associated_token_address(&maker.key(), &dc_mint.key()),
+  )]
+  pub maker_escrow: UncheckedAccount<'info>,
+
+  #[account(
+    seeds = [
+      "dc".as_bytes(),
+      dc_mint.key().as_ref(),
+    ],
+    seeds::program = data_credits_program.key(),
+    bump = data_credits.data_credits_bump,
+    has_one = hnt_price_oracle,
+  )]
+  pub data_credits: Box<Account<'info, DataCreditsV0>>,
+  /// CHECK: Checked by cpi
+  pub hnt_price_oracle: AccountInfo<'info>,
+  #[account(mut)]
+  pub hnt_mint: Box<Account<'info, Mint>>,
+  #[account(mut)]
+  pub dc_mint: Box<Account<'info, Mint>>,
+  #[account(
+    mut,
+    seeds = ["mint_windowed_breaker".as_bytes(), dc_mint.key().as_ref()],
+    seeds::program = circuit_breaker_program.key(),
+    bump = circuit_breaker.bump_seed
+  )]
+  pub circuit_breaker: Box<Account<'info, MintWindowedCircuitBreakerV0>>,
+
+  pub circuit_breaker_program: Program<'info, CircuitBreaker>,
+  pub data_credits_program: Program<'info, DataCredits>,
+  pub token_program: Program<'info, Token>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> TopUpMakerEscrowV0<'info> {
+  fn mint_dc_ctx(&self) -> CpiContext<'_, '_, '_, 'info, MintDataCreditsV0<'info>> {
+    let cpi_accounts = MintDataCreditsV0 {
+      data_credits: self.data_credits.to_account_info(),
+      hnt_price_oracle: self.hnt_price_oracle.to_account_info(),
+      burner: self.burner.to_account_info(),
+      recipient_token_account: self.maker_escrow.to_account_info(),
+      recipient: self.maker.to_account_info(),
+      owner: self.owner.to_account_info(),
+      hnt_mint: self.hnt_mint.to_account_info(),
+      dc_mint: self.dc_mint.to_account_info(),
+      circuit_breaker: self.circuit_breaker.to_account_info(),
+      circuit_breaker_program: self.circuit_breaker_program.to_account_info(),
+      token_program: self.token_program.to_account_info(),
+      system_program: self.system_program.to_account_info(),
+      associated_token_program: self.associated_token_program.to_account_info(),
+    };
+
+    CpiContext::new(self.data_credits_program.to_account_info(), cpi_accounts)
+  }
+}
+
+pub fn handler(ctx: Context<TopUpMakerEscrowV0>, args: TopUpMakerEscrowArgsV0) -> Result<()> {
+  mint_data_credits_v0(
+    ctx.accounts.mint_dc_ctx(),
+    MintDataCreditsArgsV0 {
+      hnt_amount: args.hnt_amount,
+    },
+  )?;
+
+  Ok(())
+}
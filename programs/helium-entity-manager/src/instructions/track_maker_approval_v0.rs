@@ -0,0 +1,54 @@
+use crate::error::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use helium_sub_daos::DaoV0;
+
+/// Counts an approval made before makers had to bond in `MakerV0.num_approvals`, holding the
+/// config's current bond for it. Until then the approval holds none of the bond, and the maker's
+/// bond stays locked, see `set_maker_approvals_tracked_v0`.
+#[derive(Accounts)]
+pub struct TrackMakerApprovalV0<'info> {
+  #[account(
+    has_one = authority
+  )]
+  pub rewardable_entity_config: Box<Account<'info, RewardableEntityConfigV0>>,
+  pub authority: Signer<'info>,
+
+  #[account(
+    mut,
+    has_one = dao,
+  )]
+  pub maker: Box<Account<'info, MakerV0>>,
+  #[account(
+    has_one = hnt_mint,
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  pub hnt_mint: Box<Account<'info, Mint>>,
+  #[account(
+    associated_token::mint = hnt_mint,
+    associated_token::authority = maker,
+    constraint = maker_bond.amount >= maker.locked_bond.saturating_add(rewardable_entity_config.maker_bond) @ ErrorCode::InsufficientMakerBond,
+  )]
+  pub maker_bond: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    seeds = ["maker_approval".as_bytes(), rewardable_entity_config.key().as_ref(), maker.key().as_ref()],
+    bump = maker_approval.bump_seed,
+    constraint = !maker_approval.tracked @ ErrorCode::MakerApprovalAlreadyTracked,
+  )]
+  pub maker_approval: Box<Account<'info, MakerApprovalV0>>,
+}
+
+pub fn handler(ctx: Context<TrackMakerApprovalV0>) -> Result<()> {
+  let bond_amount = ctx.accounts.rewardable_entity_config.maker_bond;
+  let approval = &mut ctx.accounts.maker_approval;
+  approval.bond_amount = bond_amount;
+  approval.tracked = true;
+
+  let maker = &mut ctx.accounts.maker;
+  maker.num_approvals = maker.num_approvals.checked_add(1).unwrap();
+  maker.locked_bond = maker.locked_bond.checked_add(bond_amount).unwrap();
+
+  Ok(())
+}
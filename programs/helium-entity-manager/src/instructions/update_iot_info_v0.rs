@@ -1,4 +1,6 @@
+use crate::error::ErrorCode;
 use crate::state::*;
+use crate::utils::update_display_name;
 use anchor_lang::prelude::*;
 use anchor_spl::{
   associated_token::AssociatedToken,
@@ -22,37 +24,38 @@ pub struct UpdateIotInfoArgsV0 {
   pub location: Option<u64>,
   pub elevation: Option<i32>,
   pub gain: Option<i32>,
+  pub region: Option<LoraRegion>,
+  // Empty string clears the display name
+  pub display_name: Option<String>,
   pub hash: [u8; 32],
   pub root: [u8; 32],
   pub index: u32,
 }
 
-impl ConfigSettingsV0 {
-  pub fn is_valid_iot(self, args: UpdateIotInfoArgsV0) -> bool {
-    match (args.gain, self) {
-      (
-        Some(gain),
-        ConfigSettingsV0::IotConfig {
-          max_gain, min_gain, ..
-        },
-      ) => gain <= max_gain && gain >= min_gain,
-      _ => true,
-    }
-  }
+#[event]
+pub struct IotInfoUpdatedV0 {
+  pub rewardable_entity_config: Pubkey,
+  pub info: Pubkey,
+  pub key_to_asset: Pubkey,
+  pub entity_key: Vec<u8>,
+  pub asset_id: Pubkey,
+  // Set when this update asserted a new location
+  pub location: Option<u64>,
+  pub dc_fee: u64,
 }
 
 #[derive(Accounts)]
-#[instruction(args: UpdateIotInfoArgsV0)]
 pub struct UpdateIotInfoV0<'info> {
   #[account(mut)]
   pub payer: Signer<'info>,
   #[account(mut)]
   pub dc_fee_payer: Signer<'info>,
+  #[account(mut)]
+  pub iot_info: Box<Account<'info, VersionedIotHotspotInfo>>,
   #[account(
-    mut,
-    constraint = iot_info.asset == get_asset_id(&merkle_tree.key(), u64::try_from(args.index).unwrap())
+    constraint = key_to_asset.asset == iot_info.asset,
   )]
-  pub iot_info: Box<Account<'info, IotHotspotInfoV0>>,
+  pub key_to_asset: Box<Account<'info, KeyToAssetV0>>,
   #[account(mut)]
   pub hotspot_owner: Signer<'info>,
   /// CHECK: The merkle tree
@@ -72,7 +75,6 @@ pub struct UpdateIotInfoV0<'info> {
 
   #[account(
     has_one = sub_dao,
-    constraint = rewardable_entity_config.settings.is_valid_iot(args)
   )]
   pub rewardable_entity_config: Box<Account<'info, RewardableEntityConfigV0>>,
   #[account(
@@ -129,6 +131,22 @@ pub fn handler<'info>(
   ctx: Context<'_, '_, '_, 'info, UpdateIotInfoV0<'info>>,
   args: UpdateIotInfoArgsV0,
 ) -> Result<()> {
+  // Checked here instead of in account constraints, which would read the args from the start
+  // of the instruction data and break the `_with_hnt` variants
+  let iot_info = &ctx.accounts.iot_info;
+  require_keys_eq!(
+    iot_info.asset,
+    get_asset_id(&ctx.accounts.merkle_tree.key(), args.index.into())
+  );
+  require!(
+    ctx.accounts.rewardable_entity_config.settings.is_valid_iot(
+      args.region.or(iot_info.region),
+      args.elevation.or(iot_info.elevation),
+      args.gain.or(iot_info.gain),
+    ),
+    ErrorCode::InvalidIotSettings
+  );
+
   verify_compressed_nft(VerifyCompressedNftArgs {
     hash: args.hash,
     root: args.root,
@@ -140,22 +158,15 @@ pub fn handler<'info>(
     proof_accounts: ctx.remaining_accounts.to_vec(),
   })?;
 
-  if let (
-    Some(location),
-    ConfigSettingsV0::IotConfig {
-      full_location_staking_fee,
-      dataonly_location_staking_fee,
-      ..
-    },
-  ) = (
-    args.location,
-    ctx.accounts.rewardable_entity_config.settings,
-  ) {
-    let mut dc_fee: u64 = dataonly_location_staking_fee;
-    if ctx.accounts.iot_info.is_full_hotspot {
-      dc_fee = full_location_staking_fee;
-    }
-
+  let config = &ctx.accounts.rewardable_entity_config;
+  let location_fee = config.reassert_fee(
+    config
+      .settings
+      .iot_location_fee(ctx.accounts.iot_info.is_full_hotspot),
+    ctx.accounts.iot_info.transferred,
+  );
+  let mut asserted = None;
+  if let (Some(location), Some(dc_fee)) = (args.location, location_fee) {
     ctx.accounts.iot_info.num_location_asserts = ctx
       .accounts
       .iot_info
@@ -169,6 +180,9 @@ pub fn handler<'info>(
       BurnWithoutTrackingArgsV0 { amount: dc_fee },
     )?;
     ctx.accounts.iot_info.location = Some(location);
+    ctx.accounts.iot_info.last_asserted_ts = Some(Clock::get()?.unix_timestamp);
+    ctx.accounts.iot_info.transferred = false;
+    asserted = Some((location, dc_fee));
   }
 
   if args.elevation.is_some() {
@@ -177,5 +191,29 @@ pub fn handler<'info>(
   if args.gain.is_some() {
     ctx.accounts.iot_info.gain = args.gain;
   }
+  if args.region.is_some() {
+    ctx.accounts.iot_info.region = args.region;
+  }
+  if let Some(display_name) = args.display_name {
+    update_display_name(&mut ctx.accounts.iot_info.display_name, display_name)?;
+  }
+
+  emit!(IotInfoUpdatedV0 {
+    rewardable_entity_config: ctx.accounts.rewardable_entity_config.key(),
+    info: ctx.accounts.iot_info.key(),
+    key_to_asset: ctx.accounts.key_to_asset.key(),
+    entity_key: ctx.accounts.key_to_asset.entity_key.clone(),
+    asset_id: ctx.accounts.iot_info.asset,
+    location: asserted.map(|(location, _)| location),
+    dc_fee: asserted.map(|(_, dc_fee)| dc_fee).unwrap_or(0),
+  });
+
+  // Also migrates V0 infos, which are written back as V1
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.iot_info,
+  )?;
+
   Ok(())
 }
@@ -0,0 +1,58 @@
+use crate::{update_iot_info_v0, utils::HntPaymentV0, UpdateIotInfoArgsV0, UpdateIotInfoV0};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateIotInfoWithHntArgsV0 {
+  pub update: UpdateIotInfoArgsV0,
+  pub max_hnt: u64,
+}
+
+/// Updates iot info, first burning HNT for exactly the DC a location assert costs so the
+/// hotspot owner doesn't need to hold DC.
+#[derive(Accounts)]
+pub struct UpdateIotInfoWithHntV0<'info> {
+  pub update: UpdateIotInfoV0<'info>,
+  pub hnt_payment: HntPaymentV0<'info>,
+}
+
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, UpdateIotInfoWithHntV0<'info>>,
+  args: UpdateIotInfoWithHntArgsV0,
+) -> Result<()> {
+  require_keys_eq!(
+    ctx.accounts.hnt_payment.dc_mint.key(),
+    ctx.accounts.update.dc_mint.key()
+  );
+
+  let update = &ctx.accounts.update;
+  let dc_fee = match args.update.location {
+    Some(_) => update
+      .rewardable_entity_config
+      .reassert_fee(
+        update
+          .rewardable_entity_config
+          .settings
+          .iot_location_fee(update.iot_info.is_full_hotspot),
+        update.iot_info.transferred,
+      )
+      .unwrap_or(0),
+    None => 0,
+  };
+
+  let recipient = update.dc_fee_payer.to_account_info();
+  let recipient_token_account = update.dc_burner.to_account_info();
+  ctx
+    .accounts
+    .hnt_payment
+    .mint_dc(recipient, recipient_token_account, dc_fee, args.max_hnt)?;
+
+  update_iot_info_v0::handler(
+    Context::new(
+      ctx.program_id,
+      &mut ctx.accounts.update,
+      ctx.remaining_accounts,
+      ctx.bumps,
+    ),
+    args.update,
+  )
+}
@@ -5,6 +5,7 @@ use mpl_bubblegum::{
   program::Bubblegum,
   state::TreeConfig,
 };
+use shared_utils::resize_to_fit;
 use spl_account_compression::{program::SplAccountCompression, Noop};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -73,7 +74,22 @@ pub fn handler(ctx: Context<UpdateMakerTreeV0>, args: UpdateMakerTreeArgsV0) ->
     args.max_buffer_size,
     None,
   )?;
-  ctx.accounts.maker.merkle_tree = ctx.accounts.new_merkle_tree.key();
+  let maker = &mut ctx.accounts.maker;
+  maker.queue_tree(ctx.accounts.new_merkle_tree.key())?;
+  // Issuance only rolls over after a successful mint, so a tree that filled up before anything
+  // was queued behind it has to be swapped out here
+  let tree_authority = &ctx.accounts.tree_authority;
+  if tree_authority.num_minted >= tree_authority.total_mint_capacity {
+    if let Some(next_tree) = maker.next_merkle_tree() {
+      maker.merkle_tree = next_tree;
+    }
+  }
+
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.maker,
+  )?;
 
   Ok(())
 }
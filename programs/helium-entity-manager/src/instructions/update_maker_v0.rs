@@ -1,12 +1,21 @@
 use crate::state::*;
 use anchor_lang::prelude::*;
+use shared_utils::nominate_authority;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct UpdateMakerArgsV0 {
   pub issuing_authority: Option<Pubkey>,
+  // Only nominates the new update authority, see `accept_maker_update_authority_v0`
   pub update_authority: Option<Pubkey>,
 }
 
+#[event]
+pub struct MakerUpdatedV0 {
+  pub maker: Pubkey,
+  pub issuing_authority: Pubkey,
+  pub update_authority: Pubkey,
+}
+
 #[derive(Accounts)]
 #[instruction(args: UpdateMakerArgsV0)]
 pub struct UpdateMakerV0<'info> {
@@ -25,8 +34,18 @@ pub fn handler(ctx: Context<UpdateMakerV0>, args: UpdateMakerArgsV0) -> Result<(
   if args.issuing_authority.is_some() {
     maker.issuing_authority = args.issuing_authority.unwrap();
   }
-  if args.update_authority.is_some() {
-    maker.update_authority = args.update_authority.unwrap();
+  if let Some(update_authority) = args.update_authority {
+    nominate_authority(
+      maker.update_authority,
+      &mut maker.pending_update_authority,
+      update_authority,
+    );
   }
+
+  emit!(MakerUpdatedV0 {
+    maker: maker.key(),
+    issuing_authority: maker.issuing_authority,
+    update_authority: maker.update_authority,
+  });
   Ok(())
 }
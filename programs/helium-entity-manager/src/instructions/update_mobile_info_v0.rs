@@ -1,4 +1,6 @@
+use crate::error::ErrorCode;
 use crate::state::*;
+use crate::utils::update_display_name;
 use anchor_lang::prelude::*;
 use anchor_spl::{
   associated_token::AssociatedToken,
@@ -20,27 +22,45 @@ use spl_account_compression::program::SplAccountCompression;
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct UpdateMobileInfoArgsV0 {
   pub location: Option<u64>,
+  // Empty string clears the display name
+  pub display_name: Option<String>,
   pub hash: [u8; 32],
   pub root: [u8; 32],
   pub index: u32,
 }
 
 impl ConfigSettingsV0 {
-  pub fn is_valid_mobile(self, _: UpdateMobileInfoArgsV0) -> bool {
-    matches!(self, ConfigSettingsV0::MobileConfig { .. })
+  pub fn is_valid_mobile(&self, _: &UpdateMobileInfoArgsV0) -> bool {
+    matches!(
+      self,
+      ConfigSettingsV0::MobileConfig { .. } | ConfigSettingsV0::MobileConfigV1 { .. }
+    )
   }
 }
 
+#[event]
+pub struct MobileInfoUpdatedV0 {
+  pub rewardable_entity_config: Pubkey,
+  pub info: Pubkey,
+  pub key_to_asset: Pubkey,
+  pub entity_key: Vec<u8>,
+  pub asset_id: Pubkey,
+  // Set when this update asserted a new location
+  pub location: Option<u64>,
+  pub dc_fee: u64,
+}
+
 #[derive(Accounts)]
-#[instruction(args: UpdateMobileInfoArgsV0)]
 pub struct UpdateMobileInfoV0<'info> {
+  #[account(mut)]
   pub payer: Signer<'info>,
   pub dc_fee_payer: Signer<'info>,
+  #[account(mut)]
+  pub mobile_info: Box<Account<'info, VersionedMobileHotspotInfo>>,
   #[account(
-    mut,
-    constraint = mobile_info.asset == get_asset_id(&merkle_tree.key(), u64::try_from(args.index).unwrap())
+    constraint = key_to_asset.asset == mobile_info.asset,
   )]
-  pub mobile_info: Box<Account<'info, MobileHotspotInfoV0>>,
+  pub key_to_asset: Box<Account<'info, KeyToAssetV0>>,
   #[account(mut)]
   pub hotspot_owner: Signer<'info>,
   /// CHECK: The merkle tree
@@ -60,7 +80,6 @@ pub struct UpdateMobileInfoV0<'info> {
 
   #[account(
     has_one = sub_dao,
-    constraint = rewardable_entity_config.settings.is_valid_mobile(args)
   )]
   pub rewardable_entity_config: Box<Account<'info, RewardableEntityConfigV0>>,
   #[account(
@@ -117,6 +136,21 @@ pub fn handler<'info>(
   ctx: Context<'_, '_, '_, 'info, UpdateMobileInfoV0<'info>>,
   args: UpdateMobileInfoArgsV0,
 ) -> Result<()> {
+  // Checked here instead of in account constraints, which would read the args from the start
+  // of the instruction data and break the `_with_hnt` variants
+  require_keys_eq!(
+    ctx.accounts.mobile_info.asset,
+    get_asset_id(&ctx.accounts.merkle_tree.key(), args.index.into())
+  );
+  require!(
+    ctx
+      .accounts
+      .rewardable_entity_config
+      .settings
+      .is_valid_mobile(&args),
+    ErrorCode::InvalidMobileSettings
+  );
+
   verify_compressed_nft(VerifyCompressedNftArgs {
     hash: args.hash,
     root: args.root,
@@ -128,22 +162,16 @@ pub fn handler<'info>(
     proof_accounts: ctx.remaining_accounts.to_vec(),
   })?;
 
-  if let (
-    Some(location),
-    ConfigSettingsV0::MobileConfig {
-      full_location_staking_fee,
-      dataonly_location_staking_fee,
-      ..
-    },
-  ) = (
-    args.location,
-    ctx.accounts.rewardable_entity_config.settings,
-  ) {
-    let mut dc_fee: u64 = dataonly_location_staking_fee;
-    if ctx.accounts.mobile_info.is_full_hotspot {
-      dc_fee = full_location_staking_fee;
-    }
-
+  let config = &ctx.accounts.rewardable_entity_config;
+  let location_fee = config.reassert_fee(
+    config.settings.mobile_location_fee(
+      ctx.accounts.mobile_info.is_full_hotspot,
+      ctx.accounts.mobile_info.device_type,
+    )?,
+    ctx.accounts.mobile_info.transferred,
+  );
+  let mut asserted = None;
+  if let (Some(location), Some(dc_fee)) = (args.location, location_fee) {
     ctx.accounts.mobile_info.num_location_asserts = ctx
       .accounts
       .mobile_info
@@ -157,7 +185,31 @@ pub fn handler<'info>(
       BurnWithoutTrackingArgsV0 { amount: dc_fee },
     )?;
     ctx.accounts.mobile_info.location = Some(location);
+    ctx.accounts.mobile_info.last_asserted_ts = Some(Clock::get()?.unix_timestamp);
+    ctx.accounts.mobile_info.transferred = false;
+    asserted = Some((location, dc_fee));
+  }
+
+  if let Some(display_name) = args.display_name {
+    update_display_name(&mut ctx.accounts.mobile_info.display_name, display_name)?;
   }
 
+  emit!(MobileInfoUpdatedV0 {
+    rewardable_entity_config: ctx.accounts.rewardable_entity_config.key(),
+    info: ctx.accounts.mobile_info.key(),
+    key_to_asset: ctx.accounts.key_to_asset.key(),
+    entity_key: ctx.accounts.key_to_asset.entity_key.clone(),
+    asset_id: ctx.accounts.mobile_info.asset,
+    location: asserted.map(|(location, _)| location),
+    dc_fee: asserted.map(|(_, dc_fee)| dc_fee).unwrap_or(0),
+  });
+
+  // Also migrates V0 infos, which are written back as V1
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.mobile_info,
+  )?;
+
   Ok(())
 }
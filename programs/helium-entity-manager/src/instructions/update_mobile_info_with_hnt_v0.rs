@@ -0,0 +1,63 @@
+use crate::{
+  update_mobile_info_v0, utils::HntPaymentV0, UpdateMobileInfoArgsV0, UpdateMobileInfoV0,
+};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateMobileInfoWithHntArgsV0 {
+  pub update: UpdateMobileInfoArgsV0,
+  pub max_hnt: u64,
+}
+
+/// Updates mobile info, first burning HNT for exactly the DC a location assert costs so the
+/// hotspot owner doesn't need to hold DC.
+#[derive(Accounts)]
+pub struct UpdateMobileInfoWithHntV0<'info> {
+  pub update: UpdateMobileInfoV0<'info>,
+  pub hnt_payment: HntPaymentV0<'info>,
+}
+
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, UpdateMobileInfoWithHntV0<'info>>,
+  args: UpdateMobileInfoWithHntArgsV0,
+) -> Result<()> {
+  require_keys_eq!(
+    ctx.accounts.hnt_payment.dc_mint.key(),
+    ctx.accounts.update.dc_mint.key()
+  );
+
+  let update = &ctx.accounts.update;
+  let dc_fee = match args.update.location {
+    Some(_) => update
+      .rewardable_entity_config
+      .reassert_fee(
+        update
+          .rewardable_entity_config
+          .settings
+          .mobile_location_fee(
+            update.mobile_info.is_full_hotspot,
+            update.mobile_info.device_type,
+          )?,
+        update.mobile_info.transferred,
+      )
+      .unwrap_or(0),
+    None => 0,
+  };
+
+  let recipient = update.dc_fee_payer.to_account_info();
+  let recipient_token_account = update.dc_burner.to_account_info();
+  ctx
+    .accounts
+    .hnt_payment
+    .mint_dc(recipient, recipient_token_account, dc_fee, args.max_hnt)?;
+
+  update_mobile_info_v0::handler(
+    Context::new(
+      ctx.program_id,
+      &mut ctx.accounts.update,
+      ctx.remaining_accounts,
+      ctx.bumps,
+    ),
+    args.update,
+  )
+}
@@ -1,21 +1,29 @@
 use crate::state::*;
 use anchor_lang::prelude::*;
+use shared_utils::{nominate_authority, resize_to_fit};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct UpdateRewardableEntityConfigArgsV0 {
+  // Only nominates the new authority, see `accept_rewardable_entity_config_authority_v0`
   pub new_authority: Option<Pubkey>,
   pub settings: Option<ConfigSettingsV0>,
+  pub maker_bond: Option<u64>,
+  pub maker_bond_cooldown_seconds: Option<i64>,
+  pub transfer_reassert_dc_fee: Option<u64>,
 }
 
 #[derive(Accounts)]
 #[instruction(args: UpdateRewardableEntityConfigArgsV0)]
 pub struct UpdateRewardableEntityConfigV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
   pub authority: Signer<'info>,
   #[account(
     mut,
     has_one = authority,
   )]
   pub rewardable_entity_config: Box<Account<'info, RewardableEntityConfigV0>>,
+  pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
@@ -24,12 +32,32 @@ pub fn handler(
 ) -> Result<()> {
   let config = &mut ctx.accounts.rewardable_entity_config;
   if let Some(new_authority) = args.new_authority {
-    config.authority = new_authority;
+    nominate_authority(
+      config.authority,
+      &mut config.pending_authority,
+      new_authority,
+    );
   }
 
   if let Some(settings) = args.settings {
     config.settings = settings;
   }
+  if let Some(maker_bond) = args.maker_bond {
+    config.maker_bond = maker_bond;
+  }
+  if let Some(maker_bond_cooldown_seconds) = args.maker_bond_cooldown_seconds {
+    require_gte!(maker_bond_cooldown_seconds, 0);
+    config.maker_bond_cooldown_seconds = maker_bond_cooldown_seconds;
+  }
+  if args.transfer_reassert_dc_fee.is_some() {
+    config.transfer_reassert_dc_fee = args.transfer_reassert_dc_fee;
+  }
+
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.rewardable_entity_config,
+  )?;
 
   Ok(())
 }
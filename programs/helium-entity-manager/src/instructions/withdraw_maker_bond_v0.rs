@@ -0,0 +1,74 @@
+use crate::error::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use helium_sub_daos::DaoV0;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct WithdrawMakerBondArgsV0 {
+  pub amount: u64,
+}
+
+/// The bond can only be withdrawn once every approval has been revoked and the
+/// slashing cooldown of the last revocation has passed. Makers from before bonding
+/// must also have had their untracked approvals confirmed gone.
+#[derive(Accounts)]
+#[instruction(args: WithdrawMakerBondArgsV0)]
+pub struct WithdrawMakerBondV0<'info> {
+  pub update_authority: Signer<'info>,
+  #[account(
+    has_one = update_authority,
+    has_one = dao,
+    constraint = maker.num_approvals == 0 @ ErrorCode::MakerBondLocked,
+    constraint = maker.approvals_tracked @ ErrorCode::UntrackedMakerApprovals,
+  )]
+  pub maker: Box<Account<'info, MakerV0>>,
+  #[account(
+    has_one = hnt_mint,
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  pub hnt_mint: Box<Account<'info, Mint>>,
+  #[account(
+    mut,
+    associated_token::mint = hnt_mint,
+    associated_token::authority = maker,
+  )]
+  pub maker_bond: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    constraint = destination.mint == hnt_mint.key(),
+  )]
+  pub destination: Box<Account<'info, TokenAccount>>,
+
+  pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawMakerBondV0>, args: WithdrawMakerBondArgsV0) -> Result<()> {
+  require_gte!(
+    Clock::get()?.unix_timestamp,
+    ctx.accounts.maker.bond_unlock_ts,
+    ErrorCode::MakerBondLocked
+  );
+
+  let maker_seeds: &[&[&[u8]]] = &[&[
+    b"maker",
+    ctx.accounts.maker.dao.as_ref(),
+    ctx.accounts.maker.name.as_bytes(),
+    &[ctx.accounts.maker.bump_seed],
+  ]];
+
+  token::transfer(
+    CpiContext::new_with_signer(
+      ctx.accounts.token_program.to_account_info(),
+      Transfer {
+        from: ctx.accounts.maker_bond.to_account_info(),
+        to: ctx.accounts.destination.to_account_info(),
+        authority: ctx.accounts.maker.to_account_info(),
+      },
+      maker_seeds,
+    ),
+    args.amount,
+  )?;
+
+  Ok(())
+}
@@ -6,9 +6,11 @@ pub mod constants;
 pub mod error;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 pub use instructions::*;
 pub use state::*;
+pub use utils::*;
 
 #[program]
 pub mod helium_entity_manager {
@@ -25,6 +27,10 @@ pub mod helium_entity_manager {
     approve_maker_v0::handler(ctx)
   }
 
+  pub fn revoke_maker_v0(ctx: Context<RevokeMakerV0>) -> Result<()> {
+    revoke_maker_v0::handler(ctx)
+  }
+
   pub fn initialize_maker_v0(
     ctx: Context<InitializeMakerV0>,
     args: InitializeMakerArgsV0,
@@ -36,6 +42,13 @@ pub mod helium_entity_manager {
     issue_entity_v0::handler(ctx, args)
   }
 
+  pub fn issue_entities_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, IssueEntitiesV0<'info>>,
+    args: IssueEntitiesArgsV0,
+  ) -> Result<()> {
+    issue_entities_v0::handler(ctx, args)
+  }
+
   pub fn genesis_issue_hotspot_v0<'info>(
     ctx: Context<'_, '_, '_, 'info, GenesisIssueHotspotV0<'info>>,
     args: GenesisIssueHotspotArgsV0,
@@ -57,6 +70,20 @@ pub mod helium_entity_manager {
     onboard_mobile_hotspot_v0::handler(ctx, args)
   }
 
+  pub fn onboard_iot_hotspot_with_hnt_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, OnboardIotHotspotWithHntV0<'info>>,
+    args: OnboardIotHotspotWithHntArgsV0,
+  ) -> Result<()> {
+    onboard_iot_hotspot_with_hnt_v0::handler(ctx, args)
+  }
+
+  pub fn onboard_mobile_hotspot_with_hnt_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, OnboardMobileHotspotWithHntV0<'info>>,
+    args: OnboardMobileHotspotWithHntArgsV0,
+  ) -> Result<()> {
+    onboard_mobile_hotspot_with_hnt_v0::handler(ctx, args)
+  }
+
   pub fn update_rewardable_entity_config_v0(
     ctx: Context<UpdateRewardableEntityConfigV0>,
     args: UpdateRewardableEntityConfigArgsV0,
@@ -64,14 +91,30 @@ pub mod helium_entity_manager {
     update_rewardable_entity_config_v0::handler(ctx, args)
   }
 
+  pub fn accept_rewardable_entity_config_authority_v0(
+    ctx: Context<AcceptRewardableEntityConfigAuthorityV0>,
+  ) -> Result<()> {
+    accept_rewardable_entity_config_authority_v0::handler(ctx)
+  }
+
   pub fn update_maker_v0(ctx: Context<UpdateMakerV0>, args: UpdateMakerArgsV0) -> Result<()> {
     update_maker_v0::handler(ctx, args)
   }
 
+  pub fn accept_maker_update_authority_v0(
+    ctx: Context<AcceptMakerUpdateAuthorityV0>,
+  ) -> Result<()> {
+    accept_maker_update_authority_v0::handler(ctx)
+  }
+
   pub fn set_maker_tree_v0(ctx: Context<SetMakerTreeV0>, args: SetMakerTreeArgsV0) -> Result<()> {
     set_maker_tree_v0::handler(ctx, args)
   }
 
+  pub fn add_maker_tree_v0(ctx: Context<AddMakerTreeV0>, args: AddMakerTreeArgsV0) -> Result<()> {
+    add_maker_tree_v0::handler(ctx, args)
+  }
+
   pub fn update_maker_tree_v0(
     ctx: Context<UpdateMakerTreeV0>,
     args: UpdateMakerTreeArgsV0,
@@ -92,4 +135,83 @@ pub mod helium_entity_manager {
   ) -> Result<()> {
     update_mobile_info_v0::handler(ctx, args)
   }
+
+  pub fn update_iot_info_with_hnt_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateIotInfoWithHntV0<'info>>,
+    args: UpdateIotInfoWithHntArgsV0,
+  ) -> Result<()> {
+    update_iot_info_with_hnt_v0::handler(ctx, args)
+  }
+
+  pub fn update_mobile_info_with_hnt_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateMobileInfoWithHntV0<'info>>,
+    args: UpdateMobileInfoWithHntArgsV0,
+  ) -> Result<()> {
+    update_mobile_info_with_hnt_v0::handler(ctx, args)
+  }
+
+  pub fn top_up_maker_escrow_v0(
+    ctx: Context<TopUpMakerEscrowV0>,
+    args: TopUpMakerEscrowArgsV0,
+  ) -> Result<()> {
+    top_up_maker_escrow_v0::handler(ctx, args)
+  }
+
+  pub fn delegate_maker_escrow_v0(
+    ctx: Context<DelegateMakerEscrowV0>,
+    args: DelegateMakerEscrowArgsV0,
+  ) -> Result<()> {
+    delegate_maker_escrow_v0::handler(ctx, args)
+  }
+
+  pub fn migrate_iot_info_v0(ctx: Context<MigrateIotInfoV0>) -> Result<()> {
+    migrate_iot_info_v0::handler(ctx)
+  }
+
+  pub fn migrate_mobile_info_v0(ctx: Context<MigrateMobileInfoV0>) -> Result<()> {
+    migrate_mobile_info_v0::handler(ctx)
+  }
+
+  pub fn reset_iot_info_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, ResetIotInfoV0<'info>>,
+    args: ResetIotInfoArgsV0,
+  ) -> Result<()> {
+    reset_iot_info_v0::handler(ctx, args)
+  }
+
+  pub fn reset_mobile_info_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, ResetMobileInfoV0<'info>>,
+    args: ResetMobileInfoArgsV0,
+  ) -> Result<()> {
+    reset_mobile_info_v0::handler(ctx, args)
+  }
+
+  pub fn bond_maker_v0(ctx: Context<BondMakerV0>, args: BondMakerArgsV0) -> Result<()> {
+    bond_maker_v0::handler(ctx, args)
+  }
+
+  pub fn slash_maker_bond_v0(
+    ctx: Context<SlashMakerBondV0>,
+    args: SlashMakerBondArgsV0,
+  ) -> Result<()> {
+    slash_maker_bond_v0::handler(ctx, args)
+  }
+
+  pub fn withdraw_maker_bond_v0(
+    ctx: Context<WithdrawMakerBondV0>,
+    args: WithdrawMakerBondArgsV0,
+  ) -> Result<()> {
+    withdraw_maker_bond_v0::handler(ctx, args)
+  }
+
+  pub fn track_maker_approval_v0(ctx: Context<TrackMakerApprovalV0>) -> Result<()> {
+    track_maker_approval_v0::handler(ctx)
+  }
+
+  pub fn set_maker_approvals_tracked_v0(
+    ctx: Context<SetMakerApprovalsTrackedV0>,
+    args: SetMakerApprovalsTrackedArgsV0,
+  ) -> Result<()> {
+    set_maker_approvals_tracked_v0::handler(ctx, args)
+  }
 }
@@ -1,4 +1,9 @@
-use anchor_lang::prelude::*;
+use crate::constants::MAX_MAKER_TREES;
+use crate::error::ErrorCode;
+use anchor_lang::{prelude::*, Discriminator};
+use std::io::Write;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
 
 #[account]
 #[derive(Default)]
@@ -9,9 +14,74 @@ pub struct RewardableEntityConfigV0 {
   pub settings: ConfigSettingsV0,
 
   pub bump_seed: u8,
+  // HNT a maker must have bonded before it can be approved
+  pub maker_bond: u64,
+  // How long a maker's bond stays slashable after an approval is revoked
+  pub maker_bond_cooldown_seconds: i64,
+  // Charged instead of the location fee for the first assert after an ownership reset
+  pub transfer_reassert_dc_fee: Option<u64>,
+  // Nominated by the authority, takes over once it accepts
+  pub pending_authority: Option<Pubkey>,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+impl RewardableEntityConfigV0 {
+  pub fn reassert_fee(&self, location_fee: Option<u64>, transferred: bool) -> Option<u64> {
+    match (location_fee, self.transfer_reassert_dc_fee) {
+      (Some(fee), Some(reduced)) if transferred => Some(fee.min(reduced)),
+      _ => location_fee,
+    }
+  }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LoraRegion {
+  Us915,
+  Eu868,
+  Eu433,
+  Cn470,
+  Cn779,
+  Au915,
+  As9231,
+  As9231b,
+  As9232,
+  As9233,
+  As9234,
+  Kr920,
+  In865,
+  Cd9001a,
+  Ru864,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct RegionGainLimitV0 {
+  pub region: LoraRegion,
+  pub min_gain: i32,
+  pub max_gain: i32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MobileDeviceTypeV0 {
+  Cbrs,
+  WifiIndoor,
+  WifiOutdoor,
+  WifiDataOnly,
+}
+
+impl Default for MobileDeviceTypeV0 {
+  fn default() -> Self {
+    MobileDeviceTypeV0::Cbrs
+  }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct MobileDeviceFeesV0 {
+  pub device_type: MobileDeviceTypeV0,
+  // Replaces the sub dao onboarding fee for this device type
+  pub dc_onboarding_fee: u64,
+  pub location_staking_fee: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub enum ConfigSettingsV0 {
   IotConfig {
     min_gain: i32,
@@ -23,6 +93,20 @@ pub enum ConfigSettingsV0 {
     full_location_staking_fee: u64,
     dataonly_location_staking_fee: u64,
   },
+  IotConfigV1 {
+    min_gain: i32,
+    max_gain: i32,
+    min_elevation: i32,
+    max_elevation: i32,
+    full_location_staking_fee: u64,
+    dataonly_location_staking_fee: u64,
+    // Overrides min_gain and max_gain for hotspots in these regions
+    region_gain_limits: Vec<RegionGainLimitV0>,
+  },
+  MobileConfigV1 {
+    // Device types without an entry can't be onboarded
+    fees_by_device: Vec<MobileDeviceFeesV0>,
+  },
 }
 
 impl Default for ConfigSettingsV0 {
@@ -36,6 +120,138 @@ impl Default for ConfigSettingsV0 {
   }
 }
 
+impl ConfigSettingsV0 {
+  /// Checks elevation and gain against the config. Regions without an entry in
+  /// `region_gain_limits` fall back to the global gain bounds.
+  pub fn is_valid_iot(
+    &self,
+    region: Option<LoraRegion>,
+    elevation: Option<i32>,
+    gain: Option<i32>,
+  ) -> bool {
+    match self {
+      ConfigSettingsV0::IotConfig {
+        min_gain, max_gain, ..
+      } => gain.map_or(true, |gain| gain >= *min_gain && gain <= *max_gain),
+      ConfigSettingsV0::IotConfigV1 {
+        min_gain,
+        max_gain,
+        min_elevation,
+        max_elevation,
+        region_gain_limits,
+        ..
+      } => {
+        let (min_gain, max_gain) = region
+          .and_then(|region| {
+            region_gain_limits
+              .iter()
+              .find(|limit| limit.region == region)
+          })
+          .map_or((*min_gain, *max_gain), |limit| {
+            (limit.min_gain, limit.max_gain)
+          });
+        let valid_elevation = elevation.map_or(true, |elevation| {
+          elevation >= *min_elevation && elevation <= *max_elevation
+        });
+        let valid_gain = gain.map_or(true, |gain| gain >= min_gain && gain <= max_gain);
+
+        valid_elevation && valid_gain
+      }
+      _ => true,
+    }
+  }
+
+  pub fn mobile_device_fees(&self, device_type: MobileDeviceTypeV0) -> Option<MobileDeviceFeesV0> {
+    match self {
+      ConfigSettingsV0::MobileConfigV1 { fees_by_device } => fees_by_device
+        .iter()
+        .find(|fees| fees.device_type == device_type)
+        .copied(),
+      _ => None,
+    }
+  }
+
+  /// The DC onboarding fee and, when the config charges one, the location staking fee
+  /// taken at onboarding.
+  pub fn iot_onboarding_fees(&self, sub_dao_fee: u64) -> (u64, Option<u64>) {
+    match self {
+      ConfigSettingsV0::IotConfig {
+        full_location_staking_fee,
+        ..
+      }
+      | ConfigSettingsV0::IotConfigV1 {
+        full_location_staking_fee,
+        ..
+      } => (sub_dao_fee, Some(*full_location_staking_fee)),
+      _ => (sub_dao_fee, None),
+    }
+  }
+
+  /// Same as `iot_onboarding_fees`. Only `MobileConfigV1` charges a location staking fee at
+  /// onboarding, and it replaces the sub dao fee by the device type's own.
+  pub fn mobile_onboarding_fees(
+    &self,
+    sub_dao_fee: u64,
+    device_type: MobileDeviceTypeV0,
+  ) -> Result<(u64, Option<u64>)> {
+    match self {
+      ConfigSettingsV0::MobileConfigV1 { .. } => {
+        let fees = self
+          .mobile_device_fees(device_type)
+          .ok_or_else(|| error!(ErrorCode::InvalidDeviceType))?;
+        Ok((fees.dc_onboarding_fee, Some(fees.location_staking_fee)))
+      }
+      _ => Ok((sub_dao_fee, None)),
+    }
+  }
+
+  /// DC burned when an iot hotspot asserts a new location, if the config charges for it
+  pub fn iot_location_fee(&self, is_full_hotspot: bool) -> Option<u64> {
+    match self {
+      ConfigSettingsV0::IotConfig {
+        full_location_staking_fee,
+        dataonly_location_staking_fee,
+        ..
+      }
+      | ConfigSettingsV0::IotConfigV1 {
+        full_location_staking_fee,
+        dataonly_location_staking_fee,
+        ..
+      } => Some(if is_full_hotspot {
+        *full_location_staking_fee
+      } else {
+        *dataonly_location_staking_fee
+      }),
+      _ => None,
+    }
+  }
+
+  /// DC burned when a mobile hotspot asserts a new location, if the config charges for it
+  pub fn mobile_location_fee(
+    &self,
+    is_full_hotspot: bool,
+    device_type: MobileDeviceTypeV0,
+  ) -> Result<Option<u64>> {
+    match self {
+      ConfigSettingsV0::MobileConfig {
+        full_location_staking_fee,
+        dataonly_location_staking_fee,
+      } => Ok(Some(if is_full_hotspot {
+        *full_location_staking_fee
+      } else {
+        *dataonly_location_staking_fee
+      })),
+      ConfigSettingsV0::MobileConfigV1 { .. } => Ok(Some(
+        self
+          .mobile_device_fees(device_type)
+          .ok_or_else(|| error!(ErrorCode::InvalidDeviceType))?
+          .location_staking_fee,
+      )),
+      _ => Ok(None),
+    }
+  }
+}
+
 #[account]
 #[derive(Default)]
 pub struct MakerV0 {
@@ -47,6 +263,60 @@ pub struct MakerV0 {
   pub merkle_tree: Pubkey,
   pub collection_bump_seed: u8,
   pub dao: Pubkey,
+  // Every tree registered to this maker, in the order they will be issued to.
+  // `merkle_tree` is the one currently being issued to.
+  pub merkle_trees: Vec<Pubkey>,
+  // Approvals that haven't been revoked. The bond can only be withdrawn once this is zero.
+  pub num_approvals: u16,
+  // Sum of `MakerApprovalV0.bond_amount` over those approvals
+  pub locked_bond: u64,
+  pub bond_unlock_ts: i64,
+  // Nominated by the update authority, takes over once it accepts
+  pub pending_update_authority: Option<Pubkey>,
+  // False on makers created before bonding, which may have approvals that aren't counted in
+  // `num_approvals`. Their bond stays locked until the dao authority confirms there are none left,
+  // see `set_maker_approvals_tracked_v0`.
+  pub approvals_tracked: bool,
+}
+
+impl MakerV0 {
+  pub fn is_registered_tree(&self, merkle_tree: &Pubkey) -> bool {
+    self.merkle_tree == *merkle_tree || self.merkle_trees.contains(merkle_tree)
+  }
+
+  /// The registered tree that follows the current one, if any
+  pub fn next_merkle_tree(&self) -> Option<Pubkey> {
+    self
+      .merkle_trees
+      .iter()
+      .position(|tree| *tree == self.merkle_tree)
+      .and_then(|idx| self.merkle_trees.get(idx + 1))
+      .copied()
+  }
+
+  /// Appends a tree to the end of the queue. The current tree only changes if the maker
+  /// doesn't have one yet.
+  pub fn queue_tree(&mut self, merkle_tree: Pubkey) -> Result<()> {
+    if self.merkle_tree == Pubkey::default() {
+      self.merkle_tree = merkle_tree;
+    }
+    // Legacy makers only tracked the current tree, keep it at the front of the queue
+    let current = self.merkle_tree;
+    self.register_tree(current)?;
+    self.register_tree(merkle_tree)
+  }
+
+  fn register_tree(&mut self, merkle_tree: Pubkey) -> Result<()> {
+    if !self.merkle_trees.contains(&merkle_tree) {
+      require_gt!(
+        MAX_MAKER_TREES,
+        self.merkle_trees.len(),
+        ErrorCode::TooManyMakerTrees
+      );
+      self.merkle_trees.push(merkle_tree);
+    }
+    Ok(())
+  }
 }
 
 #[account]
@@ -55,6 +325,38 @@ pub struct MakerApprovalV0 {
   pub rewardable_entity_config: Pubkey,
   pub maker: Pubkey,
   pub bump_seed: u8,
+  // Part of the maker's bond held for this config, slashable by its authority
+  pub bond_amount: u64,
+  // Whether this approval is counted in `MakerV0.num_approvals`. False on approvals made
+  // before makers had to bond, see `track_maker_approval_v0`.
+  pub tracked: bool,
+}
+
+#[account]
+#[derive(Default)]
+pub struct MakerRevocationV0 {
+  pub rewardable_entity_config: Pubkey,
+  pub maker: Pubkey,
+  // Bond held by the revoked approval, slashable until `unlock_ts`
+  pub bond_amount: u64,
+  pub unlock_ts: i64,
+  pub bump_seed: u8,
+}
+
+/// How `KeyToAssetV0.entity_key` is stored. PDAs hash the decoded address either way, see
+/// `entity_key_hash`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KeySerializationV0 {
+  /// Base58check decoded helium address bytes
+  B58,
+  /// Utf8 bytes of the base58 helium address
+  Utf8,
+}
+
+impl Default for KeySerializationV0 {
+  fn default() -> Self {
+    KeySerializationV0::B58
+  }
 }
 
 #[account]
@@ -64,6 +366,8 @@ pub struct KeyToAssetV0 {
   pub asset: Pubkey,
   pub entity_key: Vec<u8>,
   pub bump_seed: u8,
+  // Trailing so accounts created before this field read it from their zeroed padding as B58
+  pub key_serialization: KeySerializationV0,
 }
 
 #[account]
@@ -88,6 +392,97 @@ pub const IOT_HOTSPOT_INFO_SIZE: usize = 8 +
     2 + // num location assers
     60; // pad
 
+#[account]
+#[derive(Default)]
+pub struct IotHotspotInfoV1 {
+  pub asset: Pubkey,
+  pub bump_seed: u8,
+
+  pub location: Option<u64>,
+  pub elevation: Option<i32>,
+  pub gain: Option<i32>,
+  pub is_full_hotspot: bool,
+  pub num_location_asserts: u16,
+  pub region: Option<LoraRegion>,
+  // Owner chosen label, the animal name remains the canonical identifier
+  pub display_name: Option<String>,
+  pub last_asserted_ts: Option<i64>,
+  // Owner as of onboarding or the last ownership reset
+  pub owner: Option<Pubkey>,
+  // Set by an ownership reset until the new owner asserts a location
+  pub transferred: bool,
+}
+
+impl From<IotHotspotInfoV0> for IotHotspotInfoV1 {
+  fn from(info: IotHotspotInfoV0) -> Self {
+    IotHotspotInfoV1 {
+      asset: info.asset,
+      bump_seed: info.bump_seed,
+      location: info.location,
+      elevation: info.elevation,
+      gain: info.gain,
+      is_full_hotspot: info.is_full_hotspot,
+      num_location_asserts: info.num_location_asserts,
+      region: None,
+      display_name: None,
+      last_asserted_ts: None,
+      owner: None,
+      transferred: false,
+    }
+  }
+}
+
+pub type VersionedIotHotspotInfo = Versioned<IotHotspotInfoV0, IotHotspotInfoV1>;
+
+/// An account of either version, read as `New`. It is always written back as `New`, so an `Old`
+/// account is migrated in place the first time an instruction updates it. `Old` accounts can be
+/// smaller than the `New` data, so writers must `resize_to_fit` before exiting.
+#[derive(Clone, Default)]
+pub struct Versioned<Old, New>(pub New, PhantomData<Old>);
+
+impl<Old, New> AccountDeserialize for Versioned<Old, New>
+where
+  Old: AccountDeserialize + Discriminator,
+  New: AccountDeserialize + From<Old>,
+{
+  fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+    if buf.starts_with(&Old::discriminator()) {
+      return Ok(Self(Old::try_deserialize(buf)?.into(), PhantomData));
+    }
+    Ok(Self(New::try_deserialize(buf)?, PhantomData))
+  }
+
+  fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+    Self::try_deserialize(buf)
+  }
+}
+
+impl<Old, New: AccountSerialize> AccountSerialize for Versioned<Old, New> {
+  fn try_serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+    self.0.try_serialize(writer)
+  }
+}
+
+impl<Old, New> Owner for Versioned<Old, New> {
+  fn owner() -> Pubkey {
+    crate::id()
+  }
+}
+
+impl<Old, New> Deref for Versioned<Old, New> {
+  type Target = New;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<Old, New> DerefMut for Versioned<Old, New> {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
+
 #[account]
 #[derive(Default)]
 pub struct MobileHotspotInfoV0 {
@@ -105,3 +500,45 @@ pub const MOBILE_HOTSPOT_INFO_SIZE: usize = 8 +
     1 + // is full hotspot
     2 + // num location assers
     60; // pad
+
+#[account]
+#[derive(Default)]
+pub struct MobileHotspotInfoV1 {
+  pub asset: Pubkey,
+  pub bump_seed: u8,
+
+  pub location: Option<u64>,
+  pub is_full_hotspot: bool,
+  pub num_location_asserts: u16,
+  pub display_name: Option<String>,
+  pub device_type: MobileDeviceTypeV0,
+  pub last_asserted_ts: Option<i64>,
+  // Owner as of onboarding or the last ownership reset
+  pub owner: Option<Pubkey>,
+  // Set by an ownership reset until the new owner asserts a location
+  pub transferred: bool,
+}
+
+impl From<MobileHotspotInfoV0> for MobileHotspotInfoV1 {
+  fn from(info: MobileHotspotInfoV0) -> Self {
+    MobileHotspotInfoV1 {
+      asset: info.asset,
+      bump_seed: info.bump_seed,
+      location: info.location,
+      is_full_hotspot: info.is_full_hotspot,
+      num_location_asserts: info.num_location_asserts,
+      display_name: None,
+      // V0 only distinguished full hotspots, which were all cbrs radios
+      device_type: if info.is_full_hotspot {
+        MobileDeviceTypeV0::Cbrs
+      } else {
+        MobileDeviceTypeV0::WifiDataOnly
+      },
+      last_asserted_ts: None,
+      owner: None,
+      transferred: false,
+    }
+  }
+}
+
+pub type VersionedMobileHotspotInfo = Versioned<MobileHotspotInfoV0, MobileHotspotInfoV1>;
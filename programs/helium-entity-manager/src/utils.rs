@@ -0,0 +1,319 @@
+use crate::{constants::MAX_DISPLAY_NAME_LENGTH, error::ErrorCode, state::KeySerializationV0};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_spl::{
+  associated_token::AssociatedToken,
+  token::{Mint, Token, TokenAccount},
+};
+use circuit_breaker::{program::CircuitBreaker, MintWindowedCircuitBreakerV0};
+use data_credits::{
+  cpi::{accounts::MintDataCreditsV1, mint_data_credits_v1},
+  program::DataCredits,
+  DataCreditsV0, MintDataCreditsArgsV1,
+};
+use mpl_bubblegum::{state::leaf_schema::LeafSchema, utils::get_asset_id};
+use shared_utils::{verify_compressed_nft, VerifyCompressedNftArgs};
+
+const HELIUM_ADDRESS_VERSION: u8 = 0;
+const KEY_TYPE_ECC_COMPACT: u8 = 0;
+const KEY_TYPE_ED25519: u8 = 1;
+const NET_TYPE_MAIN: u8 = 0x00;
+const NET_TYPE_TEST: u8 = 0x10;
+// Version byte, key tag, 32 byte key and 4 byte checksum
+const HELIUM_ADDRESS_LEN: usize = 38;
+
+/// Display names are limited to printable ascii so they render the same everywhere
+/// and can't be confused with another hotspot's animal name through lookalike characters.
+pub fn validate_display_name(display_name: &str) -> Result<()> {
+  require!(
+    !display_name.is_empty() && display_name.len() <= MAX_DISPLAY_NAME_LENGTH,
+    ErrorCode::InvalidStringLength
+  );
+  require!(
+    display_name.chars().all(|c| c.is_ascii_alphanumeric()
+      || c == ' '
+      || c == '-'
+      || c == '_'
+      || c == '.'),
+    ErrorCode::StringNotAlphanumeric
+  );
+  require!(
+    display_name.trim() == display_name,
+    ErrorCode::StringNotAlphanumeric
+  );
+
+  Ok(())
+}
+
+/// Applies a display name update. An empty string clears the display name.
+pub fn update_display_name(current: &mut Option<String>, display_name: String) -> Result<()> {
+  if display_name.is_empty() {
+    *current = None;
+  } else {
+    validate_display_name(&display_name)?;
+    *current = Some(display_name);
+  }
+
+  Ok(())
+}
+
+/// Returns the base58 helium address for an entity key stored with the given serialization.
+pub fn entity_key_to_b58(
+  entity_key: &[u8],
+  key_serialization: KeySerializationV0,
+) -> Result<String> {
+  match key_serialization {
+    KeySerializationV0::B58 => Ok(bs58::encode(entity_key).into_string()),
+    KeySerializationV0::Utf8 => std::str::from_utf8(entity_key)
+      .map(|s| s.to_string())
+      .map_err(|_| error!(ErrorCode::InvalidHeliumKey)),
+  }
+}
+
+/// Hashes the decoded helium address, so a key issued as B58 bytes and as Utf8 text derives the
+/// same key_to_asset and info PDAs. Utf8 keys that don't decode are hashed as is, issuance
+/// rejects them anyway.
+pub fn entity_key_hash(entity_key: &[u8], key_serialization: KeySerializationV0) -> [u8; 32] {
+  let decoded = match key_serialization {
+    KeySerializationV0::B58 => None,
+    KeySerializationV0::Utf8 => std::str::from_utf8(entity_key)
+      .ok()
+      .and_then(|key| bs58::decode(key).into_vec().ok()),
+  };
+  hash(decoded.as_deref().unwrap_or(entity_key)).to_bytes()
+}
+
+/// Checks that the entity key decodes to an ecc compact or ed25519 helium address,
+/// with a valid checksum, on either the main or test network.
+pub fn validate_helium_key(entity_key: &[u8], key_serialization: KeySerializationV0) -> Result<()> {
+  let address = match key_serialization {
+    KeySerializationV0::B58 => entity_key.to_vec(),
+    KeySerializationV0::Utf8 => bs58::decode(entity_key_to_b58(entity_key, key_serialization)?)
+      .into_vec()
+      .map_err(|_| error!(ErrorCode::InvalidHeliumKey))?,
+  };
+
+  require_eq!(
+    address.len(),
+    HELIUM_ADDRESS_LEN,
+    ErrorCode::InvalidHeliumKey
+  );
+  require_eq!(
+    address[0],
+    HELIUM_ADDRESS_VERSION,
+    ErrorCode::InvalidHeliumKey
+  );
+
+  let net_type = address[1] & 0xf0;
+  let key_type = address[1] & 0x0f;
+  require!(
+    net_type == NET_TYPE_MAIN || net_type == NET_TYPE_TEST,
+    ErrorCode::InvalidHeliumKey
+  );
+  require!(
+    key_type == KEY_TYPE_ECC_COMPACT || key_type == KEY_TYPE_ED25519,
+    ErrorCode::InvalidHeliumKey
+  );
+
+  let (payload, checksum) = address.split_at(HELIUM_ADDRESS_LEN - 4);
+  let digest = hash(&hash(payload).to_bytes()).to_bytes();
+  require!(digest[..4] == *checksum, ErrorCode::InvalidHeliumKey);
+
+  Ok(())
+}
+
+/// Proves `owner` holds the compressed hotspot at `index` by rebuilding its leaf on chain
+/// instead of trusting a client supplied leaf hash. A bubblegum transfer resets the leaf
+/// delegate to the new owner, so the delegate is expected to be the owner.
+#[allow(clippy::too_many_arguments)]
+pub fn verify_hotspot_owner<'info>(
+  merkle_tree: AccountInfo<'info>,
+  compression_program: AccountInfo<'info>,
+  owner: Pubkey,
+  data_hash: [u8; 32],
+  creator_hash: [u8; 32],
+  root: [u8; 32],
+  index: u32,
+  proof_accounts: Vec<AccountInfo<'info>>,
+) -> Result<Pubkey> {
+  let asset_id = get_asset_id(&merkle_tree.key(), index.into());
+  let leaf = LeafSchema::new_v0(
+    asset_id,
+    owner,
+    owner,
+    index.into(),
+    data_hash,
+    creator_hash,
+  );
+
+  verify_compressed_nft(VerifyCompressedNftArgs {
+    hash: leaf.to_node(),
+    root,
+    index,
+    compression_program,
+    merkle_tree,
+    owner,
+    delegate: owner,
+    proof_accounts,
+  })?;
+
+  Ok(asset_id)
+}
+
+/// Accounts for buying the exact DC a fee needs with HNT. Shared by the `_with_hnt` variants
+/// of the onboarding and update instructions.
+#[derive(Accounts)]
+pub struct HntPaymentV0<'info> {
+  #[account(mut)]
+  pub hnt_owner: Signer<'info>,
+  #[account(
+    mut,
+    constraint = hnt_burner.mint == hnt_mint.key(),
+    constraint = hnt_burner.owner == hnt_owner.key(),
+  )]
+  pub hnt_burner: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub hnt_mint: Box<Account<'info, Mint>>,
+  #[account(mut)]
+  pub dc_mint: Box<Account<'info, Mint>>,
+  #[account(
+    seeds = [
+      "dc".as_bytes(),
+      dc_mint.key().as_ref(),
+    ],
+    seeds::program = data_credits_program.key(),
+    bump = data_credits.data_credits_bump,
+    has_one = dc_mint,
+    has_one = hnt_mint,
+    has_one = hnt_price_oracle,
+  )]
+  pub data_credits: Box<Account<'info, DataCreditsV0>>,
+  /// CHECK: Checked by cpi
+  pub hnt_price_oracle: AccountInfo<'info>,
+  #[account(
+    mut,
+    seeds = ["mint_windowed_breaker".as_bytes(), dc_mint.key().as_ref()],
+    seeds::program = circuit_breaker_program.key(),
+    bump = circuit_breaker.bump_seed
+  )]
+  pub circuit_breaker: Box<Account<'info, MintWindowedCircuitBreakerV0>>,
+
+  pub circuit_breaker_program: Program<'info, CircuitBreaker>,
+  pub data_credits_program: Program<'info, DataCredits>,
+  pub token_program: Program<'info, Token>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub system_program: Program<'info, System>,
+}
+
+impl<'info> HntPaymentV0<'info> {
+  /// Burns the HNT needed to mint exactly `dc_amount` DC to `recipient`, failing if that
+  /// takes more than `max_hnt`.
+  pub fn mint_dc(
+    &mut self,
+    recipient: AccountInfo<'info>,
+    recipient_token_account: AccountInfo<'info>,
+    dc_amount: u64,
+    max_hnt: u64,
+  ) -> Result<()> {
+    if dc_amount == 0 {
+      return Ok(());
+    }
+
+    let pre_balance = self.hnt_burner.amount;
+    let cpi_accounts = MintDataCreditsV1 {
+      data_credits: self.data_credits.to_account_info(),
+      hnt_price_oracle: self.hnt_price_oracle.to_account_info(),
+      burner: self.hnt_burner.to_account_info(),
+      recipient_token_account,
+      recipient,
+      owner: self.hnt_owner.to_account_info(),
+      hnt_mint: self.hnt_mint.to_account_info(),
+      dc_mint: self.dc_mint.to_account_info(),
+      circuit_breaker: self.circuit_breaker.to_account_info(),
+      circuit_breaker_program: self.circuit_breaker_program.to_account_info(),
+      token_program: self.token_program.to_account_info(),
+      system_program: self.system_program.to_account_info(),
+      associated_token_program: self.associated_token_program.to_account_info(),
+    };
+    mint_data_credits_v1(
+      CpiContext::new(self.data_credits_program.to_account_info(), cpi_accounts),
+      MintDataCreditsArgsV1 {
+        hnt_amount: None,
+        dc_amount: Some(dc_amount),
+      },
+    )?;
+
+    self.hnt_burner.reload()?;
+    let hnt_burned = pre_balance.checked_sub(self.hnt_burner.amount).unwrap();
+    require_gte!(max_hnt, hnt_burned, ErrorCode::HntSlippageExceeded);
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const ED25519_ADDRESS: &str = "12xpAPzxFns5GQqSWcCfWmoSkgzz8xYC4HX18YQHNcPMTtDuQHC";
+  const ECC_COMPACT_ADDRESS: &str = "115EfcN5YQmt1PzEsWSByYk3MNjTmMvPz4KpGZoZSFpFNERG722";
+
+  fn decoded(address: &str) -> Vec<u8> {
+    bs58::decode(address).into_vec().unwrap()
+  }
+
+  #[test]
+  fn test_validates_b58_keys() {
+    assert!(validate_helium_key(&decoded(ED25519_ADDRESS), KeySerializationV0::B58).is_ok());
+    assert!(validate_helium_key(&decoded(ECC_COMPACT_ADDRESS), KeySerializationV0::B58).is_ok());
+  }
+
+  #[test]
+  fn test_validates_utf8_keys() {
+    assert!(validate_helium_key(ED25519_ADDRESS.as_bytes(), KeySerializationV0::Utf8).is_ok());
+    assert!(validate_helium_key(ECC_COMPACT_ADDRESS.as_bytes(), KeySerializationV0::Utf8).is_ok());
+  }
+
+  #[test]
+  fn test_rejects_mismatched_serialization() {
+    assert!(validate_helium_key(ED25519_ADDRESS.as_bytes(), KeySerializationV0::B58).is_err());
+    assert!(validate_helium_key(&decoded(ECC_COMPACT_ADDRESS), KeySerializationV0::Utf8).is_err());
+  }
+
+  #[test]
+  fn test_rejects_bad_checksum() {
+    let mut key = decoded(ED25519_ADDRESS);
+    key[10] ^= 1;
+    assert!(validate_helium_key(&key, KeySerializationV0::B58).is_err());
+  }
+
+  #[test]
+  fn test_rejects_unsupported_key_type() {
+    // Multisig tag on an otherwise well formed address
+    let mut key = decoded(ECC_COMPACT_ADDRESS);
+    key[1] = 2;
+    let digest = hash(&hash(&key[..34]).to_bytes()).to_bytes();
+    key[34..].copy_from_slice(&digest[..4]);
+    assert!(validate_helium_key(&key, KeySerializationV0::B58).is_err());
+  }
+
+  #[test]
+  fn test_round_trips_to_b58() {
+    assert_eq!(
+      entity_key_to_b58(&decoded(ED25519_ADDRESS), KeySerializationV0::B58).unwrap(),
+      ED25519_ADDRESS
+    );
+    assert_eq!(
+      entity_key_to_b58(ECC_COMPACT_ADDRESS.as_bytes(), KeySerializationV0::Utf8).unwrap(),
+      ECC_COMPACT_ADDRESS
+    );
+  }
+
+  #[test]
+  fn test_hashes_both_serializations_the_same() {
+    assert_eq!(
+      entity_key_hash(&decoded(ECC_COMPACT_ADDRESS), KeySerializationV0::B58),
+      entity_key_hash(ECC_COMPACT_ADDRESS.as_bytes(), KeySerializationV0::Utf8)
+    );
+  }
+}
@@ -46,4 +46,52 @@ pub enum ErrorCode {
 
   #[msg("Cannot change a position while it is delegated")]
   PositionChangeWhileDelegated,
+
+  #[msg("Invalid string length, your string was likely too long")]
+  InvalidStringLength,
+
+  #[msg("Rewards have not been issued for this epoch yet")]
+  RewardsNotIssued,
+
+  #[msg("Rewards for ended epochs must be claimed first")]
+  UnclaimedRewards,
+
+  #[msg("Cannot redelegate a position that expires this epoch")]
+  PositionExpiring,
+
+  #[msg("Epoch length must be cron expressible and the offset shorter than an epoch")]
+  InvalidEpochLength,
+
+  #[msg("Percent must be between 0 and 100")]
+  InvalidPercent,
+
+  #[msg("Utility score divisors and exponent denominators must be non-zero")]
+  InvalidUtilityScoreConfig,
+
+  #[msg("Active devices can only be tracked for epochs that have ended")]
+  InvalidActiveDeviceEpoch,
+
+  #[msg("Epoch infos must be consecutive epochs after the last vehnt calculation, up to now")]
+  InvalidCatchUpEpochs,
+
+  #[msg("Schedules can have at most 100 entries")]
+  ScheduleTooLong,
+
+  #[msg("Schedule start times must be strictly increasing")]
+  ScheduleNotSorted,
+
+  #[msg("Schedule entries that have already started cannot be changed or removed")]
+  SchedulePastEntryChanged,
+
+  #[msg("Emission schedules must have an entry that has already started")]
+  ScheduleNotStarted,
+
+  #[msg("Active devices must be tracked for sub daos with an active device authority")]
+  ActiveDevicesNotTracked,
+
+  #[msg("The active device aggregator and history buffer must match the sub dao")]
+  InvalidActiveDeviceAggregator,
+
+  #[msg("Clockwork threads must be passed along with the clockwork program")]
+  MissingClockworkThread,
 }
@@ -1,8 +1,10 @@
-use crate::{current_epoch, error::ErrorCode, state::*, update_subdao_vehnt, OrArithError};
+use crate::{
+  calculate_utility_score, current_epoch, error::ErrorCode, pay_crank_bounty, state::*,
+  update_subdao_vehnt,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token};
 use circuit_breaker::CircuitBreaker;
-use shared_utils::precise_number::{PreciseNumber, FOUR_PREC, TWO_PREC};
 use switchboard_v2::{AggregatorAccountData, AggregatorHistoryBuffer};
 use voter_stake_registry::state::Registrar;
 
@@ -28,15 +30,16 @@ pub struct CalculateUtilityScoreV0<'info> {
   #[account(
     mut,
     has_one = dao,
-    has_one = active_device_aggregator
   )]
   pub sub_dao: Box<Account<'info, SubDaoV0>>,
+  /// Only needed when the sub dao has no active device authority
   #[account(
+    constraint = active_device_aggregator.key() == sub_dao.active_device_aggregator @ ErrorCode::InvalidActiveDeviceAggregator,
     has_one = history_buffer
   )]
-  pub active_device_aggregator: AccountLoader<'info, AggregatorAccountData>,
+  pub active_device_aggregator: Option<AccountLoader<'info, AggregatorAccountData>>,
   /// CHECK: Checked by has_one with active device aggregator
-  pub history_buffer: AccountInfo<'info>,
+  pub history_buffer: Option<AccountInfo<'info>>,
   #[account(
     seeds = ["dao_epoch_info".as_bytes(), dao.key().as_ref(), &(args.epoch - 1).to_le_bytes()],
     bump,
@@ -59,6 +62,12 @@ pub struct CalculateUtilityScoreV0<'info> {
     bump,
   )]
   pub sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
+  #[account(
+    mut,
+    seeds = ["crank_vault".as_bytes(), dao.key().as_ref()],
+    bump,
+  )]
+  pub crank_vault: SystemAccount<'info>,
   pub system_program: Program<'info, System>,
   pub token_program: Program<'info, Token>,
   pub circuit_breaker_program: Program<'info, CircuitBreaker>,
@@ -69,7 +78,7 @@ pub fn handler(
   args: CalculateUtilityScoreArgsV0,
 ) -> Result<()> {
   let curr_ts = ctx.accounts.registrar.clock_unix_timestamp();
-  let epoch = current_epoch(curr_ts);
+  let epoch = current_epoch(&ctx.accounts.dao, curr_ts);
 
   // Set total rewards, accounting for net emmissions by counting
   // burned hnt since last supply setting.
@@ -110,8 +119,9 @@ pub fn handler(
   }
 
   ctx.accounts.sub_dao_epoch_info.epoch = args.epoch;
-  let epoch_end_ts = ctx.accounts.sub_dao_epoch_info.end_ts();
+  let epoch_end_ts = ctx.accounts.sub_dao_epoch_info.end_ts(&ctx.accounts.dao);
   update_subdao_vehnt(
+    &ctx.accounts.dao,
     &mut ctx.accounts.sub_dao,
     &mut ctx.accounts.sub_dao_epoch_info,
     epoch_end_ts,
@@ -123,27 +133,29 @@ pub fn handler(
   ctx.accounts.sub_dao_epoch_info.initialized = true;
   ctx.accounts.dao_epoch_info.bump_seed = *ctx.bumps.get("dao_epoch_info").unwrap();
 
-  // Calculate utility score
-  // utility score = V * D * A
-  // V = max(1, veHNT_dnp).
-  // D = max(1, sqrt(DCs burned in USD)). 1 DC = $0.00001.
-  // A = max(1, fourth_root(Total active device count * device activation fee)).
   let epoch_info = &mut ctx.accounts.sub_dao_epoch_info;
 
-  let dc_burned = PreciseNumber::new(epoch_info.dc_burned.into())
-    .or_arith_error()?
-    .checked_div(&PreciseNumber::new(100000_u128).or_arith_error()?) // DC has 0 decimals, plus 10^5 to get to dollars.
-    .or_arith_error()?;
-
-  let history_buffer = AggregatorHistoryBuffer::new(&ctx.accounts.history_buffer)?;
-
-  let total_devices_opt = history_buffer.lower_bound(epoch_end_ts);
-
-  let total_devices_u64: u64;
-  if let Some(total_devices_row) = total_devices_opt {
-    total_devices_u64 = total_devices_row.value.try_into().unwrap();
+  // Sub daos with an active device authority must have the count attested, the
+  // aggregator is only read for sub daos without one
+  let total_devices_u64: u64 = if ctx.accounts.sub_dao.active_device_authority != Pubkey::default()
+  {
+    epoch_info
+      .active_devices
+      .ok_or_else(|| error!(ErrorCode::ActiveDevicesNotTracked))?
   } else {
-    total_devices_u64 = 0;
+    let history_buffer_account = match (
+      &ctx.accounts.active_device_aggregator,
+      &ctx.accounts.history_buffer,
+    ) {
+      (Some(_), Some(history_buffer)) => history_buffer,
+      _ => return Err(error!(ErrorCode::InvalidActiveDeviceAggregator)),
+    };
+    let history_buffer = AggregatorHistoryBuffer::new(history_buffer_account)?;
+    if let Some(total_devices_row) = history_buffer.lower_bound(epoch_end_ts) {
+      total_devices_row.value.try_into().unwrap()
+    } else {
+      0
+    }
   };
 
   msg!(
@@ -152,68 +164,15 @@ pub fn handler(
     epoch_info.dc_burned
   );
 
-  let total_devices = PreciseNumber::new(total_devices_u64.into()).or_arith_error()?;
-  let devices_with_fee = total_devices
-    .checked_mul(
-      &PreciseNumber::new(u128::from(ctx.accounts.sub_dao.onboarding_dc_fee)).or_arith_error()?,
-    )
-    .or_arith_error()?
-    .checked_div(&PreciseNumber::new(100000_u128).or_arith_error()?) // Need onboarding fee in dollars
-    .or_arith_error()?;
-
-  // sqrt(x) = e^(ln(x)/2)
-  // x^1/4 = e^(ln(x)/4))
-  let one = PreciseNumber::one();
-  let d = if epoch_info.dc_burned > 0 {
-    std::cmp::max(
-      one.clone(),
-      dc_burned
-        .log()
-        .or_arith_error()?
-        .checked_div(&FOUR_PREC.clone().signed())
-        .or_arith_error()?
-        .exp()
-        .or_arith_error()?,
-    )
-  } else {
-    one.clone()
-  };
-
-  let vehnt_staked = PreciseNumber::new(epoch_info.vehnt_at_epoch_start.into())
-    .or_arith_error()?
-    .checked_div(&PreciseNumber::new(100000000_u128).or_arith_error()?) // vehnt has 8 decimals
-    .or_arith_error()?;
-
-  let v = std::cmp::max(one.clone(), vehnt_staked);
-
-  let a = if total_devices_u64 > 0 {
-    std::cmp::max(
-      one,
-      devices_with_fee
-        .log()
-        .or_arith_error()?
-        .checked_div(&TWO_PREC.clone().signed())
-        .or_arith_error()?
-        .exp()
-        .or_arith_error()?,
-    )
-  } else {
-    one
-  };
-
-  let utility_score_prec = d
-    .checked_mul(&a)
-    .or_arith_error()?
-    .checked_mul(&v)
-    .or_arith_error()?;
-  // Convert to u128 with 12 decimals of precision
-  let utility_score = utility_score_prec
-    .checked_mul(
-      &PreciseNumber::new(1000000000000_u128).or_arith_error()?, // u128 with 12 decimal places
-    )
-    .or_arith_error()?
-    .to_imprecise()
-    .unwrap();
+  // Calculate utility score from the dao's config, by default V * D * A. See `UtilityScoreConfig`
+  let utility_score = calculate_utility_score(
+    &ctx.accounts.dao.utility_score_config(),
+    epoch_info.vehnt_at_epoch_start.into(),
+    epoch_info.dc_burned,
+    total_devices_u64,
+    ctx.accounts.sub_dao.onboarding_dc_fee,
+  )
+  .ok_or_else(|| error!(ErrorCode::ArithmeticError))?;
 
   // Store utility scores
   epoch_info.utility_score = Some(utility_score);
@@ -236,5 +195,13 @@ pub fn handler(
     ctx.accounts.dao_epoch_info.done_calculating_scores = true;
   }
 
+  pay_crank_bounty(
+    &ctx.accounts.dao,
+    &ctx.accounts.crank_vault.to_account_info(),
+    ctx.bumps["crank_vault"],
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+  )?;
+
   Ok(())
 }
@@ -0,0 +1,84 @@
+use crate::{current_epoch, error::ErrorCode, id, state::*, update_subdao_vehnt};
+use anchor_lang::prelude::*;
+use shared_utils::init_pda_account;
+use voter_stake_registry::state::Registrar;
+
+pub const MAX_CATCH_UP_EPOCHS: usize = 10;
+
+#[derive(Accounts)]
+pub struct CatchUpVehntV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub registrar: Box<Account<'info, Registrar>>,
+  #[account(
+    has_one = registrar,
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  #[account(
+    mut,
+    has_one = dao,
+  )]
+  pub sub_dao: Box<Account<'info, SubDaoV0>>,
+  pub system_program: Program<'info, System>,
+}
+
+/// Walks the sub dao's vehnt forward one epoch at a time after missed epochs, so that vehnt can
+/// again be calculated linearly. The remaining accounts must be the writable sub dao epoch infos
+/// for a contiguous run of epochs starting at the one after `vehnt_last_calculated_ts`, in order.
+/// Missing epoch infos are created.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CatchUpVehntV0<'info>>) -> Result<()> {
+  require!(
+    !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() <= MAX_CATCH_UP_EPOCHS,
+    ErrorCode::InvalidCatchUpEpochs
+  );
+
+  let dao = &ctx.accounts.dao;
+  let sub_dao = &mut ctx.accounts.sub_dao;
+  let sub_dao_key = sub_dao.key();
+  let epoch = current_epoch(dao, ctx.accounts.registrar.clock_unix_timestamp());
+  let mut catch_up_epoch = current_epoch(dao, sub_dao.vehnt_last_calculated_ts);
+  for account in ctx.remaining_accounts {
+    catch_up_epoch += 1;
+    require_gte!(epoch, catch_up_epoch, ErrorCode::InvalidCatchUpEpochs);
+
+    let (expected, bump_seed) = Pubkey::find_program_address(
+      &[
+        "sub_dao_epoch_info".as_bytes(),
+        sub_dao_key.as_ref(),
+        &catch_up_epoch.to_le_bytes(),
+      ],
+      &id(),
+    );
+    require_keys_eq!(expected, account.key(), ErrorCode::InvalidCatchUpEpochs);
+
+    if account.owner != &id() {
+      init_pda_account(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        account,
+        &[
+          "sub_dao_epoch_info".as_bytes(),
+          sub_dao_key.as_ref(),
+          &catch_up_epoch.to_le_bytes(),
+          &[bump_seed],
+        ],
+        60 + 8 + std::mem::size_of::<SubDaoEpochInfoV0>(),
+        &SubDaoEpochInfoV0 {
+          epoch: catch_up_epoch,
+          sub_dao: sub_dao_key,
+          bump_seed,
+          ..Default::default()
+        },
+      )?;
+    }
+
+    let mut sub_dao_epoch_info: Account<SubDaoEpochInfoV0> = Account::try_from(account)?;
+    let epoch_start_ts = sub_dao_epoch_info.start_ts(dao);
+    // Records vehnt at the start of the epoch and applies its closing position corrections
+    update_subdao_vehnt(dao, sub_dao, &mut sub_dao_epoch_info, epoch_start_ts)?;
+    sub_dao_epoch_info.initialized = true;
+    sub_dao_epoch_info.exit(&id())?;
+  }
+
+  Ok(())
+}
@@ -47,6 +47,8 @@ fn construct_next_ix(ctx: &Context<CalculateKickoffV0>, epoch: u64) -> Option<In
     &epoch.to_le_bytes(),
   ];
   let sub_dao_epoch_info = Pubkey::find_program_address(sub_dao_ei_seeds, &crate::id()).0;
+  let crank_vault =
+    Pubkey::find_program_address(&["crank_vault".as_bytes(), dao_key.as_ref()], &crate::id()).0;
 
   // build calculate utility score ix
   Some(Instruction {
@@ -57,17 +59,20 @@ fn construct_next_ix(ctx: &Context<CalculateKickoffV0>, epoch: u64) -> Option<In
       dao: ctx.accounts.dao.key(),
       hnt_mint: ctx.accounts.hnt_mint.key(),
       sub_dao: sub_dao_key,
-      active_device_aggregator: ctx.accounts.active_device_aggregator.key(),
-      history_buffer: ctx
-        .accounts
-        .active_device_aggregator
-        .load()
-        .ok()
-        .unwrap()
-        .history_buffer,
+      active_device_aggregator: Some(ctx.accounts.active_device_aggregator.key()),
+      history_buffer: Some(
+        ctx
+          .accounts
+          .active_device_aggregator
+          .load()
+          .ok()
+          .unwrap()
+          .history_buffer,
+      ),
       prev_dao_epoch_info,
       dao_epoch_info,
       sub_dao_epoch_info,
+      crank_vault,
       system_program: ctx.accounts.system_program.key(),
       token_program: ctx.accounts.token_program.key(),
       circuit_breaker_program: ctx.accounts.circuit_breaker_program.key(),
@@ -83,7 +88,7 @@ fn construct_next_ix(ctx: &Context<CalculateKickoffV0>, epoch: u64) -> Option<In
 /// This instruction is responsible for deriving the calculate utility score ix
 pub fn handler(ctx: Context<CalculateKickoffV0>) -> Result<ThreadResponse> {
   let curr_ts = Clock::get()?.unix_timestamp;
-  let epoch = current_epoch(curr_ts) - 1; // operate calculations on previous epoch
+  let epoch = current_epoch(&ctx.accounts.dao, curr_ts) - 1; // operate calculations on previous epoch
   let calculate_utility_ix = construct_next_ix(&ctx, epoch).unwrap();
   Ok(ThreadResponse {
     dynamic_instruction: Some(calculate_utility_ix.into()),
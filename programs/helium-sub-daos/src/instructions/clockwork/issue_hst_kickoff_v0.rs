@@ -2,7 +2,7 @@ use crate::{current_epoch, state::*};
 use anchor_lang::{prelude::*, solana_program::instruction::Instruction, InstructionData};
 use anchor_spl::token::{Mint, Token};
 use circuit_breaker::CircuitBreaker;
-use clockwork_sdk::{self, state::ThreadResponse};
+use clockwork_sdk::{self, state::ThreadResponse, utils::PAYER_PUBKEY};
 
 #[derive(Accounts)]
 pub struct IssueHstKickoffV0<'info> {
@@ -26,6 +26,8 @@ fn construct_issue_hst_ix(ctx: &Context<IssueHstKickoffV0>, epoch: u64) -> Instr
     &epoch.to_le_bytes(),
   ];
   let dao_epoch_info = Pubkey::find_program_address(dao_ei_seeds, &crate::id()).0;
+  let crank_vault =
+    Pubkey::find_program_address(&["crank_vault".as_bytes(), dao_key.as_ref()], &crate::id()).0;
 
   let hnt_circuit_breaker = Pubkey::find_program_address(
     &[
@@ -39,11 +41,13 @@ fn construct_issue_hst_ix(ctx: &Context<IssueHstKickoffV0>, epoch: u64) -> Instr
   Instruction {
     program_id: crate::ID,
     accounts: crate::accounts::IssueHstPoolV0 {
+      payer: PAYER_PUBKEY,
       dao: ctx.accounts.dao.key(),
       dao_epoch_info,
       hnt_circuit_breaker,
       hnt_mint: ctx.accounts.hnt_mint.key(),
       hst_pool: ctx.accounts.dao.hst_pool,
+      crank_vault,
       system_program: ctx.accounts.system_program.key(),
       token_program: ctx.accounts.token_program.key(),
       circuit_breaker_program: ctx.accounts.circuit_breaker_program.key(),
@@ -58,7 +62,7 @@ fn construct_issue_hst_ix(ctx: &Context<IssueHstKickoffV0>, epoch: u64) -> Instr
 
 pub fn handler(ctx: Context<IssueHstKickoffV0>) -> Result<ThreadResponse> {
   let curr_ts = Clock::get()?.unix_timestamp;
-  let epoch = current_epoch(curr_ts) - 1; // operate calculations on previous epoch
+  let epoch = current_epoch(&ctx.accounts.dao, curr_ts) - 1; // operate calculations on previous epoch
   let issue_hst_ix = construct_issue_hst_ix(&ctx, epoch);
   Ok(ThreadResponse {
     dynamic_instruction: Some(issue_hst_ix.into()),
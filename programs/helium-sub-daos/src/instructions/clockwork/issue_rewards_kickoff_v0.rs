@@ -2,7 +2,7 @@ use crate::{current_epoch, state::*};
 use anchor_lang::{prelude::*, solana_program::instruction::Instruction, InstructionData};
 use anchor_spl::token::{Mint, Token};
 use circuit_breaker::CircuitBreaker;
-use clockwork_sdk::{self, state::ThreadResponse};
+use clockwork_sdk::{self, state::ThreadResponse, utils::PAYER_PUBKEY};
 
 #[derive(Accounts)]
 pub struct IssueRewardsKickoffV0<'info> {
@@ -40,6 +40,8 @@ fn construct_issue_rewards_ix(ctx: &Context<IssueRewardsKickoffV0>, epoch: u64)
     &epoch.to_le_bytes(),
   ];
   let sub_dao_epoch_info = Pubkey::find_program_address(sub_dao_ei_seeds, &crate::id()).0;
+  let crank_vault =
+    Pubkey::find_program_address(&["crank_vault".as_bytes(), dao_key.as_ref()], &crate::id()).0;
 
   let hnt_circuit_breaker = Pubkey::find_program_address(
     &[
@@ -61,6 +63,7 @@ fn construct_issue_rewards_ix(ctx: &Context<IssueRewardsKickoffV0>, epoch: u64)
   Instruction {
     program_id: crate::ID,
     accounts: crate::accounts::IssueRewardsV0 {
+      payer: PAYER_PUBKEY,
       dao: ctx.accounts.dao.key(),
       sub_dao: ctx.accounts.sub_dao.key(),
       dao_epoch_info,
@@ -72,6 +75,7 @@ fn construct_issue_rewards_ix(ctx: &Context<IssueRewardsKickoffV0>, epoch: u64)
       treasury: ctx.accounts.sub_dao.treasury,
       rewards_escrow: ctx.accounts.sub_dao.rewards_escrow,
       delegator_pool: ctx.accounts.sub_dao.delegator_pool,
+      crank_vault,
       system_program: ctx.accounts.system_program.key(),
       token_program: ctx.accounts.token_program.key(),
       circuit_breaker_program: ctx.accounts.circuit_breaker_program.key(),
@@ -86,7 +90,7 @@ fn construct_issue_rewards_ix(ctx: &Context<IssueRewardsKickoffV0>, epoch: u64)
 
 pub fn handler(ctx: Context<IssueRewardsKickoffV0>) -> Result<ThreadResponse> {
   let curr_ts = Clock::get()?.unix_timestamp;
-  let epoch = current_epoch(curr_ts) - 1; // operate calculations on previous epoch
+  let epoch = current_epoch(&ctx.accounts.dao, curr_ts) - 1; // operate calculations on previous epoch
   let issue_rewards_ix = construct_issue_rewards_ix(&ctx, epoch);
   Ok(ThreadResponse {
     dynamic_instruction: Some(issue_rewards_ix.into()),
@@ -54,7 +54,7 @@ pub fn handler(ctx: Context<ResetDaoThreadV0>) -> Result<()> {
   );
 
   let curr_ts = Clock::get()?.unix_timestamp;
-  let epoch = current_epoch(curr_ts);
+  let epoch = current_epoch(&ctx.accounts.dao, curr_ts);
 
   let dao_key = ctx.accounts.dao.key();
   let dao_ei_seeds: &[&[u8]] = &[
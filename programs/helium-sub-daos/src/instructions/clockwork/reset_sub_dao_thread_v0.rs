@@ -61,7 +61,7 @@ pub fn handler(ctx: Context<ResetSubDaoThreadV0>) -> Result<()> {
   );
   let curr_ts = Clock::get()?.unix_timestamp;
 
-  let cron = create_end_epoch_cron(curr_ts, 60 * 5);
+  let cron = create_end_epoch_cron(&ctx.accounts.dao, curr_ts, 60 * 5);
 
   let signer_seeds: &[&[&[u8]]] = &[&[
     "sub_dao".as_bytes(),
@@ -124,7 +124,7 @@ pub fn handler(ctx: Context<ResetSubDaoThreadV0>) -> Result<()> {
   }
 
   // reset the issue thread
-  let epoch = current_epoch(curr_ts);
+  let epoch = current_epoch(&ctx.accounts.dao, curr_ts);
   let dao_epoch_info = Pubkey::find_program_address(
     &[
       "dao_epoch_info".as_bytes(),
@@ -0,0 +1,139 @@
+use crate::{claim_epoch_rewards, current_epoch, error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+  associated_token::AssociatedToken,
+  token::{Mint, Token, TokenAccount},
+};
+use circuit_breaker::{
+  cpi::{accounts::TransferV0, transfer_v0},
+  CircuitBreaker, TransferArgsV0,
+};
+use voter_stake_registry::{
+  state::{PositionV0, Registrar},
+  VoterStakeRegistry,
+};
+
+#[derive(Accounts)]
+pub struct BatchClaimRewardsV0<'info> {
+  #[account(
+    seeds = [b"position".as_ref(), mint.key().as_ref()],
+    seeds::program = vsr_program.key(),
+    bump = position.bump_seed,
+    has_one = mint,
+    has_one = registrar,
+  )]
+  pub position: Box<Account<'info, PositionV0>>,
+  pub mint: Box<Account<'info, Mint>>,
+  #[account(
+    token::mint = mint,
+    token::authority = position_authority,
+    constraint = position_token_account.amount > 0
+  )]
+  pub position_token_account: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub position_authority: Signer<'info>,
+  pub registrar: Box<Account<'info, Registrar>>,
+  #[account(
+    has_one = registrar
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+
+  #[account(
+    mut,
+    has_one = delegator_pool,
+    has_one = dnt_mint,
+    has_one = dao,
+  )]
+  pub sub_dao: Account<'info, SubDaoV0>,
+  #[account(
+    mut,
+    has_one = sub_dao,
+    seeds = ["delegated_position".as_bytes(), position.key().as_ref()],
+    bump,
+  )]
+  pub delegated_position: Account<'info, DelegatedPositionV0>,
+
+  pub dnt_mint: Box<Account<'info, Mint>>,
+
+  #[account(mut)]
+  pub delegator_pool: Box<Account<'info, TokenAccount>>,
+  #[account(
+    init_if_needed,
+    payer = position_authority,
+    associated_token::mint = dnt_mint,
+    associated_token::authority = position_authority,
+  )]
+  pub delegator_ata: Box<Account<'info, TokenAccount>>,
+
+  /// CHECK: checked via cpi
+  #[account(
+    mut,
+    seeds = ["account_windowed_breaker".as_bytes(), delegator_pool.key().as_ref()],
+    seeds::program = circuit_breaker_program.key(),
+    bump
+  )]
+  pub delegator_pool_circuit_breaker: AccountInfo<'info>,
+
+  pub vsr_program: Program<'info, VoterStakeRegistry>,
+  pub system_program: Program<'info, System>,
+  pub circuit_breaker_program: Program<'info, CircuitBreaker>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub token_program: Program<'info, Token>,
+}
+
+impl<'info> BatchClaimRewardsV0<'info> {
+  fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, TransferV0<'info>> {
+    let cpi_accounts = TransferV0 {
+      from: self.delegator_pool.to_account_info(),
+      to: self.delegator_ata.to_account_info(),
+      owner: self.sub_dao.to_account_info(),
+      circuit_breaker: self.delegator_pool_circuit_breaker.to_account_info(),
+      token_program: self.token_program.to_account_info(),
+    };
+
+    CpiContext::new(self.circuit_breaker_program.to_account_info(), cpi_accounts)
+  }
+}
+
+/// Claims every epoch after `last_claimed_epoch` that is passed in remaining accounts. The
+/// remaining accounts must be the sub dao epoch infos for a contiguous run of epochs starting at
+/// `last_claimed_epoch + 1`, in order.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, BatchClaimRewardsV0<'info>>) -> Result<()> {
+  let position = &ctx.accounts.position;
+  let registrar = &ctx.accounts.registrar;
+  let voting_mint_config = &registrar.voting_mints[position.voting_mint_config_idx as usize];
+  let sub_dao_key = ctx.accounts.sub_dao.key();
+
+  require!(
+    !ctx.remaining_accounts.is_empty(),
+    ErrorCode::InvalidClaimEpoch
+  );
+
+  let dao = &ctx.accounts.dao;
+  let (claim_epoch, rewards) = claim_epoch_rewards(
+    dao,
+    sub_dao_key,
+    position,
+    voting_mint_config,
+    ctx.accounts.delegated_position.last_claimed_epoch,
+    current_epoch(dao, registrar.clock_unix_timestamp()),
+    ctx.remaining_accounts,
+  )?;
+
+  ctx.accounts.delegated_position.last_claimed_epoch = claim_epoch;
+
+  let amount_left = ctx.accounts.delegator_pool.amount;
+  transfer_v0(
+    ctx.accounts.transfer_ctx().with_signer(&[&[
+      b"sub_dao",
+      ctx.accounts.sub_dao.dnt_mint.as_ref(),
+      &[ctx.accounts.sub_dao.bump_seed],
+    ]]),
+    // Each epoch's share rounds down, so the pool keeps the dust. It can still fall a little
+    // short of the total because vehnt fall rates round down too.
+    TransferArgsV0 {
+      amount: std::cmp::min(rewards, amount_left),
+    },
+  )?;
+  Ok(())
+}
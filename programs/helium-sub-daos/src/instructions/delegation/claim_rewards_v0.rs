@@ -1,4 +1,4 @@
-use crate::{current_epoch, error::ErrorCode, state::*, TESTING};
+use crate::{current_epoch, error::ErrorCode, position_epoch_rewards, state::*, TESTING};
 use anchor_lang::prelude::*;
 use anchor_spl::{
   associated_token::AssociatedToken,
@@ -115,7 +115,7 @@ pub fn handler(ctx: Context<ClaimRewardsV0>, args: ClaimRewardsArgsV0) -> Result
   let delegated_position = &mut ctx.accounts.delegated_position;
 
   // check epoch that's being claimed is over
-  let epoch = current_epoch(registrar.clock_unix_timestamp());
+  let epoch = current_epoch(&ctx.accounts.dao, registrar.clock_unix_timestamp());
   if !TESTING {
     require_gt!(epoch, args.epoch, ErrorCode::EpochNotOver,);
     require_eq!(
@@ -125,9 +125,12 @@ pub fn handler(ctx: Context<ClaimRewardsV0>, args: ClaimRewardsArgsV0) -> Result
     )
   }
 
-  let delegated_vehnt_at_epoch = position.voting_power(
+  // calculate the position's share of that epoch's rewards
+  let (delegated_vehnt_at_epoch, rewards) = position_epoch_rewards(
+    position,
     voting_mint_config,
-    ctx.accounts.sub_dao_epoch_info.start_ts(),
+    &ctx.accounts.dao,
+    &ctx.accounts.sub_dao_epoch_info,
   )?;
 
   msg!("Staked {} veHNT at start of epoch with {} total veHNT delegated to subdao and {} total rewards to subdao", 
@@ -136,15 +139,8 @@ pub fn handler(ctx: Context<ClaimRewardsV0>, args: ClaimRewardsArgsV0) -> Result
     ctx.accounts.sub_dao_epoch_info.delegation_rewards_issued
   );
 
-  // calculate the position's share of that epoch's rewards
-  // rewards = staking_rewards_issued * staked_vehnt_at_epoch / total_vehnt
-  let rewards = delegated_vehnt_at_epoch
-    .checked_mul(ctx.accounts.sub_dao_epoch_info.delegation_rewards_issued)
-    .unwrap()
-    .checked_div(ctx.accounts.sub_dao_epoch_info.vehnt_at_epoch_start)
-    .unwrap();
-
-  delegated_position.last_claimed_epoch = epoch;
+  // Only advance past the epoch that was actually claimed, so later epochs remain claimable
+  delegated_position.last_claimed_epoch = args.epoch;
 
   let amount_left = ctx.accounts.delegator_pool.amount;
   transfer_v0(
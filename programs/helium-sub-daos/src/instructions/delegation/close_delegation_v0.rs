@@ -55,7 +55,7 @@ pub struct CloseDelegationV0<'info> {
     init_if_needed,
     payer = payer,
     space = 60 + 8 + std::mem::size_of::<SubDaoEpochInfoV0>(),
-    seeds = ["sub_dao_epoch_info".as_bytes(), sub_dao.key().as_ref(), &current_epoch(registrar.clock_unix_timestamp()).to_le_bytes()],
+    seeds = ["sub_dao_epoch_info".as_bytes(), sub_dao.key().as_ref(), &current_epoch(&dao, registrar.clock_unix_timestamp()).to_le_bytes()],
     bump,
   )]
   pub sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
@@ -63,7 +63,7 @@ pub struct CloseDelegationV0<'info> {
   // They were used when delegate_v0 was called
   #[account(
     mut,
-    seeds = ["sub_dao_epoch_info".as_bytes(), sub_dao.key().as_ref(), &current_epoch(position.lockup.end_ts).to_le_bytes()],
+    seeds = ["sub_dao_epoch_info".as_bytes(), sub_dao.key().as_ref(), &current_epoch(&dao, position.lockup.end_ts).to_le_bytes()],
     bump = closing_time_sub_dao_epoch_info.bump_seed,
   )]
   pub closing_time_sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
@@ -73,6 +73,7 @@ pub struct CloseDelegationV0<'info> {
       "sub_dao_epoch_info".as_bytes(), 
       sub_dao.key().as_ref(),
       &current_epoch(
+        &dao,
         // Avoid passing an extra account if the end is 0 (no genesis on this position).
         // Pass instead closing time epoch info, txn account deduplication will reduce the overall tx size
         if position.genesis_end < registrar.clock_unix_timestamp() {
@@ -107,6 +108,7 @@ pub fn handler(ctx: Context<CloseDelegationV0>) -> Result<()> {
     end_vehnt_correction,
     ..
   } = caclulate_vhnt_info(
+    &ctx.accounts.dao,
     ctx.accounts.delegated_position.start_ts,
     position,
     voting_mint_config,
@@ -114,15 +116,20 @@ pub fn handler(ctx: Context<CloseDelegationV0>) -> Result<()> {
 
   // don't allow unstake without claiming available rewards
   // unless we're testing, in which case we don't care
-  let curr_epoch = current_epoch(curr_ts);
+  let curr_epoch = current_epoch(&ctx.accounts.dao, curr_ts);
   assert!((ctx.accounts.delegated_position.last_claimed_epoch >= curr_epoch - 1) || TESTING);
 
   let delegated_position = &mut ctx.accounts.delegated_position;
   let sub_dao = &mut ctx.accounts.sub_dao;
 
-  ctx.accounts.sub_dao_epoch_info.epoch = current_epoch(curr_ts);
+  ctx.accounts.sub_dao_epoch_info.epoch = current_epoch(&ctx.accounts.dao, curr_ts);
 
-  update_subdao_vehnt(sub_dao, &mut ctx.accounts.sub_dao_epoch_info, curr_ts)?;
+  update_subdao_vehnt(
+    &ctx.accounts.dao,
+    sub_dao,
+    &mut ctx.accounts.sub_dao_epoch_info,
+    curr_ts,
+  )?;
 
   // Update the ending epochs with this new info
   ctx
@@ -179,7 +186,7 @@ pub fn handler(ctx: Context<CloseDelegationV0>) -> Result<()> {
 
   // Only subtract from the stake if the position ends after the end of this epoch. Otherwise,
   // the position was already purged due to the sub_dao_epoch_info closing info logic.
-  if position.lockup.end_ts >= ctx.accounts.sub_dao_epoch_info.end_ts()
+  if position.lockup.end_ts >= ctx.accounts.sub_dao_epoch_info.end_ts(&ctx.accounts.dao)
     || position.lockup.kind != LockupKind::Cliff
   {
     msg!(
@@ -204,10 +211,10 @@ pub fn handler(ctx: Context<CloseDelegationV0>) -> Result<()> {
       .unwrap();
   }
   // Unless the position was staked before this epoch, remove it.
-  if current_epoch(delegated_position.start_ts) < curr_epoch {
+  if delegated_position.has_epoch_start_share(&ctx.accounts.dao, curr_epoch) {
     let vehnt_at_start = position.voting_power(
       voting_mint_config,
-      ctx.accounts.sub_dao_epoch_info.start_ts(),
+      ctx.accounts.sub_dao_epoch_info.start_ts(&ctx.accounts.dao),
     )?;
     msg!(
       "Removing {} vehnt from this epoch for this subdao, which currently has {} vehnt",
@@ -43,7 +43,7 @@ pub struct DelegateV0<'info> {
     init_if_needed,
     payer = payer,
     space = 60 + 8 + std::mem::size_of::<SubDaoEpochInfoV0>(),
-    seeds = ["sub_dao_epoch_info".as_bytes(), sub_dao.key().as_ref(), &current_epoch(registrar.clock_unix_timestamp()).to_le_bytes()],
+    seeds = ["sub_dao_epoch_info".as_bytes(), sub_dao.key().as_ref(), &current_epoch(&dao, registrar.clock_unix_timestamp()).to_le_bytes()],
     bump,
   )]
   pub sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
@@ -51,7 +51,7 @@ pub struct DelegateV0<'info> {
     init_if_needed,
     payer = payer,
     space = 60 + 8 + std::mem::size_of::<SubDaoEpochInfoV0>(),
-    seeds = ["sub_dao_epoch_info".as_bytes(), sub_dao.key().as_ref(), &current_epoch(position.lockup.end_ts).to_le_bytes()],
+    seeds = ["sub_dao_epoch_info".as_bytes(), sub_dao.key().as_ref(), &current_epoch(&dao, position.lockup.end_ts).to_le_bytes()],
     bump,
   )]
   pub closing_time_sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
@@ -61,6 +61,7 @@ pub struct DelegateV0<'info> {
       "sub_dao_epoch_info".as_bytes(), 
       sub_dao.key().as_ref(),
       &current_epoch(
+        &dao,
         // Avoid passing an extra account if the end is 0 (no genesis on this position).
         // Pass instead closing time epoch info, txn account deduplication will reduce the overall tx size
         if position.genesis_end < registrar.clock_unix_timestamp() {
@@ -94,7 +95,7 @@ pub fn handler(ctx: Context<DelegateV0>) -> Result<()> {
   let registrar = &ctx.accounts.registrar;
   let voting_mint_config = &registrar.voting_mints[position.voting_mint_config_idx as usize];
   let curr_ts = registrar.clock_unix_timestamp();
-  let vehnt_info = caclulate_vhnt_info(curr_ts, position, voting_mint_config)?;
+  let vehnt_info = caclulate_vhnt_info(&ctx.accounts.dao, curr_ts, position, voting_mint_config)?;
   let VehntInfo {
     has_genesis,
     vehnt_at_curr_ts,
@@ -108,14 +109,19 @@ pub fn handler(ctx: Context<DelegateV0>) -> Result<()> {
 
   msg!("Vehnt calculations: {:?}", vehnt_info);
 
-  let curr_epoch = current_epoch(curr_ts);
+  let curr_epoch = current_epoch(&ctx.accounts.dao, curr_ts);
 
   let sub_dao = &mut ctx.accounts.sub_dao;
   let delegated_position = &mut ctx.accounts.delegated_position;
 
   // Update the veHnt at start of epoch
-  ctx.accounts.sub_dao_epoch_info.epoch = current_epoch(curr_ts);
-  update_subdao_vehnt(sub_dao, &mut ctx.accounts.sub_dao_epoch_info, curr_ts)?;
+  ctx.accounts.sub_dao_epoch_info.epoch = current_epoch(&ctx.accounts.dao, curr_ts);
+  update_subdao_vehnt(
+    &ctx.accounts.dao,
+    sub_dao,
+    &mut ctx.accounts.sub_dao_epoch_info,
+    curr_ts,
+  )?;
 
   sub_dao.vehnt_delegated = sub_dao
     .vehnt_delegated
@@ -157,7 +163,8 @@ pub fn handler(ctx: Context<DelegateV0>) -> Result<()> {
     .checked_add(end_vehnt_correction)
     .unwrap();
   ctx.accounts.closing_time_sub_dao_epoch_info.sub_dao = sub_dao.key();
-  ctx.accounts.closing_time_sub_dao_epoch_info.epoch = current_epoch(position.lockup.end_ts);
+  ctx.accounts.closing_time_sub_dao_epoch_info.epoch =
+    current_epoch(&ctx.accounts.dao, position.lockup.end_ts);
   ctx.accounts.closing_time_sub_dao_epoch_info.bump_seed =
     ctx.bumps["closing_time_sub_dao_epoch_info"];
 
@@ -165,7 +172,7 @@ pub fn handler(ctx: Context<DelegateV0>) -> Result<()> {
     // If the end account doesn't exist, init it. Otherwise just set the correcitons
     if ctx.accounts.genesis_end_sub_dao_epoch_info.data_len() == 0 {
       msg!("Genesis end doesn't exist, initting");
-      let genesis_end_epoch = current_epoch(position.genesis_end);
+      let genesis_end_epoch = current_epoch(&ctx.accounts.dao, position.genesis_end);
       // Anchor doesn't natively support dynamic account creation using remaining_accounts
       // and we have to take it on the manual drive
       create_and_serialize_account_signed(
@@ -186,6 +193,7 @@ pub fn handler(ctx: Context<DelegateV0>) -> Result<()> {
           utility_score: None,
           rewards_issued_at: None,
           initialized: false,
+          active_devices: None,
         },
         &[
           "sub_dao_epoch_info".as_bytes(),
@@ -232,6 +240,7 @@ pub fn handler(ctx: Context<DelegateV0>) -> Result<()> {
 
   delegated_position.purged = false;
   delegated_position.start_ts = curr_ts;
+  delegated_position.delegation_ts = curr_ts;
   delegated_position.hnt_amount = position.amount_deposited_native;
   delegated_position.last_claimed_epoch = curr_epoch;
   delegated_position.sub_dao = ctx.accounts.sub_dao.key();
@@ -1,11 +1,17 @@
+pub mod batch_claim_rewards_v0;
 pub mod claim_rewards_v0;
 pub mod close_delegation_v0;
 pub mod delegate_v0;
+pub mod preview_rewards_v0;
+pub mod redelegate_v0;
 pub mod reset_lockup_v0;
 pub mod transfer_v0;
 
+pub use batch_claim_rewards_v0::*;
 pub use claim_rewards_v0::*;
 pub use close_delegation_v0::*;
 pub use delegate_v0::*;
+pub use preview_rewards_v0::*;
+pub use redelegate_v0::*;
 pub use reset_lockup_v0::*;
 pub use transfer_v0::*;
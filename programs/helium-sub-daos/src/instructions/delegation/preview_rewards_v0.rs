@@ -0,0 +1,130 @@
+use crate::{error::ErrorCode, id, position_epoch_rewards, state::*};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use voter_stake_registry::{
+  state::{PositionV0, Registrar},
+  VoterStakeRegistry,
+};
+
+// Keeps the serialized preview under the 1024 byte return data limit
+pub const MAX_PREVIEW_EPOCHS: u64 = 20;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PreviewRewardsArgsV0 {
+  pub start_epoch: u64,
+  pub end_epoch: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+pub struct EpochRewardsV0 {
+  pub epoch: u64,
+  pub delegated_vehnt_at_epoch: u64,
+  pub vehnt_at_epoch_start: u64,
+  pub delegation_rewards_issued: u64,
+  pub rewards: u64,
+  /// Rewards for the epoch have been issued and the position has not claimed it yet
+  pub claimable: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+pub struct RewardsPreviewV0 {
+  pub epochs: Vec<EpochRewardsV0>,
+  pub total_claimable: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: PreviewRewardsArgsV0)]
+pub struct PreviewRewardsV0<'info> {
+  #[account(
+    seeds = [b"position".as_ref(), mint.key().as_ref()],
+    seeds::program = vsr_program.key(),
+    bump = position.bump_seed,
+    has_one = mint,
+    has_one = registrar,
+  )]
+  pub position: Box<Account<'info, PositionV0>>,
+  pub mint: Box<Account<'info, Mint>>,
+  pub registrar: Box<Account<'info, Registrar>>,
+  #[account(
+    has_one = registrar
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  #[account(
+    has_one = dao,
+  )]
+  pub sub_dao: Box<Account<'info, SubDaoV0>>,
+  #[account(
+    has_one = sub_dao,
+    seeds = ["delegated_position".as_bytes(), position.key().as_ref()],
+    bump,
+  )]
+  pub delegated_position: Box<Account<'info, DelegatedPositionV0>>,
+
+  pub vsr_program: Program<'info, VoterStakeRegistry>,
+}
+
+/// Read-only preview of a delegated position's rewards for `start_epoch..=end_epoch`, returned via
+/// return data. The remaining accounts must be the sub dao epoch infos for those epochs, in order.
+/// Amounts are computed the same way as `claim_rewards_v0`, so a claim pays out exactly what the
+/// preview reports.
+pub fn handler<'info>(
+  ctx: Context<'_, '_, '_, 'info, PreviewRewardsV0<'info>>,
+  args: PreviewRewardsArgsV0,
+) -> Result<RewardsPreviewV0> {
+  require_gte!(
+    args.end_epoch,
+    args.start_epoch,
+    ErrorCode::InvalidClaimEpoch
+  );
+  let num_epochs = args.end_epoch - args.start_epoch + 1;
+  require_gte!(MAX_PREVIEW_EPOCHS, num_epochs, ErrorCode::InvalidClaimEpoch);
+  require_eq!(
+    ctx.remaining_accounts.len() as u64,
+    num_epochs,
+    ErrorCode::InvalidClaimEpoch
+  );
+
+  let position = &ctx.accounts.position;
+  let registrar = &ctx.accounts.registrar;
+  let voting_mint_config = &registrar.voting_mints[position.voting_mint_config_idx as usize];
+  let dao = &ctx.accounts.dao;
+  let sub_dao_key = ctx.accounts.sub_dao.key();
+  let last_claimed_epoch = ctx.accounts.delegated_position.last_claimed_epoch;
+
+  let mut preview = RewardsPreviewV0::default();
+  for (epoch, account) in (args.start_epoch..=args.end_epoch).zip(ctx.remaining_accounts) {
+    let sub_dao_epoch_info: Account<SubDaoEpochInfoV0> = Account::try_from(account)?;
+    let expected = Pubkey::create_program_address(
+      &[
+        "sub_dao_epoch_info".as_bytes(),
+        sub_dao_key.as_ref(),
+        &epoch.to_le_bytes(),
+        &[sub_dao_epoch_info.bump_seed],
+      ],
+      &id(),
+    )
+    .map_err(|_| error!(ErrorCode::InvalidClaimEpoch))?;
+    require_keys_eq!(expected, account.key(), ErrorCode::InvalidClaimEpoch);
+
+    let (delegated_vehnt_at_epoch, rewards) =
+      position_epoch_rewards(position, voting_mint_config, dao, &sub_dao_epoch_info)?;
+    let claimable = sub_dao_epoch_info.rewards_issued_at.is_some() && epoch > last_claimed_epoch;
+    if claimable {
+      preview.total_claimable = preview
+        .total_claimable
+        .checked_add(rewards)
+        .ok_or_else(|| error!(ErrorCode::ArithmeticError))?;
+    }
+
+    preview.epochs.push(EpochRewardsV0 {
+      epoch,
+      delegated_vehnt_at_epoch,
+      vehnt_at_epoch_start: sub_dao_epoch_info.vehnt_at_epoch_start,
+      delegation_rewards_issued: sub_dao_epoch_info.delegation_rewards_issued,
+      rewards,
+      claimable,
+    });
+  }
+
+  Ok(preview)
+}
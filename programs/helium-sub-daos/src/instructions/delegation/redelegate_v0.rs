@@ -0,0 +1,497 @@
+use crate::{
+  caclulate_vhnt_info, claim_epoch_rewards, current_epoch, error::ErrorCode, id, state::*,
+  update_subdao_vehnt, VehntInfo, FALL_RATE_FACTOR, TESTING,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+  associated_token::AssociatedToken,
+  token::{Mint, Token, TokenAccount},
+};
+use circuit_breaker::{
+  cpi::{accounts::TransferV0, transfer_v0},
+  CircuitBreaker, TransferArgsV0,
+};
+use spl_governance_tools::account::create_and_serialize_account_signed;
+
+use voter_stake_registry::{
+  state::{LockupKind, PositionV0, Registrar},
+  VoterStakeRegistry,
+};
+
+#[derive(Accounts)]
+pub struct RedelegateV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    seeds = [b"position".as_ref(), mint.key().as_ref()],
+    seeds::program = vsr_program.key(),
+    bump = position.bump_seed,
+    has_one = mint,
+    has_one = registrar,
+  )]
+  pub position: Box<Account<'info, PositionV0>>,
+  pub mint: Box<Account<'info, Mint>>,
+  #[account(
+    token::mint = mint,
+    token::authority = position_authority,
+    constraint = position_token_account.amount > 0
+  )]
+  pub position_token_account: Box<Account<'info, TokenAccount>>,
+  #[account(mut)]
+  pub position_authority: Signer<'info>,
+  pub registrar: Box<Account<'info, Registrar>>,
+  #[account(
+    has_one = registrar
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  #[account(
+    mut,
+    has_one = dao,
+    has_one = delegator_pool,
+    has_one = dnt_mint,
+  )]
+  pub source_sub_dao: Box<Account<'info, SubDaoV0>>,
+  #[account(
+    mut,
+    has_one = dao,
+    constraint = target_sub_dao.key() != source_sub_dao.key()
+  )]
+  pub target_sub_dao: Box<Account<'info, SubDaoV0>>,
+
+  #[account(
+    mut,
+    seeds = ["delegated_position".as_bytes(), position.key().as_ref()],
+    has_one = position,
+    constraint = delegated_position.sub_dao == source_sub_dao.key(),
+    bump = delegated_position.bump_seed,
+  )]
+  pub delegated_position: Box<Account<'info, DelegatedPositionV0>>,
+
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = 60 + 8 + std::mem::size_of::<SubDaoEpochInfoV0>(),
+    seeds = ["sub_dao_epoch_info".as_bytes(), source_sub_dao.key().as_ref(), &current_epoch(&dao, registrar.clock_unix_timestamp()).to_le_bytes()],
+    bump,
+  )]
+  pub source_sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
+  // We know these two accounts are initialized because
+  // They were used when the position was delegated to the source sub dao
+  #[account(
+    mut,
+    seeds = ["sub_dao_epoch_info".as_bytes(), source_sub_dao.key().as_ref(), &current_epoch(&dao, position.lockup.end_ts).to_le_bytes()],
+    bump = source_closing_time_sub_dao_epoch_info.bump_seed,
+  )]
+  pub source_closing_time_sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
+  #[account(
+    mut,
+    seeds = [
+      "sub_dao_epoch_info".as_bytes(),
+      source_sub_dao.key().as_ref(),
+      &current_epoch(
+        &dao,
+        // Avoid passing an extra account if the end is 0 (no genesis on this position).
+        // Pass instead closing time epoch info, txn account deduplication will reduce the overall tx size
+        if position.genesis_end < registrar.clock_unix_timestamp() {
+          position.lockup.end_ts
+        } else {
+          position.genesis_end
+        }
+      ).to_le_bytes()
+    ],
+    bump = source_genesis_end_sub_dao_epoch_info.bump_seed,
+  )]
+  pub source_genesis_end_sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
+
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = 60 + 8 + std::mem::size_of::<SubDaoEpochInfoV0>(),
+    seeds = ["sub_dao_epoch_info".as_bytes(), target_sub_dao.key().as_ref(), &current_epoch(&dao, registrar.clock_unix_timestamp()).to_le_bytes()],
+    bump,
+  )]
+  pub target_sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = 60 + 8 + std::mem::size_of::<SubDaoEpochInfoV0>(),
+    seeds = ["sub_dao_epoch_info".as_bytes(), target_sub_dao.key().as_ref(), &current_epoch(&dao, position.lockup.end_ts).to_le_bytes()],
+    bump,
+  )]
+  pub target_closing_time_sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
+  #[account(
+    mut,
+    seeds = [
+      "sub_dao_epoch_info".as_bytes(),
+      target_sub_dao.key().as_ref(),
+      &current_epoch(
+        &dao,
+        if position.genesis_end < registrar.clock_unix_timestamp() {
+          position.lockup.end_ts
+        } else {
+          position.genesis_end
+        }
+      ).to_le_bytes()
+    ],
+    bump,
+  )]
+  /// CHECK: Verified when needed in the inner instr
+  pub target_genesis_end_sub_dao_epoch_info: UncheckedAccount<'info>,
+
+  // Pays out the rewards still unclaimed in the source sub dao
+  pub dnt_mint: Box<Account<'info, Mint>>,
+  #[account(mut)]
+  pub delegator_pool: Box<Account<'info, TokenAccount>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    associated_token::mint = dnt_mint,
+    associated_token::authority = position_authority,
+  )]
+  pub delegator_ata: Box<Account<'info, TokenAccount>>,
+  /// CHECK: checked via cpi
+  #[account(
+    mut,
+    seeds = ["account_windowed_breaker".as_bytes(), delegator_pool.key().as_ref()],
+    seeds::program = circuit_breaker_program.key(),
+    bump
+  )]
+  pub delegator_pool_circuit_breaker: AccountInfo<'info>,
+
+  pub vsr_program: Program<'info, VoterStakeRegistry>,
+  pub system_program: Program<'info, System>,
+  pub circuit_breaker_program: Program<'info, CircuitBreaker>,
+  pub associated_token_program: Program<'info, AssociatedToken>,
+  pub token_program: Program<'info, Token>,
+}
+
+impl<'info> RedelegateV0<'info> {
+  fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, TransferV0<'info>> {
+    let cpi_accounts = TransferV0 {
+      from: self.delegator_pool.to_account_info(),
+      to: self.delegator_ata.to_account_info(),
+      owner: self.source_sub_dao.to_account_info(),
+      circuit_breaker: self.delegator_pool_circuit_breaker.to_account_info(),
+      token_program: self.token_program.to_account_info(),
+    };
+
+    CpiContext::new(self.circuit_breaker_program.to_account_info(), cpi_accounts)
+  }
+}
+
+/// Moves a delegated position from one sub dao to another. This is equivalent to
+/// `close_delegation_v0` followed by `delegate_v0`, except that the position keeps its claim
+/// cursor and its share of the current epoch follows it to the target sub dao.
+///
+/// Rewards for ended epochs were earned in the source sub dao and can't be claimed once the
+/// position has moved, so they are claimed here first. As in `batch_claim_rewards_v0`, the
+/// remaining accounts are the source sub dao's epoch infos for every ended epoch after
+/// `last_claimed_epoch`, in order.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, RedelegateV0<'info>>) -> Result<()> {
+  let position = &ctx.accounts.position;
+  let registrar = &ctx.accounts.registrar;
+  let voting_mint_config = &registrar.voting_mints[position.voting_mint_config_idx as usize];
+  let curr_ts = registrar.clock_unix_timestamp();
+  let curr_epoch = current_epoch(&ctx.accounts.dao, curr_ts);
+
+  let (claim_epoch, rewards) = claim_epoch_rewards(
+    &ctx.accounts.dao,
+    ctx.accounts.source_sub_dao.key(),
+    position,
+    voting_mint_config,
+    ctx.accounts.delegated_position.last_claimed_epoch,
+    curr_epoch,
+    ctx.remaining_accounts,
+  )?;
+  // Unless we're testing, in which case we don't care
+  require!(
+    claim_epoch >= curr_epoch - 1 || TESTING,
+    ErrorCode::UnclaimedRewards
+  );
+
+  if rewards > 0 {
+    msg!("Claiming {} rewards from the source subdao", rewards);
+    let amount_left = ctx.accounts.delegator_pool.amount;
+    transfer_v0(
+      ctx.accounts.transfer_ctx().with_signer(&[&[
+        b"sub_dao",
+        ctx.accounts.source_sub_dao.dnt_mint.as_ref(),
+        &[ctx.accounts.source_sub_dao.bump_seed],
+      ]]),
+      TransferArgsV0 {
+        amount: std::cmp::min(rewards, amount_left),
+      },
+    )?;
+  }
+
+  ctx.accounts.source_sub_dao_epoch_info.epoch = curr_epoch;
+  update_subdao_vehnt(
+    &ctx.accounts.dao,
+    &mut ctx.accounts.source_sub_dao,
+    &mut ctx.accounts.source_sub_dao_epoch_info,
+    curr_ts,
+  )?;
+  ctx.accounts.target_sub_dao_epoch_info.epoch = curr_epoch;
+  update_subdao_vehnt(
+    &ctx.accounts.dao,
+    &mut ctx.accounts.target_sub_dao,
+    &mut ctx.accounts.target_sub_dao_epoch_info,
+    curr_ts,
+  )?;
+
+  // A cliff position ending this epoch has already been accounted for by the source sub dao's
+  // closing info, so there is nothing left to move.
+  require!(
+    position.lockup.end_ts
+      >= ctx
+        .accounts
+        .source_sub_dao_epoch_info
+        .end_ts(&ctx.accounts.dao)
+      || position.lockup.kind != LockupKind::Cliff,
+    ErrorCode::PositionExpiring
+  );
+
+  // Step 1. Remove the position from the source sub dao, as in close_delegation_v0
+  let VehntInfo {
+    pre_genesis_end_fall_rate,
+    post_genesis_end_fall_rate,
+    genesis_end_fall_rate_correction,
+    genesis_end_vehnt_correction,
+    end_fall_rate_correction,
+    end_vehnt_correction,
+    ..
+  } = caclulate_vhnt_info(
+    &ctx.accounts.dao,
+    ctx.accounts.delegated_position.start_ts,
+    position,
+    voting_mint_config,
+  )?;
+
+  let source_closing = &mut ctx.accounts.source_closing_time_sub_dao_epoch_info;
+  source_closing.fall_rates_from_closing_positions = source_closing
+    .fall_rates_from_closing_positions
+    .checked_sub(end_fall_rate_correction)
+    .unwrap();
+  source_closing.vehnt_in_closing_positions = source_closing
+    .vehnt_in_closing_positions
+    .checked_sub(end_vehnt_correction)
+    .unwrap();
+
+  // Closing time and genesis end can be the same account
+  let mut parsed: Account<SubDaoEpochInfoV0>;
+  let end_and_genesis_same = ctx.accounts.source_genesis_end_sub_dao_epoch_info.key()
+    == ctx.accounts.source_closing_time_sub_dao_epoch_info.key();
+  let source_genesis_end: &mut Account<SubDaoEpochInfoV0> = if end_and_genesis_same {
+    &mut ctx.accounts.source_closing_time_sub_dao_epoch_info
+  } else {
+    parsed = Account::try_from(
+      &ctx
+        .accounts
+        .source_genesis_end_sub_dao_epoch_info
+        .to_account_info(),
+    )?;
+    &mut parsed
+  };
+  source_genesis_end.fall_rates_from_closing_positions = source_genesis_end
+    .fall_rates_from_closing_positions
+    .checked_sub(genesis_end_fall_rate_correction)
+    .unwrap();
+  source_genesis_end.vehnt_in_closing_positions = source_genesis_end
+    .vehnt_in_closing_positions
+    .checked_sub(genesis_end_vehnt_correction)
+    .unwrap();
+
+  if end_and_genesis_same {
+    // Ensure ordering of exit is correct
+    // If we don't do this, genesis end could exit last and overwrite the values
+    source_genesis_end.exit(&id())?;
+    ctx
+      .accounts
+      .source_genesis_end_sub_dao_epoch_info
+      .reload()?;
+  }
+
+  let VehntInfo {
+    has_genesis,
+    vehnt_at_curr_ts,
+    pre_genesis_end_fall_rate: target_pre_genesis_end_fall_rate,
+    post_genesis_end_fall_rate: target_post_genesis_end_fall_rate,
+    genesis_end_fall_rate_correction: target_genesis_end_fall_rate_correction,
+    genesis_end_vehnt_correction: target_genesis_end_vehnt_correction,
+    end_fall_rate_correction: target_end_fall_rate_correction,
+    end_vehnt_correction: target_end_vehnt_correction,
+  } = caclulate_vhnt_info(&ctx.accounts.dao, curr_ts, position, voting_mint_config)?;
+
+  let source_sub_dao = &mut ctx.accounts.source_sub_dao;
+  msg!(
+    "Current vehnt {}, moving {} from the source subdao",
+    source_sub_dao.vehnt_delegated,
+    vehnt_at_curr_ts
+  );
+  source_sub_dao.vehnt_delegated = source_sub_dao.vehnt_delegated.saturating_sub(
+    u128::from(vehnt_at_curr_ts)
+      .checked_mul(FALL_RATE_FACTOR)
+      .unwrap(),
+  );
+  source_sub_dao.vehnt_fall_rate = source_sub_dao
+    .vehnt_fall_rate
+    .checked_sub(if curr_ts >= position.genesis_end {
+      post_genesis_end_fall_rate
+    } else {
+      pre_genesis_end_fall_rate
+    })
+    .unwrap();
+
+  // Step 2. Move the position's share of the current epoch. Unless it was delegated before this
+  // epoch started it never had one. This goes by the original delegation, as `start_ts` is reset
+  // by every redelegation while the share moves along with the position.
+  if ctx
+    .accounts
+    .delegated_position
+    .has_epoch_start_share(&ctx.accounts.dao, curr_epoch)
+  {
+    let vehnt_at_start = position.voting_power(
+      voting_mint_config,
+      ctx
+        .accounts
+        .source_sub_dao_epoch_info
+        .start_ts(&ctx.accounts.dao),
+    )?;
+    msg!(
+      "Moving {} vehnt at the start of this epoch to the target subdao",
+      vehnt_at_start
+    );
+    ctx.accounts.source_sub_dao_epoch_info.vehnt_at_epoch_start = ctx
+      .accounts
+      .source_sub_dao_epoch_info
+      .vehnt_at_epoch_start
+      .checked_sub(vehnt_at_start)
+      .unwrap();
+    ctx.accounts.target_sub_dao_epoch_info.vehnt_at_epoch_start = ctx
+      .accounts
+      .target_sub_dao_epoch_info
+      .vehnt_at_epoch_start
+      .checked_add(vehnt_at_start)
+      .unwrap();
+  }
+
+  // Step 3. Add the position to the target sub dao, as in delegate_v0
+  let target_sub_dao = &mut ctx.accounts.target_sub_dao;
+  target_sub_dao.vehnt_delegated = target_sub_dao
+    .vehnt_delegated
+    .checked_add(
+      u128::from(vehnt_at_curr_ts)
+        .checked_mul(FALL_RATE_FACTOR)
+        .unwrap(),
+    )
+    .unwrap();
+  target_sub_dao.vehnt_fall_rate = target_sub_dao
+    .vehnt_fall_rate
+    .checked_add(if has_genesis {
+      target_pre_genesis_end_fall_rate
+    } else {
+      target_post_genesis_end_fall_rate
+    })
+    .unwrap();
+
+  let target_closing = &mut ctx.accounts.target_closing_time_sub_dao_epoch_info;
+  target_closing.fall_rates_from_closing_positions = target_closing
+    .fall_rates_from_closing_positions
+    .checked_add(target_end_fall_rate_correction)
+    .unwrap();
+  target_closing.vehnt_in_closing_positions = target_closing
+    .vehnt_in_closing_positions
+    .checked_add(target_end_vehnt_correction)
+    .unwrap();
+  target_closing.sub_dao = target_sub_dao.key();
+  target_closing.epoch = current_epoch(&ctx.accounts.dao, position.lockup.end_ts);
+  target_closing.bump_seed = ctx.bumps["target_closing_time_sub_dao_epoch_info"];
+
+  if target_genesis_end_fall_rate_correction > 0 || target_genesis_end_vehnt_correction > 0 {
+    // If the end account doesn't exist, init it. Otherwise just set the corrections
+    if ctx
+      .accounts
+      .target_genesis_end_sub_dao_epoch_info
+      .data_len()
+      == 0
+    {
+      let genesis_end_epoch = current_epoch(&ctx.accounts.dao, position.genesis_end);
+      create_and_serialize_account_signed(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx
+          .accounts
+          .target_genesis_end_sub_dao_epoch_info
+          .to_account_info(),
+        &SubDaoEpochInfoV0 {
+          epoch: genesis_end_epoch,
+          bump_seed: ctx.bumps["target_genesis_end_sub_dao_epoch_info"],
+          sub_dao: target_sub_dao.key(),
+          dc_burned: 0,
+          vehnt_at_epoch_start: 0,
+          vehnt_in_closing_positions: target_genesis_end_vehnt_correction,
+          fall_rates_from_closing_positions: target_genesis_end_fall_rate_correction,
+          delegation_rewards_issued: 0,
+          utility_score: None,
+          rewards_issued_at: None,
+          initialized: false,
+          active_devices: None,
+        },
+        &[
+          "sub_dao_epoch_info".as_bytes(),
+          target_sub_dao.key().as_ref(),
+          &genesis_end_epoch.to_le_bytes(),
+        ],
+        &id(),
+        &ctx.accounts.system_program.to_account_info(),
+        &Rent::get()?,
+        0,
+      )?;
+    } else {
+      // closing can be the same account as genesis end. Make sure to use the proper account
+      let mut parsed: Account<SubDaoEpochInfoV0>;
+      let target_genesis_end: &mut Account<SubDaoEpochInfoV0> =
+        if ctx.accounts.target_genesis_end_sub_dao_epoch_info.key()
+          == ctx.accounts.target_closing_time_sub_dao_epoch_info.key()
+        {
+          &mut ctx.accounts.target_closing_time_sub_dao_epoch_info
+        } else {
+          parsed = Account::try_from(
+            &ctx
+              .accounts
+              .target_genesis_end_sub_dao_epoch_info
+              .to_account_info(),
+          )?;
+          &mut parsed
+        };
+
+      target_genesis_end.fall_rates_from_closing_positions = target_genesis_end
+        .fall_rates_from_closing_positions
+        .checked_add(target_genesis_end_fall_rate_correction)
+        .unwrap();
+      target_genesis_end.vehnt_in_closing_positions = target_genesis_end
+        .vehnt_in_closing_positions
+        .checked_add(target_genesis_end_vehnt_correction)
+        .unwrap();
+
+      target_genesis_end.exit(&id())?;
+    }
+  }
+
+  // The current epoch is claimed from the target sub dao
+  let delegated_position = &mut ctx.accounts.delegated_position;
+  delegated_position.last_claimed_epoch = claim_epoch;
+  delegated_position.sub_dao = ctx.accounts.target_sub_dao.key();
+  if delegated_position.delegation_ts == 0 {
+    delegated_position.delegation_ts = delegated_position.start_ts;
+  }
+  delegated_position.start_ts = curr_ts;
+
+  ctx.accounts.source_sub_dao_epoch_info.sub_dao = ctx.accounts.source_sub_dao.key();
+  ctx.accounts.source_sub_dao_epoch_info.bump_seed = ctx.bumps["source_sub_dao_epoch_info"];
+  ctx.accounts.source_sub_dao_epoch_info.initialized = true;
+  ctx.accounts.target_sub_dao_epoch_info.sub_dao = ctx.accounts.target_sub_dao.key();
+  ctx.accounts.target_sub_dao_epoch_info.bump_seed = ctx.bumps["target_sub_dao_epoch_info"];
+  ctx.accounts.target_sub_dao_epoch_info.initialized = true;
+
+  Ok(())
+}
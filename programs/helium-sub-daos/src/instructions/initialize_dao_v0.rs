@@ -1,4 +1,8 @@
-use crate::{construct_issue_hst_kickoff_ix, current_epoch, state::*, EPOCH_LENGTH};
+use crate::{
+  construct_issue_hst_kickoff_ix, current_epoch, error::ErrorCode, state::*,
+  validate_emission_schedule, validate_epoch_config, DEFAULT_EPOCH_LENGTH,
+  MAX_ENTITY_METADATA_URL_LENGTH,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
 use anchor_spl::token::spl_token::instruction::AuthorityType;
@@ -18,6 +22,10 @@ pub struct InitializeDaoArgsV0 {
   pub hst_emission_schedule: Vec<PercentItem>,
   pub net_emissions_cap: u64,
   pub registrar: Pubkey,
+  pub entity_metadata_url: String,
+  // Defaults to 24h epochs starting at midnight UTC
+  pub epoch_length: Option<i64>,
+  pub epoch_offset: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -28,7 +36,7 @@ pub struct InitializeDaoV0<'info> {
   #[account(
     init,
     payer = payer,
-    space = 60 + 8 + std::mem::size_of::<DaoV0>() + (std::mem::size_of::<EmissionScheduleItem>() * args.emission_schedule.len()),
+    space = 60 + 8 + std::mem::size_of::<DaoV0>() + (std::mem::size_of::<EmissionScheduleItem>() * args.emission_schedule.len()) + (std::mem::size_of::<PercentItem>() * args.hst_emission_schedule.len()) + args.entity_metadata_url.len(),
     seeds = ["dao".as_bytes(), hnt_mint.key().as_ref()],
     bump,
   )]
@@ -58,14 +66,26 @@ pub struct InitializeDaoV0<'info> {
   #[account(
     mut,
     seeds = [b"thread", dao.key().as_ref(), b"issue_hst"],
-    seeds::program = clockwork.key(),
+    seeds::program = ThreadProgram::id(),
     bump
   )]
-  pub thread: AccountInfo<'info>,
-  pub clockwork: Program<'info, ThreadProgram>,
+  pub thread: Option<AccountInfo<'info>>,
+  /// Without clockwork, `issue_hst_pool_v0` is cranked by anyone for the crank bounty
+  pub clockwork: Option<Program<'info, ThreadProgram>>,
 }
 
 pub fn handler(ctx: Context<InitializeDaoV0>, args: InitializeDaoArgsV0) -> Result<()> {
+  require!(
+    args.entity_metadata_url.len() <= MAX_ENTITY_METADATA_URL_LENGTH,
+    ErrorCode::InvalidStringLength
+  );
+  let epoch_length = args.epoch_length.unwrap_or(DEFAULT_EPOCH_LENGTH);
+  let epoch_offset = args.epoch_offset.unwrap_or(0);
+  validate_epoch_config(epoch_length, epoch_offset)?;
+  let curr_ts = Clock::get()?.unix_timestamp;
+  validate_emission_schedule(&args.emission_schedule, &[], curr_ts)?;
+  validate_emission_schedule(&args.hst_emission_schedule, &[], curr_ts)?;
+
   initialize_mint_windowed_breaker_v0(
     CpiContext::new(
       ctx.accounts.circuit_breaker_program.to_account_info(),
@@ -83,13 +103,9 @@ pub fn handler(ctx: Context<InitializeDaoV0>, args: InitializeDaoArgsV0) -> Resu
       config: WindowedCircuitBreakerConfigV0 {
         // No more than 5 epochs worth can be distributed. We should be distributing once per epoch so this
         // should never get triggered.
-        window_size_seconds: u64::try_from(EPOCH_LENGTH).unwrap(),
+        window_size_seconds: u64::try_from(epoch_length).unwrap(),
         threshold_type: ThresholdType::Absolute,
-        threshold: 5
-          * args
-            .emission_schedule
-            .get_emissions_at(Clock::get()?.unix_timestamp)
-            .unwrap(),
+        threshold: 5 * args.emission_schedule.get_emissions_at(curr_ts).unwrap(),
       },
       mint_authority: ctx.accounts.dao.key(),
     },
@@ -117,10 +133,20 @@ pub fn handler(ctx: Context<InitializeDaoV0>, args: InitializeDaoArgsV0) -> Resu
     bump_seed: ctx.bumps["dao"],
     net_emissions_cap: args.net_emissions_cap,
     hst_pool: ctx.accounts.hst_pool.key(),
+    entity_metadata_url: args.entity_metadata_url,
+    epoch_length,
+    epoch_offset,
+    utility_score_config: None,
+    crank_bounty_lamports: 0,
   });
 
-  let curr_ts = Clock::get()?.unix_timestamp;
-  let epoch = current_epoch(curr_ts);
+  let (clockwork, thread) = match (&ctx.accounts.clockwork, &ctx.accounts.thread) {
+    (Some(clockwork), Some(thread)) => (clockwork, thread),
+    (None, _) => return Ok(()),
+    _ => return Err(error!(ErrorCode::MissingClockworkThread)),
+  };
+
+  let epoch = current_epoch(&ctx.accounts.dao, curr_ts);
 
   let dao_key = ctx.accounts.dao.key();
   let dao_ei_seeds: &[&[u8]] = &[
@@ -146,11 +172,11 @@ pub fn handler(ctx: Context<InitializeDaoV0>, args: InitializeDaoArgsV0) -> Resu
   ]];
   thread_create(
     CpiContext::new_with_signer(
-      ctx.accounts.clockwork.to_account_info(),
+      clockwork.to_account_info(),
       clockwork_sdk::cpi::ThreadCreate {
         authority: ctx.accounts.dao.to_account_info(),
         payer: ctx.accounts.payer.to_account_info(),
-        thread: ctx.accounts.thread.to_account_info(),
+        thread: thread.to_account_info(),
         system_program: ctx.accounts.system_program.to_account_info(),
       },
       signer_seeds,
@@ -1,5 +1,8 @@
 use crate::{circuit_breaker::*, construct_issue_rewards_kickoff_ix, current_epoch, next_epoch_ts};
-use crate::{construct_calculate_kickoff_ix, state::*, EPOCH_LENGTH};
+use crate::{
+  construct_calculate_kickoff_ix, error::ErrorCode, state::*, validate_emission_schedule,
+  validate_schedule, DEFAULT_EPOCH_LENGTH, WEEK_EPOCH_LENGTH,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
 use anchor_spl::associated_token::AssociatedToken;
@@ -54,6 +57,8 @@ pub struct InitializeSubDaoArgsV0 {
   pub onboarding_dc_fee: u64,
   /// Authority to burn delegated data credits
   pub dc_burn_authority: Pubkey,
+  /// Empty uses the default delegator rewards percent, see `delegator_rewards_percent_at`
+  pub delegator_rewards_percent_schedule: Vec<PercentItem>,
 }
 
 #[derive(Accounts)]
@@ -71,7 +76,7 @@ pub struct InitializeSubDaoV0<'info> {
   #[account(
     init,
     payer = payer,
-    space = 60 + 8 + std::mem::size_of::<SubDaoV0>() + (std::mem::size_of::<EmissionScheduleItem>() * args.emission_schedule.len()),
+    space = 60 + 8 + std::mem::size_of::<SubDaoV0>() + (std::mem::size_of::<EmissionScheduleItem>() * args.emission_schedule.len()) + (std::mem::size_of::<PercentItem>() * args.delegator_rewards_percent_schedule.len()),
     seeds = ["sub_dao".as_bytes(), dnt_mint.key().as_ref()],
     bump,
   )]
@@ -142,28 +147,56 @@ pub struct InitializeSubDaoV0<'info> {
   #[account(
     mut,
     seeds = [b"thread", sub_dao.key().as_ref(), b"calculate"],
-    seeds::program = clockwork.key(),
+    seeds::program = ThreadProgram::id(),
     bump
   )]
-  pub calculate_thread: AccountInfo<'info>,
+  pub calculate_thread: Option<AccountInfo<'info>>,
   /// CHECK: handled by thread_create
   #[account(
     mut,
     seeds = [b"thread", sub_dao.key().as_ref(), b"issue"],
-    seeds::program = clockwork.key(),
+    seeds::program = ThreadProgram::id(),
     bump
   )]
-  pub issue_thread: AccountInfo<'info>,
-  pub clockwork: Program<'info, ThreadProgram>,
+  pub issue_thread: Option<AccountInfo<'info>>,
+  /// Without clockwork, `calculate_utility_score_v0` and `issue_rewards_v0` are cranked by anyone
+  /// for the crank bounty
+  pub clockwork: Option<Program<'info, ThreadProgram>>,
 }
 
-// returns a cron that starts at <offset> past the end of the current epoch and triggers at the same time daily.
-pub fn create_end_epoch_cron(curr_ts: i64, offset: u64) -> String {
-  let next_epoch = next_epoch_ts(curr_ts) + offset;
+// returns a cron that starts at <offset> past the end of the current epoch and triggers at the same point of every
+// following epoch. See `validate_epoch_config` for the epoch lengths this can express.
+pub fn create_end_epoch_cron(dao: &DaoV0, curr_ts: i64, offset: u64) -> String {
+  let next_epoch = next_epoch_ts(dao, curr_ts) + offset;
   let dt = OffsetDateTime::from_unix_timestamp(next_epoch.try_into().unwrap())
     .ok()
     .unwrap();
-  format!("0 {:?} {:?} * * * *", dt.minute(), dt.hour())
+  let epoch_length = dao.epoch_length();
+  if epoch_length == DEFAULT_EPOCH_LENGTH {
+    format!("0 {:?} {:?} * * * *", dt.minute(), dt.hour())
+  } else if epoch_length == WEEK_EPOCH_LENGTH {
+    format!(
+      "0 {:?} {:?} * * {:?} *",
+      dt.minute(),
+      dt.hour(),
+      dt.weekday().number_from_sunday()
+    )
+  } else if epoch_length % 3600 == 0 {
+    let hours = epoch_length / 3600;
+    format!(
+      "0 {:?} {:?}/{:?} * * * *",
+      dt.minute(),
+      i64::from(dt.hour()) % hours,
+      hours
+    )
+  } else {
+    let minutes = epoch_length / 60;
+    format!(
+      "0 {:?}/{:?} * * * * *",
+      i64::from(dt.minute()) % minutes,
+      minutes
+    )
+  }
 }
 
 impl<'info> InitializeSubDaoV0<'info> {
@@ -198,6 +231,8 @@ impl<'info> InitializeSubDaoV0<'info> {
 
 pub fn handler(ctx: Context<InitializeSubDaoV0>, args: InitializeSubDaoArgsV0) -> Result<()> {
   let curr_ts = Clock::get()?.unix_timestamp;
+  validate_emission_schedule(&args.emission_schedule, &[], curr_ts)?;
+  validate_schedule(&args.delegator_rewards_percent_schedule, &[], curr_ts)?;
   initialize_mint_windowed_breaker_v0(
     ctx.accounts.initialize_dnt_mint_breaker_ctx(),
     InitializeMintWindowedBreakerArgsV0 {
@@ -205,7 +240,7 @@ pub fn handler(ctx: Context<InitializeSubDaoV0>, args: InitializeSubDaoArgsV0) -
       config: CBWindowedCircuitBreakerConfigV0 {
         // No more than 5 epochs worth can be distributed. We should be distributing once per epoch so this
         // should never get triggered.
-        window_size_seconds: u64::try_from(EPOCH_LENGTH).unwrap(),
+        window_size_seconds: u64::try_from(ctx.accounts.dao.epoch_length()).unwrap(),
         threshold_type: CBThresholdType::Absolute,
         threshold: 5 * args.emission_schedule.get_emissions_at(curr_ts).unwrap(),
       },
@@ -226,7 +261,7 @@ pub fn handler(ctx: Context<InitializeSubDaoV0>, args: InitializeSubDaoArgsV0) -
     InitializeAccountWindowedBreakerArgsV0 {
       authority: args.authority,
       config: CBWindowedCircuitBreakerConfigV0 {
-        window_size_seconds: u64::try_from(EPOCH_LENGTH).unwrap(),
+        window_size_seconds: u64::try_from(ctx.accounts.dao.epoch_length()).unwrap(),
         threshold_type: CBThresholdType::Absolute,
         threshold: 5
           * args
@@ -290,6 +325,8 @@ pub fn handler(ctx: Context<InitializeSubDaoV0>, args: InitializeSubDaoArgsV0) -
     vehnt_last_calculated_ts: Clock::get()?.unix_timestamp,
     vehnt_fall_rate: 0,
     delegator_pool: ctx.accounts.delegator_pool.key(),
+    delegator_rewards_percent_schedule: args.delegator_rewards_percent_schedule,
+    active_device_authority: Pubkey::default(),
   });
 
   resize_to_fit(
@@ -297,6 +334,18 @@ pub fn handler(ctx: Context<InitializeSubDaoV0>, args: InitializeSubDaoArgsV0) -
     &ctx.accounts.system_program.to_account_info(),
     &ctx.accounts.sub_dao,
   )?;
+
+  let (clockwork, calculate_thread, issue_thread) = match (
+    &ctx.accounts.clockwork,
+    &ctx.accounts.calculate_thread,
+    &ctx.accounts.issue_thread,
+  ) {
+    (Some(clockwork), Some(calculate_thread), Some(issue_thread)) => {
+      (clockwork, calculate_thread, issue_thread)
+    }
+    (None, _, _) => return Ok(()),
+    _ => return Err(error!(ErrorCode::MissingClockworkThread)),
+  };
   let calculate_kickoff_ix = construct_calculate_kickoff_ix(
     ctx.accounts.dao.key(),
     ctx.accounts.sub_dao.key(),
@@ -306,15 +355,15 @@ pub fn handler(ctx: Context<InitializeSubDaoV0>, args: InitializeSubDaoArgsV0) -
     ctx.accounts.token_program.key(),
     ctx.accounts.circuit_breaker_program.key(),
   );
-  let cron = create_end_epoch_cron(curr_ts, 60 * 5);
+  let cron = create_end_epoch_cron(&ctx.accounts.dao, curr_ts, 60 * 5);
   // initialize calculate thread
   thread_create(
     CpiContext::new_with_signer(
-      ctx.accounts.clockwork.to_account_info(),
+      clockwork.to_account_info(),
       clockwork_sdk::cpi::ThreadCreate {
         authority: ctx.accounts.sub_dao.to_account_info(),
         payer: ctx.accounts.payer.to_account_info(),
-        thread: ctx.accounts.calculate_thread.to_account_info(),
+        thread: calculate_thread.to_account_info(),
         system_program: ctx.accounts.system_program.to_account_info(),
       },
       signer_seeds,
@@ -328,7 +377,7 @@ pub fn handler(ctx: Context<InitializeSubDaoV0>, args: InitializeSubDaoArgsV0) -
     },
   )?;
 
-  let epoch = current_epoch(curr_ts);
+  let epoch = current_epoch(&ctx.accounts.dao, curr_ts);
   let dao_epoch_info = Pubkey::find_program_address(
     &[
       "dao_epoch_info".as_bytes(),
@@ -351,11 +400,11 @@ pub fn handler(ctx: Context<InitializeSubDaoV0>, args: InitializeSubDaoArgsV0) -
 
   thread_create(
     CpiContext::new_with_signer(
-      ctx.accounts.clockwork.to_account_info(),
+      clockwork.to_account_info(),
       clockwork_sdk::cpi::ThreadCreate {
         authority: ctx.accounts.sub_dao.to_account_info(),
         payer: ctx.accounts.payer.to_account_info(),
-        thread: ctx.accounts.issue_thread.to_account_info(),
+        thread: issue_thread.to_account_info(),
         system_program: ctx.accounts.system_program.to_account_info(),
       },
       signer_seeds,
@@ -1,4 +1,4 @@
-use crate::{current_epoch, error::ErrorCode, state::*, TESTING};
+use crate::{current_epoch, error::ErrorCode, pay_crank_bounty, state::*, TESTING};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use circuit_breaker::{
@@ -15,6 +15,8 @@ pub struct IssueHstPoolArgsV0 {
 #[derive(Accounts)]
 #[instruction(args: IssueHstPoolArgsV0)]
 pub struct IssueHstPoolV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
   #[account(
     mut,
     has_one = hnt_mint,
@@ -41,6 +43,12 @@ pub struct IssueHstPoolV0<'info> {
   pub hnt_mint: Box<Account<'info, Mint>>,
   #[account(mut)]
   pub hst_pool: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    seeds = ["crank_vault".as_bytes(), dao.key().as_ref()],
+    bump,
+  )]
+  pub crank_vault: SystemAccount<'info>,
   pub system_program: Program<'info, System>,
   pub token_program: Program<'info, Token>,
   pub circuit_breaker_program: Program<'info, CircuitBreaker>,
@@ -62,7 +70,7 @@ impl<'info> IssueHstPoolV0<'info> {
 
 pub fn handler(ctx: Context<IssueHstPoolV0>, args: IssueHstPoolArgsV0) -> Result<ThreadResponse> {
   let curr_ts = Clock::get()?.unix_timestamp;
-  let epoch = current_epoch(curr_ts);
+  let epoch = current_epoch(&ctx.accounts.dao, curr_ts);
 
   if !TESTING && args.epoch >= epoch {
     return Err(error!(ErrorCode::EpochNotOver));
@@ -93,8 +101,16 @@ pub fn handler(ctx: Context<IssueHstPoolV0>, args: IssueHstPoolArgsV0) -> Result
 
   ctx.accounts.dao_epoch_info.done_issuing_hst_pool = true;
 
+  pay_crank_bounty(
+    &ctx.accounts.dao,
+    &ctx.accounts.crank_vault.to_account_info(),
+    ctx.bumps["crank_vault"],
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+  )?;
+
   // update thread to point at next epoch
-  let next_epoch = current_epoch(curr_ts);
+  let next_epoch = current_epoch(&ctx.accounts.dao, curr_ts);
 
   let dao_key = ctx.accounts.dao.key();
   let dao_ei_seeds: &[&[u8]] = &[
@@ -1,4 +1,4 @@
-use crate::{current_epoch, error::ErrorCode, state::*, OrArithError, TESTING};
+use crate::{current_epoch, error::ErrorCode, pay_crank_bounty, state::*, OrArithError, TESTING};
 use circuit_breaker::{
   cpi::{accounts::MintV0, mint_v0},
   CircuitBreaker, MintArgsV0, MintWindowedCircuitBreakerV0,
@@ -17,6 +17,8 @@ pub struct IssueRewardsArgsV0 {
 #[derive(Accounts)]
 #[instruction(args: IssueRewardsArgsV0)]
 pub struct IssueRewardsV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
   #[account(
     has_one = hnt_mint,
   )]
@@ -71,6 +73,12 @@ pub struct IssueRewardsV0<'info> {
   pub rewards_escrow: Box<Account<'info, TokenAccount>>,
   #[account(mut)]
   pub delegator_pool: Box<Account<'info, TokenAccount>>,
+  #[account(
+    mut,
+    seeds = ["crank_vault".as_bytes(), dao.key().as_ref()],
+    bump,
+  )]
+  pub crank_vault: SystemAccount<'info>,
   pub system_program: Program<'info, System>,
   pub token_program: Program<'info, Token>,
   pub circuit_breaker_program: Program<'info, CircuitBreaker>,
@@ -122,7 +130,7 @@ impl<'info> IssueRewardsV0<'info> {
 
 pub fn handler(ctx: Context<IssueRewardsV0>, args: IssueRewardsArgsV0) -> Result<ThreadResponse> {
   let curr_ts = Clock::get()?.unix_timestamp;
-  let epoch = current_epoch(curr_ts);
+  let epoch = current_epoch(&ctx.accounts.dao, curr_ts);
 
   if !TESTING && args.epoch >= epoch {
     return Err(error!(ErrorCode::EpochNotOver));
@@ -169,6 +177,29 @@ pub fn handler(ctx: Context<IssueRewardsV0>, args: IssueRewardsArgsV0) -> Result
     .unwrap();
 
   let delegators_present = ctx.accounts.sub_dao_epoch_info.vehnt_at_epoch_start > 0;
+  // Looked up at the issued epoch so issuing late can't change the rate
+  let delegator_percent = ctx
+    .accounts
+    .sub_dao
+    .delegator_rewards_percent_at(ctx.accounts.sub_dao_epoch_info.start_ts(&ctx.accounts.dao));
+  // Remainder after the delegator share is sent to rewards escrow
+  let escrow_amount = 100_u64
+    .checked_sub(delegator_percent.into())
+    .unwrap()
+    .checked_mul(total_emissions)
+    .unwrap()
+    .checked_div(100)
+    .unwrap();
+  let delegation_rewards_amount = if delegators_present {
+    total_emissions
+      .checked_mul(delegator_percent.into())
+      .unwrap()
+      .checked_div(100)
+      .unwrap()
+  } else {
+    0
+  };
+
   mint_v0(
     ctx.accounts.mint_dnt_emissions_ctx().with_signer(&[&[
       b"sub_dao",
@@ -176,24 +207,10 @@ pub fn handler(ctx: Context<IssueRewardsV0>, args: IssueRewardsArgsV0) -> Result
       &[ctx.accounts.sub_dao.bump_seed],
     ]]),
     MintArgsV0 {
-      amount: total_emissions
-        .checked_mul(94)
-        .unwrap()
-        .checked_div(100)
-        .unwrap(), // 94% of emissions are sent to treasury
+      amount: escrow_amount,
     },
   )?;
 
-  let delegation_rewards_amount = if delegators_present {
-    total_emissions
-      .checked_mul(6)
-      .unwrap()
-      .checked_div(100)
-      .unwrap()
-  } else {
-    0
-  };
-
   mint_v0(
     ctx.accounts.mint_delegation_rewards_ctx().with_signer(&[&[
       b"sub_dao",
@@ -201,7 +218,7 @@ pub fn handler(ctx: Context<IssueRewardsV0>, args: IssueRewardsArgsV0) -> Result
       &[ctx.accounts.sub_dao.bump_seed],
     ]]),
     MintArgsV0 {
-      amount: delegation_rewards_amount, // 6% of emissions are sent to delegation pool
+      amount: delegation_rewards_amount,
     },
   )?;
 
@@ -222,8 +239,16 @@ pub fn handler(ctx: Context<IssueRewardsV0>, args: IssueRewardsArgsV0) -> Result
   ctx.accounts.dao_epoch_info.done_issuing_rewards =
     ctx.accounts.dao.num_sub_daos == ctx.accounts.dao_epoch_info.num_rewards_issued;
 
+  pay_crank_bounty(
+    &ctx.accounts.dao,
+    &ctx.accounts.crank_vault.to_account_info(),
+    ctx.bumps["crank_vault"],
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+  )?;
+
   // update thread to point at next epoch
-  let next_epoch = current_epoch(curr_ts);
+  let next_epoch = current_epoch(&ctx.accounts.dao, curr_ts);
   let dao_epoch_info = Pubkey::find_program_address(
     &[
       "dao_epoch_info".as_bytes(),
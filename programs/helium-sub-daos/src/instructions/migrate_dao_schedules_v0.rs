@@ -0,0 +1,40 @@
+use crate::{normalize_schedule, state::*, validate_emission_schedule};
+use anchor_lang::prelude::*;
+use shared_utils::resize_to_fit;
+
+/// Permissionlessly checks the schedules of a dao created before schedules were validated,
+/// storing them in the order lookups expect and reallocating the dao to fit. Schedules that
+/// can't be validated this way have to be replaced by the authority with `update_dao_v0`.
+#[derive(Accounts)]
+pub struct MigrateDaoSchedulesV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    mut,
+    seeds = ["dao".as_bytes(), dao.hnt_mint.key().as_ref()],
+    bump = dao.bump_seed,
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateDaoSchedulesV0>) -> Result<()> {
+  let curr_ts = Clock::get()?.unix_timestamp;
+  let dao = &mut ctx.accounts.dao;
+
+  let emission_schedule = normalize_schedule(&dao.emission_schedule);
+  validate_emission_schedule(&emission_schedule, &dao.emission_schedule, curr_ts)?;
+  dao.emission_schedule = emission_schedule;
+
+  let hst_emission_schedule = normalize_schedule(&dao.hst_emission_schedule);
+  validate_emission_schedule(&hst_emission_schedule, &dao.hst_emission_schedule, curr_ts)?;
+  dao.hst_emission_schedule = hst_emission_schedule;
+
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.dao,
+  )?;
+
+  Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::{normalize_schedule, state::*, validate_emission_schedule, validate_schedule};
+use anchor_lang::prelude::*;
+use shared_utils::resize_to_fit;
+
+/// Permissionlessly checks the schedules of a subdao created before schedules were validated,
+/// storing them in the order lookups expect and reallocating the subdao to fit. Schedules that
+/// can't be validated this way have to be replaced by the authority with `update_sub_dao_v0`.
+#[derive(Accounts)]
+pub struct MigrateSubDaoSchedulesV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  #[account(
+    mut,
+    seeds = ["sub_dao".as_bytes(), sub_dao.dnt_mint.key().as_ref()],
+    bump = sub_dao.bump_seed,
+  )]
+  pub sub_dao: Box<Account<'info, SubDaoV0>>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateSubDaoSchedulesV0>) -> Result<()> {
+  let curr_ts = Clock::get()?.unix_timestamp;
+  let sub_dao = &mut ctx.accounts.sub_dao;
+
+  let emission_schedule = normalize_schedule(&sub_dao.emission_schedule);
+  validate_emission_schedule(&emission_schedule, &sub_dao.emission_schedule, curr_ts)?;
+  sub_dao.emission_schedule = emission_schedule;
+
+  let delegator_rewards_percent_schedule =
+    normalize_schedule(&sub_dao.delegator_rewards_percent_schedule);
+  validate_schedule(
+    &delegator_rewards_percent_schedule,
+    &sub_dao.delegator_rewards_percent_schedule,
+    curr_ts,
+  )?;
+  sub_dao.delegator_rewards_percent_schedule = delegator_rewards_percent_schedule;
+
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.sub_dao,
+  )?;
+
+  Ok(())
+}
@@ -1,21 +1,29 @@
 pub mod calculate_utility_score_v0;
+pub mod catch_up_vehnt_v0;
 pub mod clockwork;
 pub mod delegation;
 pub mod initialize_dao_v0;
 pub mod initialize_sub_dao_v0;
 pub mod issue_hst_pool_v0;
 pub mod issue_rewards_v0;
+pub mod migrate_dao_schedules_v0;
+pub mod migrate_sub_dao_schedules_v0;
+pub mod track_active_devices_v0;
 pub mod track_dc_burn_v0;
 pub mod update_dao_v0;
 pub mod update_sub_dao_v0;
 
 pub use calculate_utility_score_v0::*;
+pub use catch_up_vehnt_v0::*;
 pub use clockwork::*;
 pub use delegation::*;
 pub use initialize_dao_v0::*;
 pub use initialize_sub_dao_v0::*;
 pub use issue_hst_pool_v0::*;
 pub use issue_rewards_v0::*;
+pub use migrate_dao_schedules_v0::*;
+pub use migrate_sub_dao_schedules_v0::*;
+pub use track_active_devices_v0::*;
 pub use track_dc_burn_v0::*;
 pub use update_dao_v0::*;
 pub use update_sub_dao_v0::*;
@@ -0,0 +1,55 @@
+use crate::{current_epoch, error::ErrorCode, state::*};
+use anchor_lang::prelude::*;
+use voter_stake_registry::state::Registrar;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TrackActiveDevicesArgsV0 {
+  pub epoch: u64,
+  pub active_devices: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: TrackActiveDevicesArgsV0)]
+pub struct TrackActiveDevicesV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
+  pub active_device_authority: Signer<'info>,
+  pub registrar: Box<Account<'info, Registrar>>,
+  #[account(
+    has_one = registrar,
+  )]
+  pub dao: Box<Account<'info, DaoV0>>,
+  #[account(
+    has_one = dao,
+    has_one = active_device_authority,
+  )]
+  pub sub_dao: Box<Account<'info, SubDaoV0>>,
+  #[account(
+    init_if_needed,
+    payer = payer,
+    space = 60 + 8 + std::mem::size_of::<SubDaoEpochInfoV0>(),
+    seeds = ["sub_dao_epoch_info".as_bytes(), sub_dao.key().as_ref(), &args.epoch.to_le_bytes()],
+    bump,
+    constraint = sub_dao_epoch_info.utility_score.is_none() @ ErrorCode::UtilityScoreAlreadyCalculated,
+  )]
+  pub sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
+  pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<TrackActiveDevicesV0>, args: TrackActiveDevicesArgsV0) -> Result<()> {
+  // Only ended epochs, on the same clock as `calculate_utility_score_v0`
+  let curr_ts = ctx.accounts.registrar.clock_unix_timestamp();
+  require_gt!(
+    current_epoch(&ctx.accounts.dao, curr_ts),
+    args.epoch,
+    ErrorCode::InvalidActiveDeviceEpoch
+  );
+
+  // Leave `initialized` alone so that the next vehnt update still records vehnt_at_epoch_start
+  ctx.accounts.sub_dao_epoch_info.epoch = args.epoch;
+  ctx.accounts.sub_dao_epoch_info.sub_dao = ctx.accounts.sub_dao.key();
+  ctx.accounts.sub_dao_epoch_info.bump_seed = ctx.bumps["sub_dao_epoch_info"];
+  ctx.accounts.sub_dao_epoch_info.active_devices = Some(args.active_devices);
+
+  Ok(())
+}
@@ -20,7 +20,7 @@ pub struct TrackDcBurnV0<'info> {
     init_if_needed,
     payer = account_payer,
     space = 60 + 8 + std::mem::size_of::<SubDaoEpochInfoV0>(),
-    seeds = ["sub_dao_epoch_info".as_bytes(), sub_dao.key().as_ref(), &current_epoch(registrar.clock_unix_timestamp()).to_le_bytes()],
+    seeds = ["sub_dao_epoch_info".as_bytes(), sub_dao.key().as_ref(), &current_epoch(&dao, registrar.clock_unix_timestamp()).to_le_bytes()],
     bump,
   )]
   pub sub_dao_epoch_info: Box<Account<'info, SubDaoEpochInfoV0>>,
@@ -49,8 +49,9 @@ pub struct TrackDcBurnV0<'info> {
 
 pub fn handler(ctx: Context<TrackDcBurnV0>, args: TrackDcBurnArgsV0) -> Result<()> {
   let curr_ts = ctx.accounts.registrar.clock_unix_timestamp();
-  ctx.accounts.sub_dao_epoch_info.epoch = current_epoch(curr_ts);
+  ctx.accounts.sub_dao_epoch_info.epoch = current_epoch(&ctx.accounts.dao, curr_ts);
   if let Err(e) = update_subdao_vehnt(
+    &ctx.accounts.dao,
     &mut ctx.accounts.sub_dao,
     &mut ctx.accounts.sub_dao_epoch_info,
     curr_ts,
@@ -1,16 +1,25 @@
-use crate::state::*;
+use crate::{
+  error::ErrorCode, state::*, validate_emission_schedule, validate_utility_score_config,
+  MAX_ENTITY_METADATA_URL_LENGTH,
+};
 use anchor_lang::prelude::*;
+use shared_utils::resize_to_fit;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct UpdateDaoArgsV0 {
   pub authority: Option<Pubkey>,
   pub emission_schedule: Option<Vec<EmissionScheduleItem>>,
   pub hst_emission_schedule: Option<Vec<PercentItem>>,
+  pub entity_metadata_url: Option<String>,
+  pub utility_score_config: Option<UtilityScoreConfig>,
+  pub crank_bounty_lamports: Option<u64>,
 }
 
 #[derive(Accounts)]
 #[instruction(args: UpdateDaoArgsV0)]
 pub struct UpdateDaoV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
   #[account(
     mut,
     seeds = ["dao".as_bytes(), dao.hnt_mint.key().as_ref()],
@@ -19,6 +28,7 @@ pub struct UpdateDaoV0<'info> {
   )]
   pub dao: Box<Account<'info, DaoV0>>,
   pub authority: Signer<'info>,
+  pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<UpdateDaoV0>, args: UpdateDaoArgsV0) -> Result<()> {
@@ -26,13 +36,47 @@ pub fn handler(ctx: Context<UpdateDaoV0>, args: UpdateDaoArgsV0) -> Result<()> {
     ctx.accounts.dao.authority = new_authority;
   }
 
+  let curr_ts = Clock::get()?.unix_timestamp;
   if let Some(emission_schedule) = args.emission_schedule {
+    validate_emission_schedule(
+      &emission_schedule,
+      &ctx.accounts.dao.emission_schedule,
+      curr_ts,
+    )?;
     ctx.accounts.dao.emission_schedule = emission_schedule;
   }
 
   if let Some(hst_emission_schedule) = args.hst_emission_schedule {
+    validate_emission_schedule(
+      &hst_emission_schedule,
+      &ctx.accounts.dao.hst_emission_schedule,
+      curr_ts,
+    )?;
     ctx.accounts.dao.hst_emission_schedule = hst_emission_schedule;
   }
 
+  if let Some(entity_metadata_url) = args.entity_metadata_url {
+    require!(
+      entity_metadata_url.len() <= MAX_ENTITY_METADATA_URL_LENGTH,
+      ErrorCode::InvalidStringLength
+    );
+    ctx.accounts.dao.entity_metadata_url = entity_metadata_url;
+  }
+
+  if let Some(utility_score_config) = args.utility_score_config {
+    validate_utility_score_config(&utility_score_config)?;
+    ctx.accounts.dao.utility_score_config = Some(utility_score_config);
+  }
+
+  if let Some(crank_bounty_lamports) = args.crank_bounty_lamports {
+    ctx.accounts.dao.crank_bounty_lamports = crank_bounty_lamports;
+  }
+
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.dao,
+  )?;
+
   Ok(())
 }
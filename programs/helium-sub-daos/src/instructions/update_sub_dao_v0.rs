@@ -1,5 +1,6 @@
-use crate::state::*;
+use crate::{state::*, validate_emission_schedule, validate_schedule};
 use anchor_lang::prelude::*;
+use shared_utils::resize_to_fit;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct UpdateSubDaoArgsV0 {
@@ -8,11 +9,15 @@ pub struct UpdateSubDaoArgsV0 {
   pub onboarding_dc_fee: Option<u64>,
   pub dc_burn_authority: Option<Pubkey>,
   pub active_device_aggregator: Option<Pubkey>,
+  pub delegator_rewards_percent_schedule: Option<Vec<PercentItem>>,
+  pub active_device_authority: Option<Pubkey>,
 }
 
 #[derive(Accounts)]
 #[instruction(args: UpdateSubDaoArgsV0)]
 pub struct UpdateSubDaoV0<'info> {
+  #[account(mut)]
+  pub payer: Signer<'info>,
   #[account(
     mut,
     seeds = ["sub_dao".as_bytes(), sub_dao.dnt_mint.key().as_ref()],
@@ -21,6 +26,7 @@ pub struct UpdateSubDaoV0<'info> {
   )]
   pub sub_dao: Box<Account<'info, SubDaoV0>>,
   pub authority: Signer<'info>,
+  pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<UpdateSubDaoV0>, args: UpdateSubDaoArgsV0) -> Result<()> {
@@ -28,7 +34,13 @@ pub fn handler(ctx: Context<UpdateSubDaoV0>, args: UpdateSubDaoArgsV0) -> Result
     ctx.accounts.sub_dao.authority = new_authority;
   }
 
+  let curr_ts = Clock::get()?.unix_timestamp;
   if let Some(emission_schedule) = args.emission_schedule {
+    validate_emission_schedule(
+      &emission_schedule,
+      &ctx.accounts.sub_dao.emission_schedule,
+      curr_ts,
+    )?;
     ctx.accounts.sub_dao.emission_schedule = emission_schedule;
   }
 
@@ -44,5 +56,24 @@ pub fn handler(ctx: Context<UpdateSubDaoV0>, args: UpdateSubDaoArgsV0) -> Result
     ctx.accounts.sub_dao.active_device_aggregator = active_device_aggregator;
   }
 
+  if let Some(delegator_rewards_percent_schedule) = args.delegator_rewards_percent_schedule {
+    validate_schedule(
+      &delegator_rewards_percent_schedule,
+      &ctx.accounts.sub_dao.delegator_rewards_percent_schedule,
+      curr_ts,
+    )?;
+    ctx.accounts.sub_dao.delegator_rewards_percent_schedule = delegator_rewards_percent_schedule;
+  }
+
+  if let Some(active_device_authority) = args.active_device_authority {
+    ctx.accounts.sub_dao.active_device_authority = active_device_authority;
+  }
+
+  resize_to_fit(
+    &ctx.accounts.payer.to_account_info(),
+    &ctx.accounts.system_program.to_account_info(),
+    &ctx.accounts.sub_dao,
+  )?;
+
   Ok(())
 }
@@ -40,6 +40,13 @@ pub mod helium_sub_daos {
     track_dc_burn_v0::handler(ctx, args)
   }
 
+  pub fn track_active_devices_v0(
+    ctx: Context<TrackActiveDevicesV0>,
+    args: TrackActiveDevicesArgsV0,
+  ) -> Result<()> {
+    track_active_devices_v0::handler(ctx, args)
+  }
+
   pub fn calculate_utility_score_v0(
     ctx: Context<CalculateUtilityScoreV0>,
     args: CalculateUtilityScoreArgsV0,
@@ -47,6 +54,12 @@ pub mod helium_sub_daos {
     calculate_utility_score_v0::handler(ctx, args)
   }
 
+  pub fn catch_up_vehnt_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, CatchUpVehntV0<'info>>,
+  ) -> Result<()> {
+    catch_up_vehnt_v0::handler(ctx)
+  }
+
   pub fn issue_rewards_v0(
     ctx: Context<IssueRewardsV0>,
     args: IssueRewardsArgsV0,
@@ -62,10 +75,27 @@ pub mod helium_sub_daos {
     close_delegation_v0::handler(ctx)
   }
 
+  pub fn redelegate_v0<'info>(ctx: Context<'_, '_, '_, 'info, RedelegateV0<'info>>) -> Result<()> {
+    redelegate_v0::handler(ctx)
+  }
+
   pub fn claim_rewards_v0(ctx: Context<ClaimRewardsV0>, args: ClaimRewardsArgsV0) -> Result<()> {
     claim_rewards_v0::handler(ctx, args)
   }
 
+  pub fn batch_claim_rewards_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, BatchClaimRewardsV0<'info>>,
+  ) -> Result<()> {
+    batch_claim_rewards_v0::handler(ctx)
+  }
+
+  pub fn preview_rewards_v0<'info>(
+    ctx: Context<'_, '_, '_, 'info, PreviewRewardsV0<'info>>,
+    args: PreviewRewardsArgsV0,
+  ) -> Result<RewardsPreviewV0> {
+    preview_rewards_v0::handler(ctx, args)
+  }
+
   pub fn transfer_v0(ctx: Context<TransferV0>, args: TransferArgsV0) -> Result<()> {
     transfer_v0::handler(ctx, args)
   }
@@ -100,4 +130,12 @@ pub mod helium_sub_daos {
   pub fn reset_sub_dao_thread_v0(ctx: Context<ResetSubDaoThreadV0>) -> Result<()> {
     reset_sub_dao_thread_v0::handler(ctx)
   }
+
+  pub fn migrate_dao_schedules_v0(ctx: Context<MigrateDaoSchedulesV0>) -> Result<()> {
+    migrate_dao_schedules_v0::handler(ctx)
+  }
+
+  pub fn migrate_sub_dao_schedules_v0(ctx: Context<MigrateSubDaoSchedulesV0>) -> Result<()> {
+    migrate_sub_dao_schedules_v0::handler(ctx)
+  }
 }
@@ -1,20 +1,48 @@
 use anchor_lang::prelude::*;
 use spl_governance_tools::account::AccountMaxSize;
 
-use crate::EPOCH_LENGTH;
+use crate::{
+  current_epoch, epoch_start_ts, error::ErrorCode, DEFAULT_DELEGATOR_REWARDS_PERCENT,
+  DEFAULT_EPOCH_LENGTH,
+};
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq)]
 pub struct EmissionScheduleItem {
   pub start_unix_time: i64,
   pub emissions_per_epoch: u64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq)]
 pub struct PercentItem {
   pub start_unix_time: i64,
   pub percent: u8, // percent / 100
 }
 
+pub trait ScheduleItem {
+  fn start_unix_time(&self) -> i64;
+
+  fn validate(&self) -> Result<()> {
+    Ok(())
+  }
+}
+
+impl ScheduleItem for EmissionScheduleItem {
+  fn start_unix_time(&self) -> i64 {
+    self.start_unix_time
+  }
+}
+
+impl ScheduleItem for PercentItem {
+  fn start_unix_time(&self) -> i64 {
+    self.start_unix_time
+  }
+
+  fn validate(&self) -> Result<()> {
+    require_gte!(100, self.percent, ErrorCode::InvalidPercent);
+    Ok(())
+  }
+}
+
 pub trait GetPercent {
   fn get_percent_at(&self, unix_time: i64) -> Option<u8>;
 }
@@ -37,6 +65,9 @@ impl GetPercent for Vec<PercentItem> {
         if current.start_unix_time <= unix_time {
           ans = Some(current.percent);
           low = middle + 1;
+        } else if middle == 0 {
+          // Target time is before the first entry
+          break;
         } else {
           // move left side
           high = middle - 1;
@@ -72,6 +103,9 @@ impl GetEmissions for Vec<EmissionScheduleItem> {
         if current.start_unix_time <= unix_time {
           ans = Some(current.emissions_per_epoch);
           low = middle + 1;
+        } else if middle == 0 {
+          // Target time is before the first entry
+          break;
         } else {
           // move left side
           high = middle - 1;
@@ -98,6 +132,84 @@ pub struct DaoV0 {
   pub emission_schedule: Vec<EmissionScheduleItem>,
   pub hst_emission_schedule: Vec<PercentItem>,
   pub bump_seed: u8,
+  // Base url for entity cNFT metadata. Empty uses the helium-entity-manager default.
+  // The url is baked into each cNFT at issuance, so changing it only affects entities issued after
+  pub entity_metadata_url: String,
+  // Length of an epoch in seconds. Zero on daos created before this was configurable, which use
+  // the 24h default. See `epoch_length()`
+  pub epoch_length: i64,
+  // Seconds past the unix epoch at which epoch 0 starts
+  pub epoch_offset: i64,
+  // None uses the default config. See `utility_score_config()`
+  pub utility_score_config: Option<UtilityScoreConfig>,
+  // Lamports paid from the crank vault to whoever runs a per-epoch calculate or issue step.
+  // Zero disables the bounty
+  pub crank_bounty_lamports: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UtilityScoreFactorV0 {
+  // Divides the raw on chain amount to get to whole units (dollars, veHNT)
+  pub divisor: u64,
+  // factor = max(floor, (raw / divisor) ^ (exponent_numerator / exponent_denominator))
+  pub exponent_numerator: u8,
+  pub exponent_denominator: u8,
+  pub floor: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum UtilityScoreConfig {
+  // utility score = V * D * A
+  V0 {
+    // V, from veHNT delegated at epoch start
+    vehnt: UtilityScoreFactorV0,
+    // D, from DC burned
+    dc_burned: UtilityScoreFactorV0,
+    // A, from active devices * onboarding dc fee
+    devices: UtilityScoreFactorV0,
+  },
+}
+
+impl Default for UtilityScoreConfig {
+  // V = max(1, veHNT_dnp).
+  // D = max(1, fourth_root(DCs burned in USD)). 1 DC = $0.00001.
+  // A = max(1, sqrt(Total active device count * device activation fee in USD)).
+  fn default() -> Self {
+    UtilityScoreConfig::V0 {
+      vehnt: UtilityScoreFactorV0 {
+        divisor: 100_000_000, // vehnt has 8 decimals
+        exponent_numerator: 1,
+        exponent_denominator: 1,
+        floor: 1,
+      },
+      dc_burned: UtilityScoreFactorV0 {
+        divisor: 100_000, // DC has 0 decimals, plus 10^5 to get to dollars.
+        exponent_numerator: 1,
+        exponent_denominator: 4,
+        floor: 1,
+      },
+      devices: UtilityScoreFactorV0 {
+        divisor: 100_000, // Need onboarding fee in dollars
+        exponent_numerator: 1,
+        exponent_denominator: 2,
+        floor: 1,
+      },
+    }
+  }
+}
+
+impl DaoV0 {
+  pub fn epoch_length(&self) -> i64 {
+    if self.epoch_length == 0 {
+      DEFAULT_EPOCH_LENGTH
+    } else {
+      self.epoch_length
+    }
+  }
+
+  pub fn utility_score_config(&self) -> UtilityScoreConfig {
+    self.utility_score_config.clone().unwrap_or_default()
+  }
 }
 
 #[account]
@@ -128,6 +240,22 @@ pub struct DelegatedPositionV0 {
   pub start_ts: i64,
   pub purged: bool, // if true, this position has been removed from subdao calculations. rewards can still be claimed.
   pub bump_seed: u8,
+  // When the position was delegated. Unlike `start_ts` this is kept when redelegating. Zero on
+  // positions delegated before redelegation existed, which have never had `start_ts` reset
+  pub delegation_ts: i64,
+}
+
+impl DelegatedPositionV0 {
+  /// Whether the position is counted in its sub dao's `vehnt_at_epoch_start` for `epoch`. It is
+  /// once delegated before the epoch started, and redelegation moves that share along with it.
+  pub fn has_epoch_start_share(&self, dao: &DaoV0, epoch: u64) -> bool {
+    let delegation_ts = if self.delegation_ts == 0 {
+      self.start_ts
+    } else {
+      self.delegation_ts
+    };
+    current_epoch(dao, delegation_ts) < epoch
+  }
 }
 
 #[account]
@@ -154,6 +282,9 @@ pub struct SubDaoEpochInfoV0 {
   pub rewards_issued_at: Option<i64>,
   pub bump_seed: u8,
   pub initialized: bool,
+  /// Active devices for this epoch as attested by the subdao's `active_device_authority`. Required
+  /// for the utility score of subdaos with an authority, which never read the active device aggregator
+  pub active_devices: Option<u64>,
 }
 
 impl AccountMaxSize for SubDaoEpochInfoV0 {
@@ -163,12 +294,12 @@ impl AccountMaxSize for SubDaoEpochInfoV0 {
 }
 
 impl SubDaoEpochInfoV0 {
-  pub fn start_ts(&self) -> i64 {
-    i64::try_from(self.epoch).unwrap() * EPOCH_LENGTH
+  pub fn start_ts(&self, dao: &DaoV0) -> i64 {
+    epoch_start_ts(dao, self.epoch)
   }
 
-  pub fn end_ts(&self) -> i64 {
-    i64::try_from(self.epoch + 1).unwrap() * EPOCH_LENGTH
+  pub fn end_ts(&self, dao: &DaoV0) -> i64 {
+    epoch_start_ts(dao, self.epoch + 1)
   }
 }
 
@@ -189,4 +320,18 @@ pub struct SubDaoV0 {
   pub onboarding_dc_fee: u64,
   pub emission_schedule: Vec<EmissionScheduleItem>,
   pub bump_seed: u8,
+  // Percent of emissions sent to the delegator pool, remainder goes to rewards escrow.
+  // Empty uses DEFAULT_DELEGATOR_REWARDS_PERCENT
+  pub delegator_rewards_percent_schedule: Vec<PercentItem>,
+  // Attests per epoch active device counts. Default key means the active device aggregator is used
+  pub active_device_authority: Pubkey,
+}
+
+impl SubDaoV0 {
+  pub fn delegator_rewards_percent_at(&self, unix_time: i64) -> u8 {
+    self
+      .delegator_rewards_percent_schedule
+      .get_percent_at(unix_time)
+      .unwrap_or(DEFAULT_DELEGATOR_REWARDS_PERCENT)
+  }
 }
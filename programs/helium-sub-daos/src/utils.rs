@@ -1,5 +1,10 @@
 use crate::{error::ErrorCode, state::*, TESTING};
-use anchor_lang::{prelude::*, solana_program::instruction::Instruction, InstructionData};
+use anchor_lang::{
+  prelude::*,
+  solana_program::instruction::Instruction,
+  system_program::{transfer, Transfer},
+  InstructionData,
+};
 use shared_utils::{precise_number::PreciseNumber, signed_precise_number::SignedPreciseNumber};
 use std::{cmp::Ordering, convert::TryInto};
 use time::{Duration, OffsetDateTime};
@@ -21,17 +26,328 @@ impl OrArithError<SignedPreciseNumber> for Option<SignedPreciseNumber> {
   }
 }
 
-pub const EPOCH_LENGTH: i64 = 24 * 60 * 60;
+pub const DEFAULT_EPOCH_LENGTH: i64 = 24 * 60 * 60;
+pub const WEEK_EPOCH_LENGTH: i64 = 7 * DEFAULT_EPOCH_LENGTH;
+// Percent of subdao emissions sent to the delegator pool when a subdao has no schedule
+pub const DEFAULT_DELEGATOR_REWARDS_PERCENT: u8 = 6;
+// Bounds the size of the dao and subdao accounts that hold schedules
+pub const MAX_SCHEDULE_LENGTH: usize = 100;
+pub const MAX_ENTITY_METADATA_URL_LENGTH: usize = 200;
+
+pub fn current_epoch(dao: &DaoV0, unix_timestamp: i64) -> u64 {
+  ((unix_timestamp - dao.epoch_offset) / dao.epoch_length())
+    .try_into()
+    .unwrap()
+}
+
+pub fn epoch_start_ts(dao: &DaoV0, epoch: u64) -> i64 {
+  i64::try_from(epoch).unwrap() * dao.epoch_length() + dao.epoch_offset
+}
+
+pub fn next_epoch_ts(dao: &DaoV0, unix_timestamp: i64) -> u64 {
+  epoch_start_ts(dao, current_epoch(dao, unix_timestamp) + 1)
+    .try_into()
+    .unwrap()
+}
+
+/// Epochs are ended by a clockwork cron, so the length must be expressible as one: a whole
+/// number of minutes that divides an hour, a whole number of hours that divides a day, or a
+/// week. The offset shifts epoch boundaries by a whole number of minutes, less than one epoch.
+pub fn validate_epoch_config(epoch_length: i64, epoch_offset: i64) -> Result<()> {
+  let cron_expressible = epoch_length > 0
+    && epoch_length % 60 == 0
+    && (3600 % epoch_length == 0
+      || (epoch_length % 3600 == 0 && DEFAULT_EPOCH_LENGTH % epoch_length == 0)
+      || epoch_length == WEEK_EPOCH_LENGTH);
+  require!(cron_expressible, ErrorCode::InvalidEpochLength);
+  require!(
+    (0..epoch_length).contains(&epoch_offset) && epoch_offset % 60 == 0,
+    ErrorCode::InvalidEpochLength
+  );
+
+  Ok(())
+}
+
+fn is_strictly_increasing<T: ScheduleItem>(schedule: &[T]) -> bool {
+  schedule
+    .windows(2)
+    .all(|items| items[0].start_unix_time() < items[1].start_unix_time())
+}
+
+/// Orders a schedule stored before schedules were validated the way lookups settle on entries:
+/// by start time, with the last of several entries starting at the same time winning.
+pub fn normalize_schedule<T: ScheduleItem + Clone>(schedule: &[T]) -> Vec<T> {
+  let mut sorted = schedule.to_vec();
+  sorted.sort_by_key(|item| item.start_unix_time());
+  let mut normalized: Vec<T> = Vec::with_capacity(sorted.len());
+  for item in sorted {
+    if normalized.last().map_or(false, |last| {
+      last.start_unix_time() == item.start_unix_time()
+    }) {
+      normalized.pop();
+    }
+    normalized.push(item);
+  }
+  normalized
+}
+
+/// Validates a schedule that replaces `previous` (empty at init). Start times must be strictly
+/// increasing since lookups binary search the schedule, and entries of `previous` that have
+/// already started can't be edited or removed. Legacy `previous` schedules that were never
+/// sorted are compared in their normalized order, see `normalize_schedule`.
+pub fn validate_schedule<T: ScheduleItem + PartialEq + Clone>(
+  schedule: &[T],
+  previous: &[T],
+  curr_ts: i64,
+) -> Result<()> {
+  require_gte!(
+    MAX_SCHEDULE_LENGTH,
+    schedule.len(),
+    ErrorCode::ScheduleTooLong
+  );
+  for item in schedule {
+    item.validate()?;
+  }
+  require!(
+    is_strictly_increasing(schedule),
+    ErrorCode::ScheduleNotSorted
+  );
+
+  let previous = normalize_schedule(previous);
+  let started = previous
+    .iter()
+    .take_while(|item| item.start_unix_time() <= curr_ts)
+    .count();
+  require!(
+    schedule.get(..started) == Some(&previous[..started]),
+    ErrorCode::SchedulePastEntryChanged
+  );
+
+  Ok(())
+}
+
+/// Emissions are looked up at the current time when issuing, so unlike the delegator rewards
+/// schedule, which falls back to a default, these schedules must already be in effect.
+pub fn validate_emission_schedule<T: ScheduleItem + PartialEq + Clone>(
+  schedule: &[T],
+  previous: &[T],
+  curr_ts: i64,
+) -> Result<()> {
+  validate_schedule(schedule, previous, curr_ts)?;
+  require!(
+    schedule
+      .first()
+      .map_or(false, |item| item.start_unix_time() <= curr_ts),
+    ErrorCode::ScheduleNotStarted
+  );
+
+  Ok(())
+}
+
+/// Pays the dao's crank bounty out of its crank vault. Skipped when the vault can't cover it, so
+/// that an unfunded vault never stops the crank.
+pub fn pay_crank_bounty<'info>(
+  dao: &Account<'info, DaoV0>,
+  crank_vault: &AccountInfo<'info>,
+  crank_vault_bump: u8,
+  cranker: &AccountInfo<'info>,
+  system_program: &AccountInfo<'info>,
+) -> Result<()> {
+  let bounty = dao.crank_bounty_lamports;
+  if bounty == 0 {
+    return Ok(());
+  }
+
+  let available = crank_vault
+    .lamports()
+    .saturating_sub(Rent::get()?.minimum_balance(0));
+  if available < bounty {
+    msg!("Crank vault has {} lamports, skipping bounty", available);
+    return Ok(());
+  }
+
+  transfer(
+    CpiContext::new_with_signer(
+      system_program.clone(),
+      Transfer {
+        from: crank_vault.clone(),
+        to: cranker.clone(),
+      },
+      &[&[
+        "crank_vault".as_bytes(),
+        dao.key().as_ref(),
+        &[crank_vault_bump],
+      ]],
+    ),
+    bounty,
+  )
+}
+
+pub fn validate_utility_score_config(config: &UtilityScoreConfig) -> Result<()> {
+  let UtilityScoreConfig::V0 {
+    vehnt,
+    dc_burned,
+    devices,
+  } = config;
+  require!(
+    [vehnt, dc_burned, devices]
+      .iter()
+      .all(|factor| factor.divisor > 0 && factor.exponent_denominator > 0),
+    ErrorCode::InvalidUtilityScoreConfig
+  );
+
+  Ok(())
+}
+
+// max(floor, (raw / divisor) ^ (numerator / denominator)). The exponent is applied as
+// e^(ln(x) * numerator / denominator) rather than through `pow`, so the default roots round
+// exactly as the original hardcoded formula did.
+fn utility_score_factor(
+  factor: &UtilityScoreFactorV0,
+  raw: PreciseNumber,
+) -> Option<PreciseNumber> {
+  let floor = PreciseNumber::new(factor.floor.into())?;
+  let units = raw.checked_div(&PreciseNumber::new(factor.divisor.into())?)?;
+  if units == PreciseNumber::zero() {
+    return Some(floor);
+  }
+
+  let scaled = if factor.exponent_numerator == factor.exponent_denominator {
+    units
+  } else {
+    units
+      .log()?
+      .checked_mul(&PreciseNumber::new(factor.exponent_numerator.into())?.signed())?
+      .checked_div(&PreciseNumber::new(factor.exponent_denominator.into())?.signed())?
+      .exp()?
+  };
 
-pub fn current_epoch(unix_timestamp: i64) -> u64 {
-  (unix_timestamp / (EPOCH_LENGTH)).try_into().unwrap()
+  Some(std::cmp::max(floor, scaled))
 }
 
-pub fn next_epoch_ts(unix_timestamp: i64) -> u64 {
-  (current_epoch(unix_timestamp) + 1) * u64::try_from(EPOCH_LENGTH).unwrap()
+/// Utility score as a u128 with 12 decimals of precision
+pub fn calculate_utility_score(
+  config: &UtilityScoreConfig,
+  vehnt_at_epoch_start: u128,
+  dc_burned: u64,
+  total_devices: u64,
+  onboarding_dc_fee: u64,
+) -> Option<u128> {
+  let UtilityScoreConfig::V0 {
+    vehnt: vehnt_factor,
+    dc_burned: dc_burned_factor,
+    devices: devices_factor,
+  } = config;
+
+  let v = utility_score_factor(vehnt_factor, PreciseNumber::new(vehnt_at_epoch_start)?)?;
+  let d = utility_score_factor(dc_burned_factor, PreciseNumber::new(dc_burned.into())?)?;
+  let devices_with_fee = PreciseNumber::new(total_devices.into())?
+    .checked_mul(&PreciseNumber::new(onboarding_dc_fee.into())?)?;
+  let a = utility_score_factor(devices_factor, devices_with_fee)?;
+
+  d.checked_mul(&a)?
+    .checked_mul(&v)?
+    .checked_mul(&PreciseNumber::new(1_000_000_000_000_u128)?)? // u128 with 12 decimal places
+    .to_imprecise()
+}
+
+/// A delegated position's share of an epoch's delegation rewards, rounded down. The rounding
+/// leaves a little dust in the delegator pool rather than ever paying out more than was issued.
+pub fn delegation_rewards(
+  delegated_vehnt_at_epoch: u64,
+  delegation_rewards_issued: u64,
+  vehnt_at_epoch_start: u64,
+) -> Option<u64> {
+  if vehnt_at_epoch_start == 0 {
+    return Some(0);
+  }
+
+  // rewards = delegation_rewards_issued * delegated_vehnt_at_epoch / vehnt_at_epoch_start
+  u128::from(delegated_vehnt_at_epoch)
+    .checked_mul(u128::from(delegation_rewards_issued))?
+    .checked_div(u128::from(vehnt_at_epoch_start))?
+    .try_into()
+    .ok()
+}
+
+/// A delegated position's veHNT at the start of an epoch and its rewards for that epoch. The
+/// claims and the rewards preview all go through here so that a preview matches the claim.
+pub fn position_epoch_rewards(
+  position: &PositionV0,
+  voting_mint_config: &VotingMintConfigV0,
+  dao: &DaoV0,
+  sub_dao_epoch_info: &SubDaoEpochInfoV0,
+) -> Result<(u64, u64)> {
+  let delegated_vehnt_at_epoch =
+    position.voting_power(voting_mint_config, sub_dao_epoch_info.start_ts(dao))?;
+  let rewards = delegation_rewards(
+    delegated_vehnt_at_epoch,
+    sub_dao_epoch_info.delegation_rewards_issued,
+    sub_dao_epoch_info.vehnt_at_epoch_start,
+  )
+  .ok_or_else(|| error!(ErrorCode::ArithmeticError))?;
+
+  Ok((delegated_vehnt_at_epoch, rewards))
+}
+
+/// Sums a delegated position's rewards over the sub dao epoch infos in `epoch_infos`, which must
+/// be a contiguous run of ended epochs starting at `last_claimed_epoch + 1`, in order. Returns the
+/// last epoch claimed and the total rewards.
+pub fn claim_epoch_rewards<'info>(
+  dao: &DaoV0,
+  sub_dao: Pubkey,
+  position: &PositionV0,
+  voting_mint_config: &VotingMintConfigV0,
+  last_claimed_epoch: u64,
+  curr_epoch: u64,
+  epoch_infos: &'info [AccountInfo<'info>],
+) -> Result<(u64, u64)> {
+  let mut claim_epoch = last_claimed_epoch;
+  let mut rewards: u64 = 0;
+  for account in epoch_infos {
+    claim_epoch += 1;
+    if !TESTING {
+      require_gt!(curr_epoch, claim_epoch, ErrorCode::EpochNotOver);
+    }
+
+    let sub_dao_epoch_info: Account<SubDaoEpochInfoV0> = Account::try_from(account)?;
+    let expected = Pubkey::create_program_address(
+      &[
+        "sub_dao_epoch_info".as_bytes(),
+        sub_dao.as_ref(),
+        &claim_epoch.to_le_bytes(),
+        &[sub_dao_epoch_info.bump_seed],
+      ],
+      &crate::id(),
+    )
+    .map_err(|_| error!(ErrorCode::InvalidClaimEpoch))?;
+    require_keys_eq!(expected, account.key(), ErrorCode::InvalidClaimEpoch);
+    // Claiming before rewards are issued would skip the epoch for good
+    require!(
+      sub_dao_epoch_info.rewards_issued_at.is_some(),
+      ErrorCode::RewardsNotIssued
+    );
+
+    let (delegated_vehnt_at_epoch, epoch_rewards) =
+      position_epoch_rewards(position, voting_mint_config, dao, &sub_dao_epoch_info)?;
+    msg!(
+      "Epoch {}: {} veHNT delegated of {} total, claiming {} rewards",
+      claim_epoch,
+      delegated_vehnt_at_epoch,
+      sub_dao_epoch_info.vehnt_at_epoch_start,
+      epoch_rewards
+    );
+
+    rewards = rewards
+      .checked_add(epoch_rewards)
+      .ok_or_else(|| error!(ErrorCode::ArithmeticError))?;
+  }
+
+  Ok((claim_epoch, rewards))
 }
 
 pub fn update_subdao_vehnt(
+  dao: &DaoV0,
   sub_dao: &mut SubDaoV0,
   curr_epoch_info: &mut SubDaoEpochInfoV0,
   curr_ts: i64,
@@ -49,11 +365,11 @@ pub fn update_subdao_vehnt(
   );
 
   // If last calculated was more than an epoch ago
-  let epoch_start = curr_epoch_info.start_ts();
+  let epoch_start = curr_epoch_info.start_ts(dao);
   if epoch_start
     .checked_sub(sub_dao.vehnt_last_calculated_ts)
     .unwrap()
-    > EPOCH_LENGTH
+    > dao.epoch_length()
     && !TESTING
   // Allow this check to be bypassed when testing so we can run
   // checks against this method without having to update _every_ epoch
@@ -178,6 +494,7 @@ pub struct VehntInfo {
   pub end_fall_rate_correction: u128,
 }
 pub fn caclulate_vhnt_info(
+  dao: &DaoV0,
   curr_ts: i64,
   position: &PositionV0,
   voting_mint_config: &VotingMintConfigV0,
@@ -235,8 +552,7 @@ pub fn caclulate_vhnt_info(
   let mut genesis_end_vehnt_correction = 0;
   let mut genesis_end_fall_rate_correction = 0;
   if has_genesis {
-    let genesis_end_epoch_start_ts =
-      i64::try_from(current_epoch(position.genesis_end)).unwrap() * EPOCH_LENGTH;
+    let genesis_end_epoch_start_ts = epoch_start_ts(dao, current_epoch(dao, position.genesis_end));
     if position.lockup.kind == LockupKind::Cliff {
       genesis_end_fall_rate_correction = pre_genesis_end_fall_rate
         .checked_sub(post_genesis_end_fall_rate)
@@ -276,8 +592,7 @@ pub fn caclulate_vhnt_info(
   let mut end_fall_rate_correction = 0;
   let mut end_vehnt_correction = 0;
   if position.lockup.kind == LockupKind::Cliff {
-    let end_epoch_start_ts =
-      i64::try_from(current_epoch(position.lockup.end_ts)).unwrap() * EPOCH_LENGTH;
+    let end_epoch_start_ts = epoch_start_ts(dao, current_epoch(dao, position.lockup.end_ts));
     let vehnt_at_closing_epoch_start =
       position.voting_power(voting_mint_config, end_epoch_start_ts)?;
 
@@ -387,3 +702,242 @@ pub fn construct_issue_hst_kickoff_ix(
     data: crate::instruction::IssueHstKickoffV0 {}.data(),
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_delegation_rewards_leave_dust() {
+    // Three equal delegators splitting 100 each get 33, leaving 1 in the pool
+    let shares: Vec<u64> = (0..3)
+      .map(|_| delegation_rewards(10, 100, 30).unwrap())
+      .collect();
+    assert_eq!(shares, vec![33, 33, 33]);
+    assert_eq!(100 - shares.iter().sum::<u64>(), 1);
+  }
+
+  #[test]
+  fn test_delegation_rewards_summed_across_epochs() {
+    // Dust is dropped per epoch, so a catch-up claim pays what separate claims would have
+    let epochs = [(7, 1_000, 9), (7, 0, 9), (3, 500, 11)];
+    let total: u64 = epochs
+      .iter()
+      .map(|(vehnt, issued, vehnt_start)| {
+        delegation_rewards(*vehnt, *issued, *vehnt_start).unwrap()
+      })
+      .sum();
+    // 777 + 0 + 136
+    assert_eq!(total, 913);
+  }
+
+  #[test]
+  fn test_epochs_default_to_a_day() {
+    let dao = DaoV0::default();
+    assert_eq!(current_epoch(&dao, 86_399), 0);
+    assert_eq!(current_epoch(&dao, 86_400), 1);
+    assert_eq!(epoch_start_ts(&dao, 2), 172_800);
+    assert_eq!(next_epoch_ts(&dao, 100_000), 172_800);
+  }
+
+  #[test]
+  fn test_epochs_with_length_and_offset() {
+    let dao = DaoV0 {
+      epoch_length: 3600,
+      epoch_offset: 600,
+      ..Default::default()
+    };
+    assert_eq!(current_epoch(&dao, 599), 0);
+    assert_eq!(current_epoch(&dao, 4199), 0);
+    assert_eq!(current_epoch(&dao, 4200), 1);
+    assert_eq!(epoch_start_ts(&dao, 1), 4200);
+    assert_eq!(next_epoch_ts(&dao, 4200), 7800);
+  }
+
+  #[test]
+  fn test_validate_epoch_config() {
+    assert!(validate_epoch_config(DEFAULT_EPOCH_LENGTH, 0).is_ok());
+    assert!(validate_epoch_config(WEEK_EPOCH_LENGTH, 3600).is_ok());
+    assert!(validate_epoch_config(3600, 0).is_ok());
+    assert!(validate_epoch_config(4 * 3600, 0).is_ok());
+    assert!(validate_epoch_config(15 * 60, 0).is_ok());
+
+    assert!(validate_epoch_config(0, 0).is_err());
+    // Not expressible as a cron
+    assert!(validate_epoch_config(90 * 60, 0).is_err());
+    assert!(validate_epoch_config(5 * 3600, 0).is_err());
+    assert!(validate_epoch_config(2 * DEFAULT_EPOCH_LENGTH, 0).is_err());
+    // Offsets must be whole minutes within an epoch
+    assert!(validate_epoch_config(3600, 3600).is_err());
+    assert!(validate_epoch_config(3600, 30).is_err());
+  }
+
+  #[test]
+  fn test_validate_schedule() {
+    let item = |start_unix_time, emissions_per_epoch| EmissionScheduleItem {
+      start_unix_time,
+      emissions_per_epoch,
+    };
+    let previous = [item(0, 100), item(1_000, 50)];
+
+    assert!(validate_emission_schedule(&previous, &[], 500).is_ok());
+    assert!(validate_emission_schedule(&[item(1_000, 50)], &[], 500).is_err());
+    assert!(validate_schedule(&[item(0, 100), item(0, 50)], &[], 500).is_err());
+    assert!(validate_schedule(&[item(1_000, 50), item(0, 100)], &[], 500).is_err());
+    assert!(validate_schedule(&vec![item(0, 100); MAX_SCHEDULE_LENGTH + 1], &[], 500).is_err());
+
+    // Entries that haven't started yet can be changed, started ones can't
+    assert!(validate_schedule(&[item(0, 100), item(2_000, 25)], &previous, 500).is_ok());
+    assert!(validate_schedule(&[item(0, 100)], &previous, 500).is_ok());
+    assert!(validate_schedule(&[item(0, 90), item(1_000, 50)], &previous, 500).is_err());
+    assert!(validate_schedule(&[item(1_000, 50)], &previous, 500).is_err());
+    assert!(validate_schedule(&[item(0, 100)], &previous, 1_000).is_err());
+    // Unsorted legacy schedules keep their started entries, in start time order
+    let unsorted = [item(1_000, 50), item(0, 100), item(0, 80)];
+    assert!(normalize_schedule(&unsorted) == vec![item(0, 80), item(1_000, 50)]);
+    assert!(validate_schedule(&[item(0, 80), item(1_000, 50)], &unsorted, 1_500).is_ok());
+    assert!(validate_schedule(&[item(0, 80), item(2_000, 25)], &unsorted, 500).is_ok());
+    assert!(validate_schedule(&[item(0, 90)], &unsorted, 1_500).is_err());
+    assert!(validate_schedule(&[item(0, 80)], &unsorted, 1_500).is_err());
+    // Lookups before the first entry find nothing rather than underflowing
+    let future = normalize_schedule(&[item(2_000, 25), item(1_000, 50)]);
+    assert!(future.get_emissions_at(500).is_none());
+    assert_eq!(future.get_emissions_at(1_500), Some(50));
+    assert!(validate_emission_schedule(&future, &[], 500).is_err());
+
+    let percent_item = |percent| PercentItem {
+      start_unix_time: 0,
+      percent,
+    };
+    assert!(validate_schedule(&[percent_item(100)], &[], 0).is_ok());
+    assert!(validate_schedule(&[percent_item(101)], &[], 0).is_err());
+  }
+
+  #[test]
+  fn test_percent_before_first_entry() {
+    let schedule = vec![
+      PercentItem {
+        start_unix_time: 1_000,
+        percent: 10,
+      },
+      PercentItem {
+        start_unix_time: 2_000,
+        percent: 20,
+      },
+    ];
+    assert!(schedule.get_percent_at(500).is_none());
+    assert_eq!(schedule.get_percent_at(1_500), Some(10));
+    assert_eq!(schedule.get_percent_at(2_000), Some(20));
+
+    let sub_dao = SubDaoV0 {
+      delegator_rewards_percent_schedule: schedule,
+      ..Default::default()
+    };
+    assert_eq!(
+      sub_dao.delegator_rewards_percent_at(500),
+      DEFAULT_DELEGATOR_REWARDS_PERCENT
+    );
+  }
+
+  #[test]
+  fn test_delegation_rewards_edge_cases() {
+    assert_eq!(delegation_rewards(10, 100, 0), Some(0));
+    // Doesn't overflow on large veHNT amounts
+    assert_eq!(
+      delegation_rewards(u64::MAX / 2, u64::MAX / 2, u64::MAX / 2),
+      Some(u64::MAX / 2)
+    );
+  }
+
+  #[test]
+  fn test_default_utility_score_matches_original_formula() {
+    // (vehnt_at_epoch_start, dc_burned, total_devices, onboarding_dc_fee) -> score, as produced by
+    // the original hardcoded V * D * A calculation
+    let golden: [((u64, u64, u64, u64), u128); 8] = [
+      ((0, 0, 0, 0), 1_000_000_000_000),
+      ((0, 0, 0, 4_000_000), 1_000_000_000_000),
+      ((100_000_000, 0, 0, 4_000_000), 1_000_000_000_000),
+      ((1, 1, 1, 4_000_000), 6_324_555_320_337),
+      (
+        (12_345_678_900_000, 2_000_000_000, 10_000, 4_000_000),
+        928_543_965_151_828_499_728,
+      ),
+      (
+        (5_000_000_000_000_000, 987_654_321_000, 1_000_000, 1_000_000),
+        8_863_826_494_936_000_000_000_000,
+      ),
+      (
+        (250_000_000_000_000, 100_000, 1, 4_000_000),
+        15_811_388_300_841_896_660,
+      ),
+      (
+        (99_999_999, 1_600_000_000_000, 50_000, 1_000_000),
+        44_721_359_549_995_794,
+      ),
+    ];
+    let config = UtilityScoreConfig::default();
+    for ((vehnt, dc_burned, devices, fee), score) in golden {
+      assert_eq!(
+        calculate_utility_score(&config, vehnt.into(), dc_burned, devices, fee),
+        Some(score)
+      );
+    }
+    assert_eq!(DaoV0::default().utility_score_config(), config);
+  }
+
+  #[test]
+  fn test_custom_utility_score_config() {
+    let factor = |divisor, exponent_numerator, exponent_denominator, floor| UtilityScoreFactorV0 {
+      divisor,
+      exponent_numerator,
+      exponent_denominator,
+      floor,
+    };
+    // Linear dc burn, cube root of devices, and no floor on veHNT
+    let config = UtilityScoreConfig::V0 {
+      vehnt: factor(100_000_000, 1, 1, 0),
+      dc_burned: factor(100_000, 1, 1, 1),
+      devices: factor(100_000, 1, 3, 1),
+    };
+    assert_eq!(
+      calculate_utility_score(
+        &config,
+        12_345_678_900_000,
+        2_000_000_000,
+        10_000,
+        4_000_000
+      ),
+      Some(181_927_479_758_799_998_536_913)
+    );
+    assert_eq!(
+      calculate_utility_score(&config, 0, 2_000_000_000, 10_000, 4_000_000),
+      Some(0)
+    );
+
+    // 16M dollars of dc burned to the 3/4 is 252,982
+    let config = UtilityScoreConfig::V0 {
+      vehnt: factor(100_000_000, 1, 1, 1),
+      dc_burned: factor(100_000, 3, 4, 1),
+      devices: factor(100_000, 1, 2, 1),
+    };
+    assert_eq!(
+      calculate_utility_score(&config, 100_000_000, 1_600_000_000_000, 0, 0),
+      Some(252_982_212_813_470_347)
+    );
+
+    assert!(validate_utility_score_config(&config).is_ok());
+    let UtilityScoreConfig::V0 { vehnt, devices, .. } = config;
+    assert!(validate_utility_score_config(&UtilityScoreConfig::V0 {
+      vehnt: vehnt.clone(),
+      dc_burned: factor(0, 1, 4, 1),
+      devices: devices.clone(),
+    })
+    .is_err());
+    assert!(validate_utility_score_config(&UtilityScoreConfig::V0 {
+      vehnt,
+      dc_burned: factor(100_000, 1, 0, 1),
+      devices,
+    })
+    .is_err());
+  }
+}
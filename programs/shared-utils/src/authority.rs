@@ -0,0 +1,65 @@
+use crate::error::ErrorCode;
+use anchor_lang::prelude::*;
+
+/// Nominates `new_authority` to take over from `authority`. Nothing changes hands until the
+/// nominee signs with `accept_authority`, so a mistyped key can't lock the account. Nominating
+/// the current authority cancels a pending handoff.
+pub fn nominate_authority(
+  authority: Pubkey,
+  pending_authority: &mut Option<Pubkey>,
+  new_authority: Pubkey,
+) {
+  *pending_authority = if new_authority == authority {
+    None
+  } else {
+    Some(new_authority)
+  };
+}
+
+/// Completes a handoff started by `nominate_authority`. `signer` must be the nominee.
+pub fn accept_authority(
+  authority: &mut Pubkey,
+  pending_authority: &mut Option<Pubkey>,
+  signer: &Pubkey,
+) -> Result<()> {
+  require!(
+    *pending_authority == Some(*signer),
+    ErrorCode::NotPendingAuthority
+  );
+  *authority = *signer;
+  *pending_authority = None;
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_handoff() {
+    let mut authority = Pubkey::new_unique();
+    let original = authority;
+    let nominee = Pubkey::new_unique();
+    let mut pending = None;
+
+    assert!(accept_authority(&mut authority, &mut pending, &nominee).is_err());
+
+    nominate_authority(authority, &mut pending, nominee);
+    assert_eq!(authority, original);
+    assert!(accept_authority(&mut authority, &mut pending, &Pubkey::new_unique()).is_err());
+    accept_authority(&mut authority, &mut pending, &nominee).unwrap();
+    assert_eq!(authority, nominee);
+    assert_eq!(pending, None);
+  }
+
+  #[test]
+  fn test_nominating_self_cancels() {
+    let authority = Pubkey::new_unique();
+    let mut pending = None;
+    nominate_authority(authority, &mut pending, Pubkey::new_unique());
+    assert!(pending.is_some());
+    nominate_authority(authority, &mut pending, authority);
+    assert_eq!(pending, None);
+  }
+}
@@ -7,4 +7,7 @@ pub enum ErrorCode {
 
   #[msg("Error in arithmetic")]
   ArithmeticError,
+
+  #[msg("Signer isn't the pending authority")]
+  NotPendingAuthority,
 }
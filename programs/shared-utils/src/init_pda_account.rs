@@ -0,0 +1,66 @@
+use anchor_lang::{
+  prelude::*,
+  solana_program::{
+    program::{invoke, invoke_signed},
+    system_instruction,
+  },
+};
+
+/// Creates a PDA owned by `T`'s program and writes `data` to it. Used when the accounts
+/// being initialized are only known at runtime, so they come in through remaining accounts.
+pub fn init_pda_account<'info, T: AccountSerialize + Owner>(
+  payer: &AccountInfo<'info>,
+  system_program: &AccountInfo<'info>,
+  account: &AccountInfo<'info>,
+  signer_seeds: &[&[u8]],
+  space: usize,
+  data: &T,
+) -> Result<()> {
+  let mut serialized = vec![];
+  data.try_serialize(&mut serialized)?;
+  require_gte!(space, serialized.len());
+
+  let total_lamports = Rent::get()?.minimum_balance(space);
+
+  // If the account has some lamports already it can't be created using create_account instruction
+  // Anybody can send lamports to a PDA and by doing so create the account and perform DoS attack by blocking create_account
+  if account.lamports() > 0 {
+    let top_up_lamports = total_lamports.saturating_sub(account.lamports());
+
+    if top_up_lamports > 0 {
+      invoke(
+        &system_instruction::transfer(payer.key, account.key, top_up_lamports),
+        &[payer.clone(), account.clone(), system_program.clone()],
+      )?;
+    }
+
+    invoke_signed(
+      &system_instruction::allocate(account.key, space as u64),
+      &[account.clone(), system_program.clone()],
+      &[signer_seeds],
+    )?;
+
+    invoke_signed(
+      &system_instruction::assign(account.key, &T::owner()),
+      &[account.clone(), system_program.clone()],
+      &[signer_seeds],
+    )?;
+  } else {
+    // If the PDA doesn't exist use create_account to use lower compute budget
+    invoke_signed(
+      &system_instruction::create_account(
+        payer.key,
+        account.key,
+        total_lamports,
+        space as u64,
+        &T::owner(),
+      ),
+      &[payer.clone(), account.clone(), system_program.clone()],
+      &[signer_seeds],
+    )?;
+  }
+
+  account.try_borrow_mut_data()?[..serialized.len()].copy_from_slice(&serialized);
+
+  Ok(())
+}
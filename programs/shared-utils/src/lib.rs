@@ -1,5 +1,7 @@
+pub mod authority;
 pub mod compressed_nfts;
 pub mod error;
+pub mod init_pda_account;
 pub mod precise_number;
 pub mod resize_to_fit;
 pub mod signed_precise_number;
@@ -7,8 +9,10 @@ pub mod token_metadata;
 pub mod uint;
 
 pub use crate::uint::*;
+pub use authority::*;
 pub use compressed_nfts::*;
 pub use error::*;
+pub use init_pda_account::*;
 pub use precise_number::*;
 pub use resize_to_fit::*;
 pub use signed_precise_number::*;
@@ -162,6 +162,9 @@ describe("data-credits", () => {
           authority: me,
           registrar,
           netEmissionsCap: toBN(34.24, 8),
+          entityMetadataUrl: "",
+          epochLength: null,
+          epochOffset: null,
           hstEmissionSchedule: [
             {
               startUnixTime: new anchor.BN(0),
@@ -227,6 +230,36 @@ describe("data-credits", () => {
       expect(hntBal.value.uiAmount).to.eq(startHntBal - 1);
     });
 
+    it("mints an exact amount of data credits", async () => {
+      const dcAta = await getAssociatedTokenAddress(dcMint, me);
+      const hntAta = await getAssociatedTokenAddress(hntMint, me);
+      const preDc = (await getAccount(provider.connection, dcAta)).amount;
+      const preHnt = (await getAccount(provider.connection, hntAta)).amount;
+
+      await program.methods
+        .mintDataCreditsV1({
+          hntAmount: null,
+          dcAmount: new BN(1000000),
+        })
+        .accounts({ dcMint })
+        .rpc({ skipPreflight: true });
+
+      const postDc = (await getAccount(provider.connection, dcAta)).amount;
+      const postHnt = (await getAccount(provider.connection, hntAta)).amount;
+      expect(Number(postDc - preDc)).to.eq(1000000);
+
+      const pythData = (await provider.connection.getAccountInfo(
+        new PublicKey("JBu1AL4obBcCMqKBBxhpWCNUt136ijcuMZLFvTP7iWdB")
+      ))!.data;
+      const price = parsePriceData(pythData);
+      // 1 HNT buys emaPrice * 10^5 DC
+      const expectedHnt = (1000000 / (price.emaPrice.value * 10 ** 5)) * 10 ** 8;
+      expect(Number(preHnt - postHnt)).to.be.within(
+        expectedHnt * 0.999,
+        expectedHnt * 1.001 + 1
+      );
+    });
+
     it("burns some data credits", async () => {
       await provider.connection.requestAirdrop(
         accountPayerKey()[0],
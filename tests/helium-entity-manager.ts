@@ -1,23 +1,45 @@
 import { Keypair as HeliumKeypair } from "@helium/crypto";
-import { init as initDataCredits } from "@helium/data-credits-sdk";
+import {
+  delegatedDataCreditsKey,
+  escrowAccountKey,
+  init as initDataCredits,
+} from "@helium/data-credits-sdk";
 import { init as initHeliumSubDaos } from "@helium/helium-sub-daos-sdk";
 import { Asset, AssetProof, toBN } from "@helium/spl-utils";
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { ComputeBudgetProgram, Keypair, PublicKey } from "@solana/web3.js";
+import {
+  ComputeBudgetProgram,
+  Keypair,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import {
+  getConcurrentMerkleTreeAccountSize,
+  SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+} from "@solana/spl-account-compression";
+import { getAccount, getAssociatedTokenAddress } from "@solana/spl-token";
 import chai from "chai";
 import {
   entityCreatorKey,
+  getIotInfo,
   init as initHeliumEntityManager,
+  iotInfoKey,
+  keyToAssetKey,
+  makerApprovalKey,
+  makerRevocationKey,
   onboardIotHotspot,
-  onboardMobileHotspot, updateIotMetadata, updateMobileMetadata
+  onboardMobileHotspot,
+  proofArgsAndAccounts,
+  updateIotMetadata,
+  updateMobileMetadata
 } from "../packages/helium-entity-manager-sdk/src";
 import { DataCredits } from "../target/types/data_credits";
 import { HeliumEntityManager } from "../target/types/helium_entity_manager";
 import { HeliumSubDaos } from "../target/types/helium_sub_daos";
 import { initTestDao, initTestSubdao } from "./utils/daos";
 import {
-  DC_FEE, ensureDCIdl, ensureHSDIdl, initTestDataCredits, initTestMaker, initTestRewardableEntityConfig
+  DC_FEE, ensureDCIdl, ensureHSDIdl, initTestDataCredits, initTestMaker, initTestRewardableEntityConfig, topUpMakerEscrow
 } from "./utils/fixtures";
 // @ts-ignore
 import bs58 from "bs58";
@@ -27,6 +49,8 @@ import animalHash from "angry-purple-tiger";
 
 import {
   computeCompressedNFTHash,
+  computeCreatorHash,
+  computeDataHash,
   getLeafAssetId,
   TokenProgramVersion,
   TokenStandard
@@ -46,6 +70,17 @@ describe("helium-entity-manager", () => {
 
   const provider = anchor.getProvider() as anchor.AnchorProvider;
   const me = provider.wallet.publicKey;
+  // Events emitted by the helium entity manager in a confirmed transaction
+  const hemEvents = async (sig: string) => {
+    const tx = await provider.connection.getTransaction(sig, {
+      commitment: "confirmed",
+    });
+    const parser = new anchor.EventParser(
+      hemProgram.programId,
+      hemProgram.coder
+    );
+    return Array.from(parser.parseLogs(tx!.meta!.logMessages!));
+  };
 
   let dao: PublicKey;
   let subDao: PublicKey;
@@ -167,6 +202,14 @@ describe("helium-entity-manager", () => {
 
       await initTestMaker(hemProgram, provider, rewardableEntityConfig, dao);
 
+      await topUpMakerEscrow(
+        hemProgram,
+        dcProgram,
+        makerConf.maker,
+        dcMint,
+        toBN(startDcBal, 8)
+      );
+
       await dcProgram.methods
         .mintDataCreditsV0({
           hntAmount: toBN(startDcBal, 8),
@@ -260,6 +303,7 @@ describe("helium-entity-manager", () => {
       await hemProgram.methods
         .issueEntityV0({
           entityKey: Buffer.from(bs58.decode(ecc)),
+          keySerialization: { b58: {} },
         })
         .preInstructions([
           ComputeBudgetProgram.setComputeUnitLimit({ units: 350000 }),
@@ -288,10 +332,84 @@ describe("helium-entity-manager", () => {
       await method.rpc({ skipPreflight: true });
       const { mobileInfo } = await method.pubkeys();
 
-      const mobileInfoAcc = await hemProgram.account.mobileHotspotInfoV0.fetch(
+      const mobileInfoAcc = await hemProgram.account.mobileHotspotInfoV1.fetch(
         mobileInfo!
       );
       expect(Boolean(mobileInfoAcc)).to.be.true;
+      // Legacy mobile configs don't charge location staking at onboarding
+      expect(mobileInfoAcc.numLocationAsserts).to.eq(0);
+    });
+
+    it("charges onboarding by device type", async () => {
+      await hemProgram.methods
+        .updateRewardableEntityConfigV0({
+          newAuthority: null,
+          settings: {
+            mobileConfigV1: {
+              feesByDevice: [
+                {
+                  deviceType: { wifiIndoor: {} },
+                  dcOnboardingFee: toBN(100000, 0),
+                  locationStakingFee: toBN(200000, 0),
+                },
+              ],
+            },
+          } as any,
+        })
+        .accounts({
+          rewardableEntityConfig,
+        })
+        .rpc({ skipPreflight: true });
+
+      await hemProgram.methods
+        .issueEntityV0({
+          entityKey: Buffer.from(bs58.decode(ecc)),
+          keySerialization: { b58: {} },
+        })
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: 350000 }),
+        ])
+        .accounts({
+          maker,
+          recipient: hotspotOwner.publicKey,
+          issuingAuthority: makerKeypair.publicKey,
+          dao,
+        })
+        .signers([makerKeypair])
+        .rpc({ skipPreflight: true });
+
+      const onboardAs = async (deviceType: any) =>
+        (
+          await onboardMobileHotspot({
+            program: hemProgram,
+            assetId: hotspot,
+            maker,
+            dao,
+            rewardableEntityConfig,
+            deviceType,
+            getAssetFn,
+            getAssetProofFn,
+          })
+        ).signers([makerKeypair, hotspotOwner]);
+
+      // No fees configured for outdoor wifi
+      await expect(
+        (await onboardAs({ wifiOutdoor: {} })).rpc()
+      ).to.be.rejected;
+
+      const escrow = await getAssociatedTokenAddress(dcMint, maker, true);
+      const preBalance = (await getAccount(provider.connection, escrow)).amount;
+      const method = await onboardAs({ wifiIndoor: {} });
+      await method.rpc({ skipPreflight: true });
+      const postBalance = (await getAccount(provider.connection, escrow))
+        .amount;
+      expect(Number(preBalance - postBalance)).to.eq(300000);
+
+      const mobileInfoAcc = await hemProgram.account.mobileHotspotInfoV1.fetch(
+        (await method.pubkeys()).mobileInfo!
+      );
+      expect(mobileInfoAcc.deviceType).to.deep.eq({ wifiIndoor: {} });
+      expect(mobileInfoAcc.isFullHotspot).to.be.true;
     });
 
     describe("with hotspot", () => {
@@ -299,6 +417,7 @@ describe("helium-entity-manager", () => {
         await hemProgram.methods
           .issueEntityV0({
             entityKey: Buffer.from(bs58.decode(ecc)),
+            keySerialization: { b58: {} },
           })
           .preInstructions([
             ComputeBudgetProgram.setComputeUnitLimit({ units: 350000 }),
@@ -349,12 +468,25 @@ describe("helium-entity-manager", () => {
         ).signers([hotspotOwner]);
 
         const info = (await method.pubkeys()).mobileInfo!;
-        await method.rpc({ skipPreflight: true });
+        const sig = await method.rpc({
+          skipPreflight: true,
+          commitment: "confirmed",
+        });
 
-        const storageAcc = await hemProgram.account.mobileHotspotInfoV0.fetch(
+        const storageAcc = await hemProgram.account.mobileHotspotInfoV1.fetch(
           info!
         );
         expect(storageAcc.location?.toNumber()).to.eq(location.toNumber());
+
+        const event = (await hemEvents(sig)).find(
+          (e) => e.name === "MobileInfoUpdatedV0"
+        )!;
+        expect(event.data.info.toBase58()).to.eq(info.toBase58());
+        expect(event.data.keyToAsset.toBase58()).to.eq(
+          keyToAssetKey(dao, ecc)[0].toBase58()
+        );
+        expect(bs58.encode(event.data.entityKey)).to.eq(ecc);
+        expect(event.data.location.toNumber()).to.eq(location.toNumber());
       });
     });
   });
@@ -396,6 +528,14 @@ describe("helium-entity-manager", () => {
 
       await initTestMaker(hemProgram, provider, rewardableEntityConfig, dao);
 
+      await topUpMakerEscrow(
+        hemProgram,
+        dcProgram,
+        makerConf.maker,
+        dcMint,
+        toBN(startDcBal, 8)
+      );
+
       await dcProgram.methods
         .mintDataCreditsV0({
           hntAmount: toBN(startDcBal, 8),
@@ -488,6 +628,7 @@ describe("helium-entity-manager", () => {
       await hemProgram.methods
         .issueEntityV0({
           entityKey: Buffer.from(bs58.decode(ecc)),
+          keySerialization: { b58: {} },
         })
         .preInstructions([
           ComputeBudgetProgram.setComputeUnitLimit({ units: 350000 }),
@@ -516,21 +657,442 @@ describe("helium-entity-manager", () => {
       await method.rpc({ skipPreflight: true });
       const { iotInfo } = await method.pubkeys();
 
-      const iotInfoAccount = await hemProgram.account.iotHotspotInfoV0.fetch(
+      const iotInfoAccount = await hemProgram.account.iotHotspotInfoV1.fetch(
         iotInfo!
       );
       expect(Boolean(iotInfoAccount)).to.be.true;
     });
 
+    it("issues utf8 keys and rejects invalid helium keys", async () => {
+      const issue = (entityKey: Buffer, keySerialization: any) =>
+        hemProgram.methods
+          .issueEntityV0({ entityKey, keySerialization })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 350000 }),
+          ])
+          .accounts({
+            maker,
+            dao,
+            recipient: hotspotOwner.publicKey,
+            issuingAuthority: makerKeypair.publicKey,
+          })
+          .signers([makerKeypair]);
+
+      // A b58 string stored as b58 bytes doesn't decode to a helium address
+      await expect(
+        issue(Buffer.from(ecc, "utf-8"), { b58: {} }).rpc()
+      ).to.be.rejected;
+      const badChecksum = Buffer.from(bs58.decode(ecc));
+      badChecksum[badChecksum.length - 1] ^= 1;
+      await expect(issue(badChecksum, { b58: {} }).rpc()).to.be.rejected;
+
+      // The same address can't be issued again under the other serialization
+      await issue(Buffer.from(bs58.decode(ecc)), { b58: {} }).rpc({
+        skipPreflight: true,
+      });
+      await expect(issue(Buffer.from(ecc, "utf-8"), { utf8: {} }).rpc()).to.be
+        .rejected;
+
+      const utf8Key = (await HeliumKeypair.makeRandom()).address.b58;
+      await issue(Buffer.from(utf8Key, "utf-8"), { utf8: {} }).rpc({
+        skipPreflight: true,
+      });
+      // Both serializations derive the key_to_asset from the decoded address
+      const keyToAsset = await hemProgram.account.keyToAssetV0.fetch(
+        keyToAssetKey(dao, utf8Key)[0]
+      );
+      expect(keyToAsset.keySerialization).to.deep.eq({ utf8: {} });
+      expect(Buffer.from(keyToAsset.entityKey).toString("utf-8")).to.eq(
+        utf8Key
+      );
+    });
+
+    it("issues a batch of entities and fails atomically on a duplicate", async () => {
+      const keys = await Promise.all(
+        [0, 1, 2].map(async () =>
+          Buffer.from(bs58.decode((await HeliumKeypair.makeRandom()).address.b58))
+        )
+      );
+      const issueBatch = (entityKeys: Buffer[]) =>
+        hemProgram.methods
+          .issueEntitiesV0({
+            entities: entityKeys.map((entityKey) => ({
+              entityKey,
+              keySerialization: { b58: {} },
+            })),
+          })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 1000000 }),
+          ])
+          .accounts({
+            maker,
+            dao,
+            issuingAuthority: makerKeypair.publicKey,
+          })
+          .remainingAccounts(
+            entityKeys.flatMap((entityKey) => [
+              {
+                pubkey: keyToAssetKey(dao, entityKey)[0],
+                isWritable: true,
+                isSigner: false,
+              },
+              {
+                pubkey: hotspotOwner.publicKey,
+                isWritable: false,
+                isSigner: false,
+              },
+            ])
+          )
+          .signers([makerKeypair]);
+
+      await issueBatch(keys.slice(0, 2)).rpc({ skipPreflight: true });
+      for (const key of keys.slice(0, 2)) {
+        const keyToAsset = await hemProgram.account.keyToAssetV0.fetch(
+          keyToAssetKey(dao, key)[0]
+        );
+        expect(Buffer.from(keyToAsset.entityKey).equals(key)).to.be.true;
+      }
+
+      // The new key isn't issued since its batch repeats an issued one
+      await expect(issueBatch([keys[2], keys[0]]).rpc()).to.be.rejected;
+      expect(
+        await provider.connection.getAccountInfo(keyToAssetKey(dao, keys[2])[0])
+      ).to.be.null;
+    });
+
+    it("rolls over to a queued tree once the current one is full", async () => {
+      const merkle = Keypair.generate();
+      const space = getConcurrentMerkleTreeAccountSize(3, 8);
+      const createMerkle = SystemProgram.createAccount({
+        fromPubkey: me,
+        newAccountPubkey: merkle.publicKey,
+        lamports: await provider.connection.getMinimumBalanceForRentExemption(
+          space
+        ),
+        space,
+        programId: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+      });
+      await hemProgram.methods
+        .addMakerTreeV0({
+          maxDepth: 3,
+          maxBufferSize: 8,
+        })
+        .preInstructions([createMerkle])
+        .accounts({
+          maker,
+          merkleTree: merkle.publicKey,
+          updateAuthority: makerKeypair.publicKey,
+        })
+        .signers([makerKeypair, merkle])
+        .rpc({ skipPreflight: true });
+
+      // Setting a tree on a maker that already has one queues it behind the others
+      const setMerkle = Keypair.generate();
+      await hemProgram.methods
+        .setMakerTreeV0({
+          maxDepth: 3,
+          maxBufferSize: 8,
+        })
+        .preInstructions([
+          SystemProgram.createAccount({
+            fromPubkey: me,
+            newAccountPubkey: setMerkle.publicKey,
+            lamports:
+              await provider.connection.getMinimumBalanceForRentExemption(
+                space
+              ),
+            space,
+            programId: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+          }),
+        ])
+        .accounts({
+          maker,
+          merkleTree: setMerkle.publicKey,
+          updateAuthority: makerKeypair.publicKey,
+        })
+        .signers([makerKeypair, setMerkle])
+        .rpc({ skipPreflight: true });
+
+      let makerAcc = await hemProgram.account.makerV0.fetch(maker);
+      const currentTree = makerAcc.merkleTree;
+      expect(makerAcc.merkleTrees.map((t) => t.toBase58())).to.deep.eq([
+        currentTree.toBase58(),
+        merkle.publicKey.toBase58(),
+        setMerkle.publicKey.toBase58(),
+      ]);
+
+      // Test trees have a depth of 3
+      for (let i = 0; i < 2 ** 3; i++) {
+        await hemProgram.methods
+          .issueEntityV0({
+            entityKey: Buffer.from(
+              bs58.decode((await HeliumKeypair.makeRandom()).address.b58)
+            ),
+            keySerialization: { b58: {} },
+          })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 350000 }),
+          ])
+          .accounts({
+            maker,
+            dao,
+            recipient: hotspotOwner.publicKey,
+            issuingAuthority: makerKeypair.publicKey,
+          })
+          .signers([makerKeypair])
+          .rpc({ skipPreflight: true });
+      }
+
+      makerAcc = await hemProgram.account.makerV0.fetch(maker);
+      expect(makerAcc.merkleTree.toBase58()).to.eq(merkle.publicKey.toBase58());
+    });
+
+    it("revokes a maker approval and blocks onboarding", async () => {
+      const makerApproval = makerApprovalKey(rewardableEntityConfig, maker)[0];
+      await hemProgram.methods
+        .revokeMakerV0()
+        .accounts({
+          refund: me,
+          rewardableEntityConfig,
+          maker,
+          makerApproval,
+        })
+        .rpc({ skipPreflight: true });
+
+      expect(await provider.connection.getAccountInfo(makerApproval)).to.be
+        .null;
+
+      await hemProgram.methods
+        .issueEntityV0({
+          entityKey: Buffer.from(bs58.decode(ecc)),
+          keySerialization: { b58: {} },
+        })
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: 350000 }),
+        ])
+        .accounts({
+          maker,
+          dao,
+          recipient: hotspotOwner.publicKey,
+          issuingAuthority: makerKeypair.publicKey,
+        })
+        .signers([makerKeypair])
+        .rpc({ skipPreflight: true });
+
+      const method = (
+        await onboardIotHotspot({
+          program: hemProgram,
+          assetId: hotspot,
+          maker,
+          dao,
+          rewardableEntityConfig,
+          getAssetFn,
+          getAssetProofFn,
+        })
+      ).signers([makerKeypair, hotspotOwner]);
+
+      await expect(method.rpc()).to.be.rejected;
+    });
+
+    it("delegates the maker escrow to a router and then refuses to onboard", async () => {
+      const makerEscrow = await getAssociatedTokenAddress(dcMint, maker, true);
+      const escrowBalance = (await getAccount(provider.connection, makerEscrow))
+        .amount;
+      const routerKey = (await HeliumKeypair.makeRandom()).address.b58;
+      const delegatedDataCredits = delegatedDataCreditsKey(subDao, routerKey)[0];
+      const escrowAccount = escrowAccountKey(delegatedDataCredits)[0];
+
+      await hemProgram.methods
+        .delegateMakerEscrowV0({
+          amount: new BN(escrowBalance.toString()),
+          routerKey,
+        })
+        .accounts({
+          maker,
+          updateAuthority: makerKeypair.publicKey,
+          dao,
+          subDao,
+          dcMint,
+          delegatedDataCredits,
+          escrowAccount,
+        })
+        .signers([makerKeypair])
+        .rpc({ skipPreflight: true });
+
+      expect(
+        Number((await getAccount(provider.connection, makerEscrow)).amount)
+      ).to.eq(0);
+      expect(
+        (await getAccount(provider.connection, escrowAccount)).amount
+      ).to.eq(escrowBalance);
+
+      await hemProgram.methods
+        .issueEntityV0({
+          entityKey: Buffer.from(bs58.decode(ecc)),
+          keySerialization: { b58: {} },
+        })
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: 350000 }),
+        ])
+        .accounts({
+          maker,
+          dao,
+          recipient: hotspotOwner.publicKey,
+          issuingAuthority: makerKeypair.publicKey,
+        })
+        .signers([makerKeypair])
+        .rpc({ skipPreflight: true });
+
+      const method = (
+        await onboardIotHotspot({
+          program: hemProgram,
+          assetId: hotspot,
+          maker,
+          dao,
+          rewardableEntityConfig,
+          getAssetFn,
+          getAssetProofFn,
+        })
+      ).signers([makerKeypair, hotspotOwner]);
+
+      await expect(method.rpc()).to.be.rejected;
+    });
+
+    it("requires a maker bond that can be slashed and later withdrawn", async () => {
+      const { rewardableEntityConfig: bondedConfig } =
+        await initTestRewardableEntityConfig(hemProgram, subDao);
+      await hemProgram.methods
+        .updateRewardableEntityConfigV0({
+          newAuthority: null,
+          settings: null,
+          makerBond: toBN(10, 8),
+          makerBondCooldownSeconds: new BN(0),
+        })
+        .accounts({ rewardableEntityConfig: bondedConfig })
+        .rpc({ skipPreflight: true });
+
+      const approve = () =>
+        hemProgram.methods
+          .approveMakerV0()
+          .accounts({ rewardableEntityConfig: bondedConfig, maker })
+          .rpc({ skipPreflight: true });
+      await expect(approve()).to.be.rejected;
+
+      const { hntMint } = await hsdProgram.account.daoV0.fetch(dao);
+      const hntAta = await getAssociatedTokenAddress(hntMint, me);
+      const makerBond = await getAssociatedTokenAddress(hntMint, maker, true);
+      await hemProgram.methods
+        .bondMakerV0({ amount: toBN(10, 8) })
+        .accounts({ maker, source: hntAta })
+        .rpc({ skipPreflight: true });
+      await approve();
+
+      // The bond can't be pulled while the maker is approved
+      const withdraw = (amount: BN) =>
+        hemProgram.methods
+          .withdrawMakerBondV0({ amount })
+          .accounts({
+            maker,
+            updateAuthority: makerKeypair.publicKey,
+            destination: hntAta,
+          })
+          .signers([makerKeypair])
+          .rpc({ skipPreflight: true });
+      await expect(withdraw(toBN(1, 8))).to.be.rejected;
+
+      const slash = (
+        amount: BN,
+        makerApproval: PublicKey | null,
+        makerRevocation: PublicKey | null
+      ) =>
+        hemProgram.methods
+          .slashMakerBondV0({ amount })
+          .accounts({
+            rewardableEntityConfig: bondedConfig,
+            subDao,
+            dao,
+            daoTreasury: hntAta,
+            maker,
+            makerApproval,
+            makerRevocation,
+          })
+          .rpc({ skipPreflight: true });
+      const bondedApproval = makerApprovalKey(bondedConfig, maker)[0];
+      const bondedRevocation = makerRevocationKey(bondedConfig, maker)[0];
+
+      const preTreasury = (await getAccount(provider.connection, hntAta))
+        .amount;
+      await slash(toBN(4, 8), bondedApproval, null);
+      expect(
+        (await getAccount(provider.connection, hntAta)).amount - preTreasury
+      ).to.eq(BigInt(toBN(4, 8).toString()));
+      // Only the part of the bond held for this config can be slashed
+      await expect(slash(toBN(7, 8), bondedApproval, null)).to.be.rejected;
+      // The other config's approval holds none of the bond
+      await expect(
+        hemProgram.methods
+          .slashMakerBondV0({ amount: toBN(1, 8) })
+          .accounts({
+            rewardableEntityConfig,
+            subDao,
+            dao,
+            daoTreasury: hntAta,
+            maker,
+            makerApproval: makerApprovalKey(rewardableEntityConfig, maker)[0],
+            makerRevocation: null,
+          })
+          .rpc({ skipPreflight: true })
+      ).to.be.rejected;
+
+      // Every approval has to be revoked before the bond unlocks
+      for (const config of [bondedConfig, rewardableEntityConfig]) {
+        await hemProgram.methods
+          .revokeMakerV0()
+          .accounts({
+            refund: me,
+            rewardableEntityConfig: config,
+            maker,
+            makerApproval: makerApprovalKey(config, maker)[0],
+          })
+          .rpc({ skipPreflight: true });
+      }
+
+      // The revocation cooldown is zero, so the bond is no longer slashable
+      await expect(slash(toBN(1, 8), null, bondedRevocation)).to.be.rejected;
+      expect(
+        (await hemProgram.account.makerRevocationV0.fetch(bondedRevocation))
+          .bondAmount.toString()
+      ).to.eq(toBN(6, 8).toString());
+
+      // Makers from before bonding may have approvals that were never counted. Their bond
+      // stays locked until the dao authority confirms those are gone.
+      const setApprovalsTracked = (approvalsTracked: boolean) =>
+        hemProgram.methods
+          .setMakerApprovalsTrackedV0({ approvalsTracked })
+          .accounts({ dao, maker })
+          .rpc({ skipPreflight: true });
+      await setApprovalsTracked(false);
+      await expect(withdraw(toBN(6, 8))).to.be.rejectedWith(
+        "UntrackedMakerApprovals"
+      );
+      await setApprovalsTracked(true);
+
+      await withdraw(toBN(6, 8));
+      expect(
+        Number((await getAccount(provider.connection, makerBond)).amount)
+      ).to.eq(0);
+    });
+
     it("updates entity config", async () => {
       const { rewardableEntityConfig } = await initTestRewardableEntityConfig(
         hemProgram,
         subDao
       );
 
+      const newAuthority = Keypair.generate();
       await hemProgram.methods
         .updateRewardableEntityConfigV0({
-          newAuthority: PublicKey.default,
+          newAuthority: newAuthority.publicKey,
           settings: null
         })
         .accounts({
@@ -538,10 +1100,34 @@ describe("helium-entity-manager", () => {
         })
         .rpc();
 
-      const acc = await hemProgram.account.rewardableEntityConfigV0.fetch(
+      // The authority only changes hands once the nominee accepts
+      let acc = await hemProgram.account.rewardableEntityConfigV0.fetch(
         rewardableEntityConfig
       );
-      expect(acc.authority.toBase58()).to.equal(PublicKey.default.toBase58());
+      expect(acc.authority.toBase58()).to.equal(me.toBase58());
+      expect(acc.pendingAuthority?.toBase58()).to.equal(
+        newAuthority.publicKey.toBase58()
+      );
+
+      const accept = (signer: Keypair) =>
+        hemProgram.methods
+          .acceptRewardableEntityConfigAuthorityV0()
+          .accounts({
+            rewardableEntityConfig,
+            newAuthority: signer.publicKey,
+          })
+          .signers([signer])
+          .rpc();
+      await expect(accept(Keypair.generate())).to.be.rejected;
+      await accept(newAuthority);
+
+      acc = await hemProgram.account.rewardableEntityConfigV0.fetch(
+        rewardableEntityConfig
+      );
+      expect(acc.authority.toBase58()).to.equal(
+        newAuthority.publicKey.toBase58()
+      );
+      expect(acc.pendingAuthority).to.be.null;
     });
 
     describe("with hotspot", () => {
@@ -549,6 +1135,7 @@ describe("helium-entity-manager", () => {
         await hemProgram.methods
           .issueEntityV0({
             entityKey: Buffer.from(bs58.decode(ecc)),
+            keySerialization: { b58: {} },
           })
           .preInstructions([
             ComputeBudgetProgram.setComputeUnitLimit({ units: 350000 }),
@@ -603,20 +1190,122 @@ describe("helium-entity-manager", () => {
         ).signers([hotspotOwner]);
 
         const info = (await method.pubkeys()).iotInfo!;
-        await method.rpc({ skipPreflight: true });
+        const sig = await method.rpc({
+          skipPreflight: true,
+          commitment: "confirmed",
+        });
 
-        const storageAcc = await hemProgram.account.iotHotspotInfoV0.fetch(
+        const storageAcc = await hemProgram.account.iotHotspotInfoV1.fetch(
           info!
         );
         expect(storageAcc.location?.toNumber()).to.eq(location.toNumber());
         expect(storageAcc.elevation).to.eq(elevation);
         expect(storageAcc.gain).to.eq(gain);
+        expect(storageAcc.lastAssertedTs?.toNumber()).to.be.greaterThan(0);
+
+        const event = (await hemEvents(sig)).find(
+          (e) => e.name === "IotInfoUpdatedV0"
+        )!;
+        expect(event.data.info.toBase58()).to.eq(info.toBase58());
+        expect(event.data.keyToAsset.toBase58()).to.eq(
+          keyToAssetKey(dao, ecc)[0].toBase58()
+        );
+        expect(bs58.encode(event.data.entityKey)).to.eq(ecc);
+        expect(event.data.location.toNumber()).to.eq(location.toNumber());
+
+        expect((await getIotInfo(hemProgram, info))?.version).to.eq(1);
+        // Migrating a V1 info leaves it as is
+        await hemProgram.methods
+          .migrateIotInfoV0()
+          .accounts({ iotInfo: info })
+          .rpc({ skipPreflight: true });
+        const migrated = await hemProgram.account.iotHotspotInfoV1.fetch(info);
+        expect(migrated.location?.toNumber()).to.eq(location.toNumber());
+      });
+
+      it("only resets the info for a new owner", async () => {
+        const { args, accounts, remainingAccounts } =
+          await proofArgsAndAccounts({
+            connection: provider.connection,
+            assetId: hotspot,
+            getAssetFn,
+            getAssetProofFn,
+          });
+        const info = iotInfoKey(rewardableEntityConfig, ecc)[0];
+        const reset = (owner: Keypair) =>
+          hemProgram.methods
+            .resetIotInfoV0({
+              dataHash: Array.from(computeDataHash(metadata)),
+              creatorHash: Array.from(computeCreatorHash(metadata.creators)),
+              root: args.root,
+              index: 0,
+            })
+            .accounts({
+              ...accounts,
+              hotspotOwner: owner.publicKey,
+              iotInfo: info,
+            })
+            .remainingAccounts(remainingAccounts)
+            .signers([owner]);
+
+        // The onboarding owner can't wipe its own location
+        await expect(reset(hotspotOwner).rpc()).to.be.rejected;
+        // Someone who doesn't hold the hotspot can't rebuild its leaf
+        await expect(reset(Keypair.generate()).rpc()).to.be.rejected;
+
+        const infoAcc = await hemProgram.account.iotHotspotInfoV1.fetch(info);
+        expect(infoAcc.owner?.toBase58()).to.eq(
+          hotspotOwner.publicKey.toBase58()
+        );
+        expect(infoAcc.transferred).to.be.false;
+      });
+
+      it("pays the location fee with HNT", async () => {
+        const location = new BN(2000);
+        const { hntMint } = await hsdProgram.account.daoV0.fetch(dao);
+        const hntAta = await getAssociatedTokenAddress(hntMint, me);
+        const dcAta = await getAssociatedTokenAddress(dcMint, me);
+        const updateWithHnt = async (maxHnt: BN) =>
+          (
+            await updateIotMetadata({
+              program: hemProgram,
+              assetId: hotspot,
+              rewardableEntityConfig,
+              location,
+              elevation: null,
+              gain: null,
+              maxHnt,
+              getAssetFn,
+              getAssetProofFn,
+            })
+          ).signers([hotspotOwner]);
+
+        // Buying the fee takes more than one unit of HNT
+        await expect((await updateWithHnt(new BN(1))).rpc()).to.be.rejected;
+
+        const preHnt = (await getAccount(provider.connection, hntAta)).amount;
+        const preDc = (await getAccount(provider.connection, dcAta)).amount;
+        const method = await updateWithHnt(toBN(1000, 8));
+        await method.rpc({ skipPreflight: true });
+
+        // Exactly the fee is minted and then burned
+        const postDc = (await getAccount(provider.connection, dcAta)).amount;
+        const postHnt = (await getAccount(provider.connection, hntAta)).amount;
+        expect(postDc).to.eq(preDc);
+        expect(postHnt < preHnt).to.be.true;
+
+        const info = ((await method.pubkeys()) as any).update.iotInfo;
+        const storageAcc = await hemProgram.account.iotHotspotInfoV1.fetch(
+          info
+        );
+        expect(storageAcc.location?.toNumber()).to.eq(location.toNumber());
       });
 
       it("updates maker", async () => {
+        const newUpdateAuthority = Keypair.generate();
         await hemProgram.methods
           .updateMakerV0({
-            updateAuthority: PublicKey.default,
+            updateAuthority: newUpdateAuthority.publicKey,
             issuingAuthority: PublicKey.default,
           })
           .accounts({
@@ -626,13 +1315,27 @@ describe("helium-entity-manager", () => {
           .signers([makerKeypair])
           .rpc();
 
-        const acc = await hemProgram.account.makerV0.fetch(maker);
+        let acc = await hemProgram.account.makerV0.fetch(maker);
         expect(acc.issuingAuthority.toBase58()).to.eq(
           PublicKey.default.toBase58()
         );
         expect(acc.updateAuthority.toBase58()).to.eq(
-          PublicKey.default.toBase58()
+          makerKeypair.publicKey.toBase58()
+        );
+
+        await hemProgram.methods
+          .acceptMakerUpdateAuthorityV0()
+          .accounts({
+            maker,
+            newUpdateAuthority: newUpdateAuthority.publicKey,
+          })
+          .signers([newUpdateAuthority])
+          .rpc();
+        acc = await hemProgram.account.makerV0.fetch(maker);
+        expect(acc.updateAuthority.toBase58()).to.eq(
+          newUpdateAuthority.publicKey.toBase58()
         );
+        expect(acc.pendingUpdateAuthority).to.be.null;
       });
 
       it("doesn't assert gain outside range", async () => {
@@ -668,6 +1371,112 @@ describe("helium-entity-manager", () => {
         // @ts-ignore
         expect(method2.rpc()).to.be.rejected;
       });
+
+      it("enforces elevation and regional gain limits", async () => {
+        await hemProgram.methods
+          .updateRewardableEntityConfigV0({
+            newAuthority: null,
+            settings: {
+              iotConfigV1: {
+                minGain: 10,
+                maxGain: 150,
+                minElevation: -100,
+                maxElevation: 1000,
+                fullLocationStakingFee: toBN(1000000, 0),
+                dataonlyLocationStakingFee: toBN(500000, 0),
+                regionGainLimits: [
+                  { region: { eu868: {} }, minGain: 10, maxGain: 60 },
+                ],
+              } as any,
+            },
+          })
+          .accounts({
+            rewardableEntityConfig,
+          })
+          .rpc({ skipPreflight: true });
+
+        const updateWith = async (args: {
+          elevation: number | null;
+          gain: number | null;
+          region: any;
+        }) =>
+          (
+            await updateIotMetadata({
+              program: hemProgram,
+              assetId: hotspot,
+              location: null,
+              rewardableEntityConfig,
+              getAssetFn,
+              getAssetProofFn,
+              ...args,
+            })
+          ).signers([hotspotOwner]);
+
+        await expect(
+          (
+            await updateWith({ elevation: 2000, gain: null, region: null })
+          ).rpc()
+        ).to.be.rejected;
+        await expect(
+          (
+            await updateWith({ elevation: null, gain: 100, region: { eu868: {} } })
+          ).rpc()
+        ).to.be.rejected;
+
+        const method = await updateWith({
+          elevation: 100,
+          gain: 100,
+          region: { us915: {} },
+        });
+        const info = (await method.pubkeys()).iotInfo!;
+        await method.rpc({ skipPreflight: true });
+
+        const storageAcc = await hemProgram.account.iotHotspotInfoV1.fetch(
+          info
+        );
+        expect(storageAcc.elevation).to.eq(100);
+        expect(storageAcc.gain).to.eq(100);
+        expect(storageAcc.region).to.deep.eq({ us915: {} });
+
+        // Moving to a region with a lower gain cap re-checks the stored gain
+        await expect(
+          (
+            await updateWith({ elevation: null, gain: null, region: { eu868: {} } })
+          ).rpc()
+        ).to.be.rejected;
+      });
+
+      it("sets and clears the display name", async () => {
+        const updateName = async (displayName: string) =>
+          (
+            await updateIotMetadata({
+              program: hemProgram,
+              assetId: hotspot,
+              location: null,
+              elevation: null,
+              gain: null,
+              displayName,
+              rewardableEntityConfig,
+              getAssetFn,
+              getAssetProofFn,
+            })
+          ).signers([hotspotOwner]);
+
+        await expect(
+          (await updateName(" not trimmed")).rpc()
+        ).to.be.rejected;
+
+        const method = await updateName("Rooftop Hotspot");
+        const info = (await method.pubkeys()).iotInfo!;
+        await method.rpc({ skipPreflight: true });
+
+        let storageAcc = await hemProgram.account.iotHotspotInfoV1.fetch(info);
+        expect(storageAcc.displayName).to.eq("Rooftop Hotspot");
+
+        await (await updateName("")).rpc({ skipPreflight: true });
+        storageAcc = await hemProgram.account.iotHotspotInfoV1.fetch(info);
+        expect(storageAcc.displayName).to.be.null;
+      });
     });
   });
 });
@@ -28,9 +28,11 @@ import chaiAsPromised from "chai-as-promised";
 import { init as dcInit } from "../packages/data-credits-sdk/src";
 import { init as issuerInit } from "../packages/helium-entity-manager-sdk/src";
 import {
+  crankVaultKey,
   currentEpoch,
   heliumSubDaosResolvers,
   subDaoEpochInfoKey,
+  threadKey,
 } from "../packages/helium-sub-daos-sdk/src";
 import { init as vsrInit } from "../packages/voter-stake-registry-sdk/src";
 import { DataCredits } from "../target/types/data_credits";
@@ -38,6 +40,7 @@ import { HeliumEntityManager } from "../target/types/helium_entity_manager";
 import { burnDataCredits } from "./data-credits";
 import { initTestDao, initTestSubdao } from "./utils/daos";
 import {
+  DC_FEE,
   ensureDCIdl,
   ensureHSDIdl,
   ensureVSRIdl,
@@ -129,6 +132,72 @@ describe("helium-sub-daos", () => {
     const account = await program.account.daoV0.fetch(dao!);
     expect(account.authority.toBase58()).eq(me.toBase58());
     expect(account.hntMint.toBase58()).eq(mint.toBase58());
+    expect(account.epochLength.toNumber()).eq(EPOCH_LENGTH);
+    expect(account.epochOffset.toNumber()).eq(0);
+  });
+
+  it("initializes a dao with a custom epoch length", async () => {
+    const { dao } = await initTestDao(
+      program,
+      provider,
+      EPOCH_REWARDS,
+      provider.wallet.publicKey,
+      undefined,
+      undefined,
+      undefined,
+      { epochLength: 60 * 60, epochOffset: 60 * 10 }
+    );
+    const account = await program.account.daoV0.fetch(dao!);
+    expect(account.epochLength.toNumber()).eq(60 * 60);
+    expect(account.epochOffset.toNumber()).eq(60 * 10);
+
+    // Subdaos and their end of epoch threads follow the dao's epochs
+    await initTestSubdao(program, provider, provider.wallet.publicKey, dao);
+
+    // Epochs must be expressible as a cron
+    await expect(
+      initTestDao(
+        program,
+        provider,
+        EPOCH_REWARDS,
+        provider.wallet.publicKey,
+        undefined,
+        undefined,
+        undefined,
+        { epochLength: 90 * 60, epochOffset: 0 }
+      )
+    ).to.eventually.be.rejectedWith("InvalidEpochLength");
+  });
+
+  it("initializes a dao and subdao without clockwork threads", async () => {
+    const { dao } = await initTestDao(
+      program,
+      provider,
+      EPOCH_REWARDS,
+      provider.wallet.publicKey,
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      false
+    );
+    const { subDao } = await initTestSubdao(
+      program,
+      provider,
+      provider.wallet.publicKey,
+      dao,
+      undefined,
+      undefined,
+      false
+    );
+
+    for (const thread of [
+      threadKey(dao, "issue_hst")[0],
+      threadKey(subDao, "calculate")[0],
+      threadKey(subDao, "issue")[0],
+    ]) {
+      expect(await provider.connection.getAccountInfo(thread)).to.be.null;
+    }
   });
 
   it("initializes a subdao", async () => {
@@ -139,7 +208,14 @@ describe("helium-sub-daos", () => {
       provider.wallet.publicKey
     );
     const { subDao, treasury, mint, treasuryCircuitBreaker } =
-      await initTestSubdao(program, provider, provider.wallet.publicKey, dao);
+      await initTestSubdao(
+        program,
+        provider,
+        provider.wallet.publicKey,
+        dao,
+        undefined,
+        [{ startUnixTime: new BN(0), percent: 10 }]
+      );
 
     const account = await program.account.subDaoV0.fetch(subDao!);
     const breaker =
@@ -153,6 +229,8 @@ describe("helium-sub-daos", () => {
     expect(account.authority.toBase58()).eq(me.toBase58());
     expect(account.treasury.toBase58()).eq(treasury.toBase58());
     expect(account.dntMint.toBase58()).eq(mint.toBase58());
+    expect(account.delegatorRewardsPercentSchedule.length).eq(1);
+    expect(account.delegatorRewardsPercentSchedule[0].percent).eq(10);
   });
 
   describe("with dao and subdao", () => {
@@ -242,14 +320,99 @@ describe("helium-sub-daos", () => {
         authority: newAuth,
         emissionSchedule: null,
         hstEmissionSchedule: null,
+        entityMetadataUrl: "https://entities.nft.helium.io",
+        utilityScoreConfig: null,
+        crankBountyLamports: null,
       }).accounts({
         dao,
       }).rpc({skipPreflight: true});
 
       const daoAcc = await program.account.daoV0.fetch(dao);
       expect(daoAcc.authority.toString()).to.eq(newAuth.toString());
+      expect(daoAcc.entityMetadataUrl).to.eq("https://entities.nft.helium.io");
     })
 
+    it("rejects invalid emission schedule updates", async () => {
+      const updateEmissionSchedule = (
+        emissionSchedule: { startUnixTime: BN; emissionsPerEpoch: BN }[]
+      ) =>
+        program.methods
+          .updateDaoV0({
+            authority: null,
+            emissionSchedule,
+            hstEmissionSchedule: null,
+            entityMetadataUrl: null,
+            utilityScoreConfig: null,
+            crankBountyLamports: null,
+          })
+          .accounts({
+            dao,
+          })
+          .rpc();
+      const future = new BN(Number(await getUnixTimestamp(provider)) + 1000);
+
+      await expect(
+        updateEmissionSchedule([
+          { startUnixTime: future, emissionsPerEpoch: new BN(1) },
+          { startUnixTime: new BN(0), emissionsPerEpoch: new BN(1) },
+        ])
+      ).to.eventually.be.rejectedWith("ScheduleNotSorted");
+      // The entry starting at 0 is already in effect
+      await expect(
+        updateEmissionSchedule([
+          { startUnixTime: new BN(0), emissionsPerEpoch: new BN(1) },
+        ])
+      ).to.eventually.be.rejectedWith("SchedulePastEntryChanged");
+
+      await updateEmissionSchedule([
+        {
+          startUnixTime: new BN(0),
+          emissionsPerEpoch: new BN(EPOCH_REWARDS),
+        },
+        { startUnixTime: future, emissionsPerEpoch: new BN(1) },
+      ]);
+      const daoAcc = await program.account.daoV0.fetch(dao);
+      expect(daoAcc.emissionSchedule.length).to.eq(2);
+    });
+
+    it("migrates existing schedules", async () => {
+      // Schedules may start in the future
+      const future = new BN(Number(await getUnixTimestamp(provider)) + 1000);
+      await program.methods
+        .updateSubDaoV0({
+          authority: null,
+          dcBurnAuthority: null,
+          emissionSchedule: null,
+          onboardingDcFee: null,
+          activeDeviceAggregator: null,
+          delegatorRewardsPercentSchedule: [
+            { startUnixTime: future, percent: 10 },
+          ],
+          activeDeviceAuthority: null,
+        })
+        .accounts({ subDao })
+        .rpc({ skipPreflight: true });
+
+      // Already valid schedules are left as they are
+      await program.methods
+        .migrateDaoSchedulesV0()
+        .accounts({ dao })
+        .rpc({ skipPreflight: true });
+      await program.methods
+        .migrateSubDaoSchedulesV0()
+        .accounts({ subDao })
+        .rpc({ skipPreflight: true });
+
+      const daoAcc = await program.account.daoV0.fetch(dao);
+      expect(daoAcc.emissionSchedule.length).to.eq(1);
+      expect(daoAcc.emissionSchedule[0].startUnixTime.toNumber()).to.eq(0);
+      const subDaoAcc = await program.account.subDaoV0.fetch(subDao);
+      expect(subDaoAcc.emissionSchedule.length).to.eq(1);
+      expect(
+        subDaoAcc.delegatorRewardsPercentSchedule[0].startUnixTime.toNumber()
+      ).to.eq(future.toNumber());
+    });
+
     it("updates the subdao", async () => {
       const newAuth = Keypair.generate().publicKey;
       await program.methods
@@ -259,6 +422,8 @@ describe("helium-sub-daos", () => {
           emissionSchedule: null,
           onboardingDcFee: null,
           activeDeviceAggregator: null,
+          delegatorRewardsPercentSchedule: null,
+          activeDeviceAuthority: null,
         })
         .accounts({
           subDao,
@@ -297,6 +462,201 @@ describe("helium-sub-daos", () => {
       expect(epochInfo.dcBurned.toNumber()).eq(toBN(10, 0).toNumber());
     });
 
+    it("uses the attested active device count for the utility score", async () => {
+      await program.methods
+        .updateSubDaoV0({
+          authority: null,
+          dcBurnAuthority: null,
+          emissionSchedule: null,
+          onboardingDcFee: null,
+          activeDeviceAggregator: null,
+          delegatorRewardsPercentSchedule: null,
+          activeDeviceAuthority: me,
+        })
+        .accounts({
+          subDao,
+        })
+        .rpc({ skipPreflight: true });
+      const epoch = currentEpoch(
+        new BN(Number(await getUnixTimestamp(provider)))
+      );
+      const subDaoEpochInfo = subDaoEpochInfoKey(
+        subDao,
+        Number(await getUnixTimestamp(provider))
+      )[0];
+
+      // Doesn't fall back to the aggregator once an authority is set
+      await expect(
+        program.methods
+          .calculateUtilityScoreV0({
+            epoch,
+          })
+          .preInstructions([
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 }),
+          ])
+          .accounts({
+            subDao,
+            dao,
+          })
+          .rpc()
+      ).to.eventually.be.rejectedWith("ActiveDevicesNotTracked");
+
+      const trackActiveDevices = () =>
+        program.methods
+          .trackActiveDevicesV0({
+            epoch,
+            activeDevices: new BN(100),
+          })
+          .accounts({
+            subDao,
+            dao,
+            subDaoEpochInfo,
+          })
+          .rpc();
+      // Only ended epochs can be attested
+      await expect(trackActiveDevices()).to.eventually.be.rejectedWith(
+        "InvalidActiveDeviceEpoch"
+      );
+      await vsrProgram.methods
+        .setTimeOffsetV0(new BN(1 * 60 * 60 * 24))
+        .accounts({ registrar })
+        .rpc({ skipPreflight: true });
+      await trackActiveDevices();
+      expect(
+        (
+          await program.account.subDaoEpochInfoV0.fetch(subDaoEpochInfo)
+        ).activeDevices!.toNumber()
+      ).eq(100);
+
+      await program.methods
+        .calculateUtilityScoreV0({
+          epoch,
+        })
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 }),
+        ])
+        .accounts({
+          subDao,
+          dao,
+          subDaoEpochInfo,
+        })
+        .rpc({ skipPreflight: true });
+
+      // No veHNT or dc burned, so the score is just A = sqrt(devices * fee in dollars)
+      const { utilityScore } = await program.account.subDaoEpochInfoV0.fetch(
+        subDaoEpochInfo
+      );
+      expect(utilityScore!.toNumber() / 1e12).to.be.closeTo(
+        Math.sqrt((100 * DC_FEE) / 100000),
+        0.0001
+      );
+
+      // Counts are locked in once the score is calculated
+      await expect(
+        program.methods
+          .trackActiveDevicesV0({
+            epoch,
+            activeDevices: new BN(200),
+          })
+          .accounts({
+            subDao,
+            dao,
+            subDaoEpochInfo,
+          })
+          .rpc()
+      ).to.eventually.be.rejectedWith("UtilityScoreAlreadyCalculated");
+    });
+
+    it("scores with the dao's utility score config", async () => {
+      const factor = (
+        divisor: number,
+        exponentNumerator: number,
+        exponentDenominator: number
+      ) => ({
+        divisor: new BN(divisor),
+        exponentNumerator,
+        exponentDenominator,
+        floor: new BN(1),
+      });
+      // A is linear in the device fees instead of the default square root
+      await program.methods
+        .updateDaoV0({
+          authority: null,
+          emissionSchedule: null,
+          hstEmissionSchedule: null,
+          entityMetadataUrl: null,
+          utilityScoreConfig: {
+            v0: {
+              vehnt: factor(100_000_000, 1, 1),
+              dcBurned: factor(100_000, 1, 4),
+              devices: factor(100_000, 1, 1),
+            },
+          },
+          crankBountyLamports: null,
+        })
+        .accounts({ dao })
+        .rpc({ skipPreflight: true });
+      await program.methods
+        .updateSubDaoV0({
+          authority: null,
+          dcBurnAuthority: null,
+          emissionSchedule: null,
+          onboardingDcFee: null,
+          activeDeviceAggregator: null,
+          delegatorRewardsPercentSchedule: null,
+          activeDeviceAuthority: me,
+        })
+        .accounts({
+          subDao,
+        })
+        .rpc({ skipPreflight: true });
+
+      const epoch = currentEpoch(
+        new BN(Number(await getUnixTimestamp(provider)))
+      );
+      const subDaoEpochInfo = subDaoEpochInfoKey(
+        subDao,
+        Number(await getUnixTimestamp(provider))
+      )[0];
+      await vsrProgram.methods
+        .setTimeOffsetV0(new BN(1 * 60 * 60 * 24))
+        .accounts({ registrar })
+        .rpc({ skipPreflight: true });
+      await program.methods
+        .trackActiveDevicesV0({
+          epoch,
+          activeDevices: new BN(100),
+        })
+        .accounts({
+          subDao,
+          dao,
+          subDaoEpochInfo,
+        })
+        .rpc({ skipPreflight: true });
+      await program.methods
+        .calculateUtilityScoreV0({
+          epoch,
+        })
+        .preInstructions([
+          ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 }),
+        ])
+        .accounts({
+          subDao,
+          dao,
+          subDaoEpochInfo,
+        })
+        .rpc({ skipPreflight: true });
+
+      // With the default config this would be sqrt(devices * fee in dollars)
+      const { utilityScore } = await program.account.subDaoEpochInfoV0.fetch(
+        subDaoEpochInfo
+      );
+      expect(utilityScore!.toNumber() / 1e12).to.be.closeTo(
+        (100 * DC_FEE) / 100000,
+        0.0001
+      );
+    });
+
     describe("with position", () => {
       before(() => {
         genesisVotePowerMultiplierExpirationTs = 1;
@@ -583,6 +943,76 @@ describe("helium-sub-daos", () => {
             );
           });
 
+          it("catches up vehnt after skipped epochs", async () => {
+            const lastEpoch = currentEpoch(
+              new BN(Number(await getUnixTimestamp(provider)))
+            );
+            await vsrProgram.methods
+              .setTimeOffsetV0(new BN(3 * EPOCH_LENGTH))
+              .accounts({ registrar })
+              .rpc({ skipPreflight: true });
+
+            const epochs = [1, 2, 3].map((i) => lastEpoch.add(new BN(i)));
+            const epochInfos = epochs.map(
+              (epoch) =>
+                subDaoEpochInfoKey(subDao, epoch.toNumber() * EPOCH_LENGTH)[0]
+            );
+            await program.methods
+              .catchUpVehntV0()
+              .accounts({
+                subDao,
+                dao,
+              })
+              .remainingAccounts(
+                epochInfos.map((pubkey) => ({
+                  pubkey,
+                  isWritable: true,
+                  isSigner: false,
+                }))
+              )
+              .rpc({ skipPreflight: true });
+
+            const subDaoAcc = await program.account.subDaoV0.fetch(subDao);
+            expect(subDaoAcc.vehntLastCalculatedTs.toNumber()).eq(
+              epochs[2].toNumber() * EPOCH_LENGTH
+            );
+            let prevVehnt: BN | undefined;
+            for (const [i, epochInfo] of epochInfos.entries()) {
+              const info = await program.account.subDaoEpochInfoV0.fetch(
+                epochInfo
+              );
+              expect(info.epoch.toNumber()).eq(epochs[i].toNumber());
+              expect(info.initialized).to.be.true;
+              expect(info.vehntAtEpochStart.toNumber()).to.be.gt(0);
+              // vehnt only decays
+              if (prevVehnt) {
+                expect(info.vehntAtEpochStart.lte(prevVehnt)).to.be.true;
+              }
+              prevVehnt = info.vehntAtEpochStart;
+            }
+
+            // Already caught up to the current epoch
+            await expect(
+              program.methods
+                .catchUpVehntV0()
+                .accounts({
+                  subDao,
+                  dao,
+                })
+                .remainingAccounts([
+                  {
+                    pubkey: subDaoEpochInfoKey(
+                      subDao,
+                      epochs[2].add(new BN(1)).toNumber() * EPOCH_LENGTH
+                    )[0],
+                    isWritable: true,
+                    isSigner: false,
+                  },
+                ])
+                .rpc()
+            ).to.eventually.be.rejectedWith("InvalidCatchUpEpochs");
+          });
+
           it("allows closing delegate", async () => {
             await sleep(options.delay);
             const method = program.methods
@@ -612,6 +1042,66 @@ describe("helium-sub-daos", () => {
             );
           });
 
+          it("moves the delegation to another subdao", async () => {
+            const { subDao: targetSubDao } = await initTestSubdao(
+              program,
+              provider,
+              me,
+              dao
+            );
+            const method = program.methods
+              .redelegateV0()
+              .accounts({
+                position,
+                sourceSubDao: subDao,
+                targetSubDao,
+                positionAuthority: positionAuthorityKp.publicKey,
+              })
+              .signers([positionAuthorityKp]);
+            const { delegatedPosition } = await method.pubkeys();
+            const { lastClaimedEpoch } =
+              await program.account.delegatedPositionV0.fetch(
+                delegatedPosition!
+              );
+            await method.rpc({ skipPreflight: true });
+
+            const sourceAcc = await program.account.subDaoV0.fetch(subDao);
+            expect(sourceAcc.vehntFallRate.toNumber()).to.eq(0);
+            expect(sourceAcc.vehntDelegated.toNumber()).to.be.lte(1);
+            const targetAcc = await program.account.subDaoV0.fetch(
+              targetSubDao
+            );
+            expect(targetAcc.vehntFallRate.toNumber()).to.be.gt(0);
+            expect(targetAcc.vehntDelegated.toNumber()).to.be.gt(0);
+
+            const delegatedPositionAcc =
+              await program.account.delegatedPositionV0.fetch(
+                delegatedPosition!
+              );
+            expect(delegatedPositionAcc.subDao.toBase58()).to.eq(
+              targetSubDao.toBase58()
+            );
+            expect(delegatedPositionAcc.lastClaimedEpoch.toNumber()).to.eq(
+              lastClaimedEpoch.toNumber()
+            );
+
+            // The position can now be undelegated from the target subdao
+            await program.methods
+              .closeDelegationV0()
+              .accounts({
+                position,
+                subDao: targetSubDao,
+                positionAuthority: positionAuthorityKp.publicKey,
+              })
+              .signers([positionAuthorityKp])
+              .rpc({ skipPreflight: true });
+            const closedTargetAcc = await program.account.subDaoV0.fetch(
+              targetSubDao
+            );
+            expect(closedTargetAcc.vehntFallRate.toNumber()).to.eq(0);
+            expect(closedTargetAcc.vehntDelegated.toNumber()).to.be.lte(1);
+          });
+
           describe("with calculated rewards", () => {
             let epoch: anchor.BN;
             let subDaoEpochInfo: PublicKey;
@@ -703,6 +1193,101 @@ describe("helium-sub-daos", () => {
               expect(Boolean(acc.rewardsIssuedAt)).to.be.true;
             });
 
+            it("pays the crank bounty to whoever issues rewards", async () => {
+              const bounty = 10000;
+              await program.methods
+                .updateDaoV0({
+                  authority: null,
+                  emissionSchedule: null,
+                  hstEmissionSchedule: null,
+                  entityMetadataUrl: null,
+                  utilityScoreConfig: null,
+                  crankBountyLamports: new BN(bounty),
+                })
+                .accounts({
+                  dao,
+                })
+                .rpc({ skipPreflight: true });
+              const cranker = Keypair.generate();
+              await sendInstructions(provider, [
+                SystemProgram.transfer({
+                  fromPubkey: me,
+                  toPubkey: crankVaultKey(dao)[0],
+                  lamports: LAMPORTS_PER_SOL,
+                }),
+                // Rent exempt so it can receive a small bounty
+                SystemProgram.transfer({
+                  fromPubkey: me,
+                  toPubkey: cranker.publicKey,
+                  lamports: LAMPORTS_PER_SOL,
+                }),
+              ]);
+
+              await program.methods
+                .issueRewardsV0({
+                  epoch,
+                })
+                .accounts({
+                  subDao,
+                  payer: cranker.publicKey,
+                })
+                .signers([cranker])
+                .rpc({ skipPreflight: true });
+
+              expect(
+                await provider.connection.getBalance(cranker.publicKey)
+              ).eq(LAMPORTS_PER_SOL + bounty);
+            });
+
+            it("splits dnt emissions by the subdao's delegator rewards schedule", async () => {
+              await program.methods
+                .updateSubDaoV0({
+                  authority: null,
+                  dcBurnAuthority: null,
+                  emissionSchedule: null,
+                  onboardingDcFee: null,
+                  activeDeviceAggregator: null,
+                  delegatorRewardsPercentSchedule: [
+                    { startUnixTime: new BN(0), percent: 10 },
+                  ],
+                })
+                .accounts({
+                  subDao,
+                })
+                .rpc({ skipPreflight: true });
+              const { delegatorPool } = await program.account.subDaoV0.fetch(
+                subDao
+              );
+              const preEscrowBalance = AccountLayout.decode(
+                (await provider.connection.getAccountInfo(rewardsEscrow))?.data!
+              ).amount;
+              const prePoolBalance = AccountLayout.decode(
+                (await provider.connection.getAccountInfo(delegatorPool))?.data!
+              ).amount;
+
+              await program.methods
+                .issueRewardsV0({
+                  epoch,
+                })
+                .accounts({
+                  subDao,
+                })
+                .rpc({ skipPreflight: true });
+
+              const postEscrowBalance = AccountLayout.decode(
+                (await provider.connection.getAccountInfo(rewardsEscrow))?.data!
+              ).amount;
+              const postPoolBalance = AccountLayout.decode(
+                (await provider.connection.getAccountInfo(delegatorPool))?.data!
+              ).amount;
+              expect((postEscrowBalance - preEscrowBalance).toString()).to.eq(
+                ((SUB_DAO_EPOCH_REWARDS / 100) * 90).toString()
+              );
+              expect((postPoolBalance - prePoolBalance).toString()).to.eq(
+                ((SUB_DAO_EPOCH_REWARDS / 100) * 10).toString()
+              );
+            });
+
             it("claim rewards", async () => {
               // issue rewards
               await sendInstructions(provider, [
@@ -716,6 +1301,26 @@ describe("helium-sub-daos", () => {
                   .instruction(),
               ]);
 
+              const preview = await program.methods
+                .previewRewardsV0({
+                  startEpoch: epoch,
+                  endEpoch: epoch,
+                })
+                .accounts({
+                  position,
+                  subDao,
+                })
+                .remainingAccounts([
+                  {
+                    pubkey: subDaoEpochInfo,
+                    isWritable: false,
+                    isSigner: false,
+                  },
+                ])
+                .view();
+              expect(preview.epochs.length).to.eq(1);
+              expect(preview.epochs[0].claimable).to.be.true;
+
               const method = program.methods
                 .claimRewardsV0({
                   epoch,
@@ -736,6 +1341,193 @@ describe("helium-sub-daos", () => {
                 (SUB_DAO_EPOCH_REWARDS * 6) / 100 - 5,
                 (SUB_DAO_EPOCH_REWARDS * 6) / 100
               );
+              // The preview goes through the same math as the claim
+              expect(postAtaBalance.toString()).to.eq(
+                preview.totalClaimable.toString()
+              );
+            });
+
+            it("claims a contiguous run of epochs at once", async () => {
+              await program.methods
+                .issueRewardsV0({
+                  epoch,
+                })
+                .accounts({
+                  subDao,
+                })
+                .rpc({ skipPreflight: true });
+
+              // Move on to the following epoch and issue its rewards too
+              await vsrProgram.methods
+                .setTimeOffsetV0(new BN(2 * 60 * 60 * 24))
+                .accounts({ registrar })
+                .rpc({ skipPreflight: true });
+              const { subDaoEpochInfo: nextSubDaoEpochInfo } = await burnDc(
+                1600000
+              );
+              const nextEpoch = epoch.add(new BN(1));
+              await program.methods
+                .calculateUtilityScoreV0({
+                  epoch: nextEpoch,
+                })
+                .preInstructions([
+                  ComputeBudgetProgram.setComputeUnitLimit({ units: 400000 }),
+                ])
+                .accounts({
+                  subDao,
+                  dao,
+                })
+                .rpc({ skipPreflight: true });
+              await program.methods
+                .issueRewardsV0({
+                  epoch: nextEpoch,
+                })
+                .accounts({
+                  subDao,
+                })
+                .rpc({ skipPreflight: true });
+
+              const claim = (epochInfos: PublicKey[]) =>
+                program.methods
+                  .batchClaimRewardsV0()
+                  .accounts({
+                    position,
+                    subDao,
+                    positionAuthority: positionAuthorityKp.publicKey,
+                  })
+                  .remainingAccounts(
+                    epochInfos.map((pubkey) => ({
+                      pubkey,
+                      isWritable: false,
+                      isSigner: false,
+                    }))
+                  )
+                  .signers([positionAuthorityKp]);
+
+              // Skipping over the first unclaimed epoch is not allowed
+              await expect(
+                claim([nextSubDaoEpochInfo]).rpc()
+              ).to.eventually.be.rejectedWith("InvalidClaimEpoch");
+
+              const method = claim([subDaoEpochInfo, nextSubDaoEpochInfo]);
+              const { delegatorAta, delegatedPosition } =
+                await method.pubkeys();
+              await method.rpc({ skipPreflight: true });
+
+              const postAtaBalance = AccountLayout.decode(
+                (await provider.connection.getAccountInfo(delegatorAta!))?.data!
+              ).amount;
+              expect(Number(postAtaBalance)).to.be.within(
+                ((SUB_DAO_EPOCH_REWARDS * 6) / 100) * 2 - 10,
+                ((SUB_DAO_EPOCH_REWARDS * 6) / 100) * 2
+              );
+              const delegatedPositionAcc =
+                await program.account.delegatedPositionV0.fetch(
+                  delegatedPosition!
+                );
+              expect(delegatedPositionAcc.lastClaimedEpoch.toNumber()).to.eq(
+                nextEpoch.toNumber()
+              );
+            });
+
+            it("keeps the epoch share when redelegated twice in one epoch", async () => {
+              const { subDao: otherSubDao } = await initTestSubdao(
+                program,
+                provider,
+                me,
+                dao
+              );
+              const otherSubDaoEpochInfo = subDaoEpochInfoKey(
+                otherSubDao,
+                epoch.toNumber() * EPOCH_LENGTH
+              )[0];
+              const redelegate = (
+                sourceSubDao: PublicKey,
+                targetSubDao: PublicKey,
+                sourceSubDaoEpochInfo: PublicKey,
+                targetSubDaoEpochInfo: PublicKey
+              ) =>
+                program.methods
+                  .redelegateV0()
+                  .accounts({
+                    position,
+                    sourceSubDao,
+                    targetSubDao,
+                    sourceSubDaoEpochInfo,
+                    targetSubDaoEpochInfo,
+                    positionAuthority: positionAuthorityKp.publicKey,
+                  })
+                  .signers([positionAuthorityKp])
+                  .rpc({ skipPreflight: true });
+              const vehntAtEpochStart = async (epochInfo: PublicKey) =>
+                (
+                  await program.account.subDaoEpochInfoV0.fetch(epochInfo)
+                ).vehntAtEpochStart.toString();
+
+              const startVehnt = await vehntAtEpochStart(subDaoEpochInfo);
+              await redelegate(
+                subDao,
+                otherSubDao,
+                subDaoEpochInfo,
+                otherSubDaoEpochInfo
+              );
+              const movedVehnt = await vehntAtEpochStart(subDaoEpochInfo);
+              expect(movedVehnt).to.not.eq(startVehnt);
+
+              // Moving back within the same epoch brings the share back with it
+              await redelegate(
+                otherSubDao,
+                subDao,
+                otherSubDaoEpochInfo,
+                subDaoEpochInfo
+              );
+              expect(await vehntAtEpochStart(subDaoEpochInfo)).to.eq(
+                startVehnt
+              );
+              expect(await vehntAtEpochStart(otherSubDaoEpochInfo)).to.eq("0");
+
+              await program.methods
+                .issueRewardsV0({
+                  epoch,
+                })
+                .accounts({
+                  subDao,
+                })
+                .rpc({ skipPreflight: true });
+              const method = program.methods
+                .claimRewardsV0({
+                  epoch,
+                })
+                .accounts({
+                  position,
+                  subDao,
+                  positionAuthority: positionAuthorityKp.publicKey,
+                })
+                .signers([positionAuthorityKp]);
+              const { delegatorAta } = await method.pubkeys();
+              await method.rpc({ skipPreflight: true });
+              const postAtaBalance = AccountLayout.decode(
+                (await provider.connection.getAccountInfo(delegatorAta!))?.data!
+              ).amount;
+              expect(Number(postAtaBalance)).to.be.within(
+                (SUB_DAO_EPOCH_REWARDS * 6) / 100 - 5,
+                (SUB_DAO_EPOCH_REWARDS * 6) / 100
+              );
+
+              // Closing takes the share out of the sub dao it ended up in
+              await program.methods
+                .closeDelegationV0()
+                .accounts({
+                  position,
+                  subDao,
+                  subDaoEpochInfo,
+                  positionAuthority: positionAuthorityKp.publicKey,
+                })
+                .signers([positionAuthorityKp])
+                .rpc({ skipPreflight: true });
+              expect(await vehntAtEpochStart(subDaoEpochInfo)).to.eq(
+                movedVehnt
+              );
             });
           });
         });
@@ -18,7 +18,9 @@ export async function initTestDao(
   authority: PublicKey,
   dcMint?: PublicKey,
   mint?: PublicKey,
-  registrar?: PublicKey
+  registrar?: PublicKey,
+  epochConfig?: { epochLength: number; epochOffset: number },
+  withClockwork: boolean = true
 ): Promise<{
   mint: PublicKey;
   dao: PublicKey;
@@ -37,6 +39,9 @@ export async function initTestDao(
       registrar: registrar || Keypair.generate().publicKey,
       authority: authority,
       netEmissionsCap: toBN(34.24, 8),
+      entityMetadataUrl: "",
+      epochLength: epochConfig ? new BN(epochConfig.epochLength) : null,
+      epochOffset: epochConfig ? new BN(epochConfig.epochOffset) : null,
       emissionSchedule: [
         {
           startUnixTime: new anchor.BN(0),
@@ -60,6 +65,7 @@ export async function initTestDao(
       hntMint: mint,
       dcMint,
       hstPool: await getAssociatedTokenAddress(mint, me),
+      clockwork: withClockwork ? undefined : null,
     });
   const { dao } = await method.pubkeys();
 
@@ -75,7 +81,9 @@ export async function initTestSubdao(
   provider: anchor.AnchorProvider,
   authority: PublicKey,
   dao: PublicKey,
-  epochRewards?: number
+  epochRewards?: number,
+  delegatorRewardsPercentSchedule: { startUnixTime: BN; percent: number }[] = [],
+  withClockwork: boolean = true
 ): Promise<{
   mint: PublicKey;
   subDao: PublicKey;
@@ -110,6 +118,7 @@ export async function initTestSubdao(
         threshold: new anchor.BN("10000000000000000000"),
       },
       dcBurnAuthority: authority,
+      delegatorRewardsPercentSchedule,
     })
     .preInstructions([
       ComputeBudgetProgram.setComputeUnitLimit({ units: 350000 }),
@@ -122,6 +131,7 @@ export async function initTestSubdao(
       activeDeviceAggregator: new PublicKey(
         "GvDMxPzN1sCj7L26YDK2HnMRXEQmQ2aemov8YBtPS7vR"
       ), // Copied from mainnet to localnet
+      clockwork: withClockwork ? undefined : null,
     });
   const { treasury, treasuryCircuitBreaker, delegatorPool } = await method.pubkeys();
   await method.rpc();
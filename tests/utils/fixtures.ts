@@ -6,6 +6,7 @@ import { getConcurrentMerkleTreeAccountSize, SPL_ACCOUNT_COMPRESSION_PROGRAM_ID
 import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
 import { execSync } from "child_process";
 import { ThresholdType } from "../../packages/circuit-breaker-sdk/src";
+import { dataCreditsKey } from "../../packages/data-credits-sdk/src";
 import { makerKey } from "../../packages/helium-entity-manager-sdk/src";
 import { DataCredits } from "../../target/types/data_credits";
 import { HeliumEntityManager } from "../../target/types/helium_entity_manager";
@@ -182,6 +183,30 @@ export const initTestMaker = async (
   };
 };
 
+export const topUpMakerEscrow = async (
+  program: Program<HeliumEntityManager>,
+  dcProgram: Program<DataCredits>,
+  maker: PublicKey,
+  dcMint: PublicKey,
+  hntAmount: anchor.BN
+): Promise<{ makerEscrow: PublicKey }> => {
+  const dataCredits = await dcProgram.account.dataCreditsV0.fetch(
+    dataCreditsKey(dcMint)[0]
+  );
+  const makerAcc = await program.account.makerV0.fetch(maker);
+  const method = program.methods.topUpMakerEscrowV0({ hntAmount }).accounts({
+    maker,
+    dao: makerAcc.dao,
+    dcMint,
+    hntMint: dataCredits.hntMint,
+    hntPriceOracle: dataCredits.hntPriceOracle,
+  });
+  const { makerEscrow } = await method.pubkeys();
+  await method.rpc({ skipPreflight: true });
+
+  return { makerEscrow: makerEscrow! };
+};
+
 export async function ensureDCIdl(dcProgram: Program<DataCredits>) {
   try {
     execSync(